@@ -79,32 +79,70 @@ impl ShipType {
 		match self {
 			ShipType::Wraith => ShipStats {
 				speed: 620.0,
-				fire_cooldown: 0.18,
 				size: 84.5,
+				hitbox_scale: 0.6,
+				defenses: DefenseProfile {
+					shield2_max: 60.0,
+					shield1_max: 150.0,
+					armor_max: 70.0,
+					shield2_regen_delay: 1.5,
+					shield2_regen_duration: 1.2,
+					shield1_regen_per_sec: 5.0,
+				},
+				ability: ShipAbility::Dash,
 				description: "Speed-focused interceptor from Apex Dynamics",
 			},
 			ShipType::Tempest => ShipStats {
 				speed: 520.0,
-				fire_cooldown: 0.15,
 				size: 84.5,
+				hitbox_scale: 0.7,
+				defenses: DefenseProfile::default(),
+				ability: ShipAbility::ShieldSurge,
 				description: "Balanced performance fighter from Vortex Dynamics",
 			},
 			ShipType::Anvil => ShipStats {
 				speed: 450.0,
-				fire_cooldown: 0.12,
 				size: 88.4,
+				hitbox_scale: 0.8,
+				defenses: DefenseProfile {
+					shield2_max: 50.0,
+					shield1_max: 150.0,
+					armor_max: 180.0,
+					shield2_regen_delay: 3.0,
+					shield2_regen_duration: 2.0,
+					shield1_regen_per_sec: 3.0,
+				},
+				ability: ShipAbility::ArmorOvercharge,
 				description: "Heavy weapons platform from Forge Industrial",
 			},
 			ShipType::Talon => ShipStats {
 				speed: 580.0,
-				fire_cooldown: 0.16,
 				size: 84.5,
+				hitbox_scale: 0.65,
+				defenses: DefenseProfile {
+					shield2_max: 90.0,
+					shield1_max: 160.0,
+					armor_max: 80.0,
+					shield2_regen_delay: 1.8,
+					shield2_regen_duration: 1.2,
+					shield1_regen_per_sec: 4.0,
+				},
+				ability: ShipAbility::Afterburner,
 				description: "Agile strike fighter from Helix Aerospace",
 			},
 			ShipType::Bastion => ShipStats {
 				speed: 490.0,
-				fire_cooldown: 0.14,
 				size: 85.8,
+				hitbox_scale: 0.75,
+				defenses: DefenseProfile {
+					shield2_max: 100.0,
+					shield1_max: 250.0,
+					armor_max: 110.0,
+					shield2_regen_delay: 2.5,
+					shield2_regen_duration: 2.0,
+					shield1_regen_per_sec: 7.0,
+				},
+				ability: ShipAbility::Barrier,
 				description: "Reliable combat platform from Sentinel Systems",
 			},
 		}
@@ -134,15 +172,155 @@ impl ShipType {
 #[derive(Clone, Copy, Debug)]
 pub struct ShipStats {
 	pub speed: f32,
-	pub fire_cooldown: f32,
 	pub size: f32,
+	/// Collider radius as a fraction of half the sprite size
+	pub hitbox_scale: f32,
+	pub defenses: DefenseProfile,
+	pub ability: ShipAbility,
 	pub description: &'static str,
 }
 
+/// Per-ship defense layer maxima and regen tuning
+#[derive(Clone, Copy, Debug)]
+pub struct DefenseProfile {
+	pub shield2_max: f32,
+	pub shield1_max: f32,
+	pub armor_max: f32,
+	/// Seconds after any hit before shield2 starts regenerating
+	pub shield2_regen_delay: f64,
+	/// Seconds for shield2 to ease from its current value back to max
+	pub shield2_regen_duration: f64,
+	pub shield1_regen_per_sec: f32,
+}
+
+impl Default for DefenseProfile {
+	fn default() -> Self {
+		Self {
+			shield2_max: 75.0,
+			shield1_max: 200.0,
+			armor_max: 100.0,
+			shield2_regen_delay: 2.0,
+			shield2_regen_duration: 1.5,
+			shield1_regen_per_sec: 5.0,
+		}
+	}
+}
+
+/// Active ability, one per ship (triggered with C)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShipAbility {
+	/// Wraith: short burst in the movement direction with i-frames
+	Dash,
+	/// Tempest: instantly restore shield2 and top up shield1
+	ShieldSurge,
+	/// Anvil: temporary armor above max that bleeds off when it ends
+	ArmorOvercharge,
+	/// Talon: temporary speed boost
+	Afterburner,
+	/// Bastion: deploy a barrier above the ship that absorbs enemy shots
+	Barrier,
+}
+
+impl ShipAbility {
+	pub fn name(&self) -> &'static str {
+		match self {
+			ShipAbility::Dash => "Dash",
+			ShipAbility::ShieldSurge => "Shield Surge",
+			ShipAbility::ArmorOvercharge => "Armor Overcharge",
+			ShipAbility::Afterburner => "Afterburner",
+			ShipAbility::Barrier => "Barrier",
+		}
+	}
+
+	pub fn cooldown_secs(&self) -> f32 {
+		match self {
+			ShipAbility::Dash => 2.0,
+			ShipAbility::ShieldSurge => 20.0,
+			ShipAbility::ArmorOvercharge => 25.0,
+			ShipAbility::Afterburner => 10.0,
+			ShipAbility::Barrier => 15.0,
+		}
+	}
+
+	/// How long the ability stays active once triggered
+	pub fn duration_secs(&self) -> f32 {
+		match self {
+			ShipAbility::Dash => 0.18,
+			ShipAbility::ShieldSurge => 0.0,
+			ShipAbility::ArmorOvercharge => 6.0,
+			ShipAbility::Afterburner => 3.0,
+			ShipAbility::Barrier => 5.0,
+		}
+	}
+}
+
 #[derive(Component)]
 pub struct Player {
-	pub fire_cooldown: Timer,
 	pub ship_type: ShipType,
+	/// 0 = player one (keyboard), 1 = player two (gamepad / numpad)
+	pub slot: u8,
+}
+
+/// Marker for player one; the main HUD and single-player debug views follow this ship
+#[derive(Component)]
+pub struct PrimaryPlayer;
+
+/// A co-op ship that's out of lives; it sits hidden until a continue (or the other ship also goes down)
+#[derive(Component)]
+pub struct PlayerDown;
+
+/// This frame's input for one ship, filled from its device by `read_player_input`
+#[derive(Component, Default)]
+pub struct PlayerInput {
+	pub movement: Vec2,
+	pub fire: bool,
+	pub fire_just_pressed: bool,
+	pub fire_just_released: bool,
+	pub focus: bool,
+	pub bomb: bool,
+	pub ability: bool,
+	pub swap: bool,
+}
+
+/// Small core hitbox the player takes projectile hits on; `Collider` stays the contact shape
+#[derive(Component)]
+pub struct PlayerCoreHitbox {
+	pub radius: f32,
+}
+
+/// Focus (precision) mode: held Shift slows the ship, shows the core hitbox and tightens spread weapons
+#[derive(Component, Default)]
+pub struct FocusState {
+	pub active: bool,
+}
+
+/// Cooldown and active window for the player's ship ability
+#[derive(Component)]
+pub struct ShipAbilityState {
+	pub ability: ShipAbility,
+	pub cooldown: Timer,
+	pub active: Timer,
+	/// Direction locked in when a dash starts
+	pub dash_direction: Vec2,
+}
+
+impl ShipAbilityState {
+	pub fn new(ability: ShipAbility) -> Self {
+		let mut cooldown = Timer::from_seconds(ability.cooldown_secs(), TimerMode::Once);
+		cooldown.tick(cooldown.duration());
+		let mut active = Timer::from_seconds(ability.duration_secs(), TimerMode::Once);
+		active.tick(active.duration());
+		Self {
+			ability,
+			cooldown,
+			active,
+			dash_direction: Vec2::Y,
+		}
+	}
+
+	pub fn is_active(&self, ability: ShipAbility) -> bool {
+		self.ability == ability && !self.active.finished()
+	}
 }
 
 /// A simple animated thruster sprite attached to a ship (no bloom required).
@@ -166,7 +344,8 @@ pub struct ThrusterFx {
 
 // === Weapon System Components ===
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Per-level stats live in `assets/weapons/weapon_tuning.yaml` (see `WeaponTuning`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
 pub enum WeaponType {
 	BasicBlaster,
 	PlasmaCannon,
@@ -178,100 +357,88 @@ pub enum WeaponType {
 	LightningChain,
 }
 
-pub struct WeaponConfig {
-	pub base_damage: f32,
-	pub damage_per_level: f32,
-	pub base_cooldown: f32,
-	pub cooldown_reduction_per_level: f32,
-	pub projectile_speed: f32,
-	pub projectile_size: Vec2,
-	pub projectile_color: Color,
-	pub max_level: u8,
+impl WeaponType {
+	pub const ALL: [WeaponType; 8] = [
+		WeaponType::BasicBlaster,
+		WeaponType::PlasmaCannon,
+		WeaponType::WaveGun,
+		WeaponType::SpreadShot,
+		WeaponType::MissilePods,
+		WeaponType::LaserArray,
+		WeaponType::OrbitalDefense,
+		WeaponType::LightningChain,
+	];
+
+	pub fn damage_type(&self) -> DamageType {
+		match self {
+			WeaponType::BasicBlaster | WeaponType::SpreadShot => DamageType::Kinetic,
+			WeaponType::PlasmaCannon | WeaponType::WaveGun | WeaponType::LaserArray | WeaponType::OrbitalDefense => DamageType::Energy,
+			WeaponType::MissilePods => DamageType::Explosive,
+			WeaponType::LightningChain => DamageType::Electric,
+		}
+	}
+
+	/// The two branches a max-level weapon can evolve into. Orbitals and lightning already
+	/// change shape at high levels (orbs, enhanced mode), and the blaster never levels.
+	pub fn evolutions(&self) -> Option<[WeaponEvolution; 2]> {
+		match self {
+			WeaponType::PlasmaCannon => Some([WeaponEvolution::PiercingBeam, WeaponEvolution::PlasmaNova]),
+			WeaponType::WaveGun => Some([WeaponEvolution::TwinHelix, WeaponEvolution::Tsunami]),
+			WeaponType::SpreadShot => Some([WeaponEvolution::SevenWay, WeaponEvolution::RearGuard]),
+			WeaponType::MissilePods => Some([WeaponEvolution::Swarm, WeaponEvolution::Torpedo]),
+			WeaponType::LaserArray => Some([WeaponEvolution::PrismFan, WeaponEvolution::Crossfire]),
+			WeaponType::BasicBlaster | WeaponType::OrbitalDefense | WeaponType::LightningChain => None,
+		}
+	}
+
+	/// Weapons that can sit in the sub-weapon slot. The blaster is the fallback primary, and
+	/// lightning needs the ship's charge meter, so both stay primary-only.
+	pub fn can_be_sub_weapon(&self) -> bool {
+		!matches!(self, WeaponType::BasicBlaster | WeaponType::LightningChain)
+	}
 }
 
-impl WeaponType {
-	pub fn config(&self) -> WeaponConfig {
+/// Branch variant a weapon turns into at max level (see `WeaponType::evolutions`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum WeaponEvolution {
+	PiercingBeam,
+	PlasmaNova,
+	TwinHelix,
+	Tsunami,
+	SevenWay,
+	RearGuard,
+	Swarm,
+	Torpedo,
+	PrismFan,
+	Crossfire,
+}
+
+impl WeaponEvolution {
+	pub const ALL: [WeaponEvolution; 10] = [
+		WeaponEvolution::PiercingBeam,
+		WeaponEvolution::PlasmaNova,
+		WeaponEvolution::TwinHelix,
+		WeaponEvolution::Tsunami,
+		WeaponEvolution::SevenWay,
+		WeaponEvolution::RearGuard,
+		WeaponEvolution::Swarm,
+		WeaponEvolution::Torpedo,
+		WeaponEvolution::PrismFan,
+		WeaponEvolution::Crossfire,
+	];
+
+	pub fn name(&self) -> &'static str {
 		match self {
-			WeaponType::BasicBlaster => WeaponConfig {
-				base_damage: 10.0,
-				damage_per_level: 0.0,
-				base_cooldown: 0.25,
-				cooldown_reduction_per_level: 0.0,
-				projectile_speed: 1300.0,
-				projectile_size: Vec2::new(5.0, 20.0),
-				projectile_color: Color::srgb(0.2, 0.7, 1.0),
-				max_level: 0,
-			},
-			WeaponType::PlasmaCannon => WeaponConfig {
-				base_damage: 25.0,
-				damage_per_level: 8.0,
-				base_cooldown: 0.4,
-				cooldown_reduction_per_level: 0.05,
-				projectile_speed: 1500.0,
-				projectile_size: Vec2::new(8.0, 28.0),
-				projectile_color: Color::srgb(0.8, 0.2, 1.0),
-				max_level: 6,
-			},
-			WeaponType::WaveGun => WeaponConfig {
-				base_damage: 15.0,
-				damage_per_level: 5.0,
-				base_cooldown: 0.18,
-				cooldown_reduction_per_level: 0.02,
-				projectile_speed: 1100.0,
-				projectile_size: Vec2::new(6.0, 22.0),
-				projectile_color: Color::srgb(0.2, 1.0, 0.5),
-				max_level: 6,
-			},
-			WeaponType::SpreadShot => WeaponConfig {
-				base_damage: 12.0,
-				damage_per_level: 3.0,
-				base_cooldown: 0.3,
-				cooldown_reduction_per_level: 0.03,
-				projectile_speed: 1200.0,
-				projectile_size: Vec2::new(4.0, 16.0),
-				projectile_color: Color::srgb(1.0, 0.5, 0.2),
-				max_level: 6,
-			},
-			WeaponType::MissilePods => WeaponConfig {
-				base_damage: 30.0,
-				damage_per_level: 10.0,
-				base_cooldown: 0.6,
-				cooldown_reduction_per_level: 0.06,
-				projectile_speed: 800.0,
-				projectile_size: Vec2::new(8.0, 20.0),
-				projectile_color: Color::srgb(1.0, 0.2, 0.2),
-				max_level: 6,
-			},
-			WeaponType::LaserArray => WeaponConfig {
-				base_damage: 8.0,
-				damage_per_level: 2.0,
-				base_cooldown: 0.08,
-				cooldown_reduction_per_level: 0.01,
-				projectile_speed: 2000.0,
-				projectile_size: Vec2::new(3.0, 40.0),
-				projectile_color: Color::srgb(0.0, 0.8, 1.0),
-				max_level: 6,
-			},
-			WeaponType::OrbitalDefense => WeaponConfig {
-				base_damage: 15.0,
-				damage_per_level: 5.0,
-				base_cooldown: 0.25,
-				cooldown_reduction_per_level: 0.02,
-				projectile_speed: 1000.0,
-				projectile_size: Vec2::new(6.0, 18.0),
-				projectile_color: Color::srgb(1.0, 0.8, 0.0),
-				max_level: 6,
-			},
-			WeaponType::LightningChain => WeaponConfig {
-				base_damage: 25.0,
-				damage_per_level: 2.5,
-				base_cooldown: 0.4,
-				cooldown_reduction_per_level: 0.01,
-				projectile_speed: 1200.0,
-				projectile_size: Vec2::new(30.0, 70.0),
-				projectile_color: Color::srgb(0.6, 0.8, 1.0),
-				max_level: 10,
-			},
+			WeaponEvolution::PiercingBeam => "Piercing Beam",
+			WeaponEvolution::PlasmaNova => "Plasma Nova",
+			WeaponEvolution::TwinHelix => "Twin Helix",
+			WeaponEvolution::Tsunami => "Tsunami",
+			WeaponEvolution::SevenWay => "7-Way",
+			WeaponEvolution::RearGuard => "Rear Guard",
+			WeaponEvolution::Swarm => "Swarm",
+			WeaponEvolution::Torpedo => "Torpedo",
+			WeaponEvolution::PrismFan => "Prism Fan",
+			WeaponEvolution::Crossfire => "Crossfire",
 		}
 	}
 }
@@ -281,12 +448,30 @@ pub struct Weapon {
 	pub weapon_type: WeaponType,
 	pub level: u8,
 	pub fire_cooldown: Timer,
+	/// Set once a max-level weapon evolves; lost if the weapon drops below max level
+	pub evolution: Option<WeaponEvolution>,
+}
+
+impl Weapon {
+	/// Fresh weapon at `level`; `WeaponTuning::new_weapon` looks up the cooldown
+	pub fn new(weapon_type: WeaponType, level: u8, cooldown_secs: f32) -> Self {
+		Self {
+			weapon_type,
+			level,
+			fire_cooldown: Timer::from_seconds(cooldown_secs, TimerMode::Repeating),
+			evolution: None,
+		}
+	}
 }
 
+/// Sub-weapon slot: fires alongside the primary `Weapon` with its own level and cooldown.
+/// The swap button exchanges the two, keeping both levels.
+#[derive(Component)]
+pub struct SubWeapon(pub Weapon);
+
 #[derive(Component)]
 pub struct Projectile {
 	pub weapon_type: WeaponType,
-	pub level: u8,
 	pub speed: f32,
 	pub damage: f32,
 	pub lifetime: Timer,
@@ -310,8 +495,99 @@ pub struct AngledShot {
 	pub velocity: Vec2,
 }
 
+/// On impact, also hits every other enemy within `radius` for `fraction` of the damage
+#[derive(Component)]
+pub struct SplashDamage {
+	pub radius: f32,
+	pub fraction: f32,
+}
+
+/// Instant-hit beam visual (PiercingBeam); damage is dealt when fired, this only fades out
+#[derive(Component)]
+pub struct EvolutionBeam {
+	pub lifetime: Timer,
+	pub width: f32,
+}
+
+// === Projectile Modifiers ===
+// Granted per weapon level / evolution in weapon_tuning.yaml
+
+/// Passes through up to `remaining` more enemies before despawning
+#[derive(Component)]
+pub struct Pierce {
+	pub remaining: u8,
+}
+
+/// On hit, turns toward the nearest un-hit enemy within `range`, up to `remaining` times
+#[derive(Component)]
+pub struct Ricochet {
+	pub remaining: u8,
+	pub range: f32,
+}
+
+/// Enemies a piercing or ricocheting projectile has already hit, so it never hits one twice
+#[derive(Component, Default)]
+pub struct ProjectileHits(pub Vec<Entity>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum DotKind {
+	/// Plain damage over time
+	Burn,
+	/// Damage over time that also jams the enemy's weapons, slowing its fire rate
+	Emp,
+}
+
+/// Leaves a damage-over-time stack on every enemy the projectile hits
+#[derive(Component, Clone, Copy, Debug, Deserialize)]
+pub struct DamageOverTime {
+	pub kind: DotKind,
+	pub damage_per_sec: f32,
+	pub duration: f32,
+}
+
+/// Shoves hit enemies along the projectile's direction of travel (units/sec of push)
+#[derive(Component)]
+pub struct Knockback {
+	pub strength: f32,
+}
+
+/// One damage-over-time stack on an enemy
+pub struct DotStack {
+	pub kind: DotKind,
+	pub damage_per_sec: f32,
+	pub remaining: f32,
+}
+
+/// Active burn/EMP stacks on an enemy, dealt in periodic ticks
+#[derive(Component)]
+pub struct DotStacks {
+	pub stacks: Vec<DotStack>,
+	pub tick: Timer,
+}
+
+impl DotKind {
+	pub fn damage_type(&self) -> DamageType {
+		match self {
+			DotKind::Burn => DamageType::Energy,
+			DotKind::Emp => DamageType::Electric,
+		}
+	}
+}
+
+impl DotStacks {
+	pub fn has(&self, kind: DotKind) -> bool {
+		self.stacks.iter().any(|stack| stack.kind == kind)
+	}
+}
+
+/// Push an enemy is currently drifting with after a knockback hit; decays to zero
+#[derive(Component)]
+pub struct KnockbackVelocity(pub Vec2);
+
 #[derive(Component)]
 pub struct OrbitalEntity {
+	/// Ship this orb circles
+	pub owner: Entity,
 	pub angle: f32,
 	pub radius: f32,
 	pub rotation_speed: f32,
@@ -425,18 +701,52 @@ pub struct PowerUp {
 	pub upgrade_amount: i8,
 }
 
+/// Marker: this enemy projectile has already been counted as a graze
+#[derive(Component)]
+pub struct Grazed;
+
+/// Offered when a max-level weapon would level up again: alternates between the weapon's
+/// two evolution branches, and the owning ship takes whichever is showing when it touches it
+#[derive(Component)]
+pub struct EvolutionPickup {
+	pub owner: Entity,
+	pub branches: [WeaponEvolution; 2],
+	pub showing: usize,
+	pub cycle: Timer,
+}
+
+/// Adds one bomb to the player's stock when collected
+#[derive(Component)]
+pub struct BombPickup;
+
+/// Score token (e.g. from bomb-cancelled enemy projectiles); homes to the player after a short delay
+#[derive(Component)]
+pub struct ScoreItem {
+	pub value: u32,
+	pub home_delay: Timer,
+}
+
 #[derive(Event)]
 pub struct WeaponSwitchEvent {
+	pub player: Entity,
 	pub new_weapon: WeaponType,
 }
 
 #[derive(Event)]
 pub struct WeaponUpgradeEvent {
+	pub player: Entity,
 	pub level_change: i8,
 }
 
+/// A max-level weapon was powered up again; spawns an `EvolutionPickup` for this ship
+#[derive(Event)]
+pub struct EvolutionOfferEvent {
+	pub player: Entity,
+}
+
 #[derive(Event)]
 pub struct PlayerHitEvent {
+	pub player: Entity,
 	pub sink: DamageSink,
 	pub depleted: bool,
 }
@@ -507,6 +817,47 @@ impl EnemyType {
 		})
 	}
 
+	/// Built-in carrier setup for types that launch other enemies
+	pub fn default_spawner(&self) -> Option<EnemySpawnerConfig> {
+		match self {
+			EnemyType::Corvette => Some(EnemySpawnerConfig {
+				child_type: "Drone".to_string(),
+				interval: 2.5,
+				max_children: 4,
+				per_launch: 1,
+				sockets: SocketSelector::All,
+				// Shoot clear of the hull, then drift like any drone
+				behaviors: vec![
+					Behavior {
+						behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -240.0) },
+						duration: Some(0.6),
+						transition: TransitionType::WaitForCompletion,
+						flow: BehaviorFlow::default(),
+					},
+					Behavior {
+						behavior_type: BehaviorType::Drift { velocity: Vec2::new(0.0, -90.0), variance: 40.0 },
+						duration: None,
+						transition: TransitionType::WaitForCompletion,
+						flow: BehaviorFlow::default(),
+					},
+				],
+				on_parent_death: OrphanPolicy::Scatter,
+			}),
+			_ => None,
+		}
+	}
+
+	/// How dangerous the type is, for off-screen warnings
+	pub fn threat_class(&self) -> ThreatClass {
+		match self {
+			EnemyType::SmallAsteroid | EnemyType::MediumAsteroid | EnemyType::LargeAsteroid | EnemyType::StationDebris => ThreatClass::Hazard,
+			EnemyType::Scout | EnemyType::ScoutSting | EnemyType::Fighter | EnemyType::Drone => ThreatClass::Light,
+			EnemyType::Interceptor | EnemyType::Drill => ThreatClass::Fast,
+			EnemyType::HeavyGunship | EnemyType::Bomber | EnemyType::Corvette | EnemyType::AsteroidTurret => ThreatClass::Heavy,
+			EnemyType::Boss => ThreatClass::Boss,
+		}
+	}
+
 	pub fn manifest_key(&self) -> &'static str {
 		match self {
 			EnemyType::Scout => "Scout",
@@ -552,6 +903,63 @@ pub struct EnemyBehavior {
 	pub behavior_start_time: f32,
 	pub total_time_alive: f32,
 	pub spawn_position: Vec2,
+	/// 0..1 roll made on entering the current state, shared by its `Random` conditions
+	pub state_roll: f32,
+	/// Set once the enemy has been inside the play area (for `OffScreen`)
+	pub entered_screen: bool,
+	/// Set when this enemy's formation leader died and it broke formation (for `LeaderDead`)
+	pub leader_lost: bool,
+	/// Direction a `Charge` locked onto when its state was entered
+	pub charge_direction: Option<Vec2>,
+	/// Where the enemy was when its current state started (a `FollowPath` blends in from here)
+	pub entry_position: Option<Vec2>,
+}
+
+/// Reserved `goto`/`then` target meaning "the following behavior in the list"
+pub const NEXT_STATE: &str = "next";
+/// Reserved `then` target meaning "keep running this behavior" (transitions still apply)
+pub const HOLD_STATE: &str = "hold";
+
+impl EnemyBehavior {
+	pub fn new(behaviors: Vec<Behavior>, spawn_position: Vec2) -> Self {
+		let targets = behaviors.iter().flat_map(|behavior| behavior.flow.then.iter()
+			.chain(behavior.flow.transitions.iter().map(|transition| &transition.goto)));
+		for target in targets {
+			let known = target == NEXT_STATE
+				|| target == HOLD_STATE
+				|| behaviors.iter().any(|behavior| behavior.flow.name.as_ref() == Some(target));
+			if !known {
+				warn!("⚠️  Behavior target '{}' names no state - the enemy will stop when it gets there", target);
+			}
+		}
+
+		Self {
+			behaviors,
+			current_index: 0,
+			behavior_start_time: 0.0,
+			total_time_alive: 0.0,
+			spawn_position,
+			state_roll: rand::random(),
+			entered_screen: false,
+			leader_lost: false,
+			charge_direction: None,
+			entry_position: None,
+		}
+	}
+
+	/// Index of the state labelled `name`
+	pub fn find_state(&self, name: &str) -> Option<usize> {
+		self.behaviors.iter().position(|behavior| behavior.flow.name.as_deref() == Some(name))
+	}
+
+	/// Switch to the state at `index`, restarting its timer and random roll
+	pub fn enter_state(&mut self, index: usize) {
+		self.current_index = index;
+		self.behavior_start_time = self.total_time_alive;
+		self.state_roll = rand::random();
+		self.charge_direction = None;
+		self.entry_position = None;
+	}
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -559,7 +967,48 @@ pub struct Behavior {
 	#[serde(flatten)]
 	pub behavior_type: BehaviorType,
 	pub duration: Option<f32>,
+	#[serde(default)]
 	pub transition: TransitionType,
+	#[serde(flatten)]
+	pub flow: BehaviorFlow,
+}
+
+/// State-machine wiring for one behavior: its label, conditional exits and where it goes when done
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BehaviorFlow {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// Checked in order; the first whose condition holds is taken
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub transitions: Vec<BehaviorTransition>,
+	/// Target once the behavior completes without a transition firing
+	/// (state name or `next`; defaults to the next behavior in the list)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub then: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BehaviorTransition {
+	pub when: BehaviorCondition,
+	/// State name, or `next`
+	pub goto: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum BehaviorCondition {
+	/// Health at or below this fraction of max
+	HealthBelow { fraction: f32 },
+	/// Any living player within this distance
+	PlayerWithin { radius: f32 },
+	/// The formation leader this enemy was following has died
+	LeaderDead,
+	/// Left the play area after having entered it
+	OffScreen,
+	/// Seconds spent in the current state
+	After { seconds: f32 },
+	/// The state's entry roll is below this chance (0..1); several `Random` exits in order form a weighted branch
+	Random { chance: f32 },
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -568,7 +1017,14 @@ pub enum BehaviorType {
 	MoveStraight { velocity: Vec2 },
 	MoveSineWave { base_velocity: Vec2, amplitude: f32, frequency: f32, axis: SineAxis },
 	MoveCircular { center_offset: Vec2, radius: f32, angular_speed: f32, clockwise: bool },
-	MoveToPosition { target: Vec2, speed: f32, easing: EasingType },
+	/// With `until_arrived`, completes on reaching `target` when it has no duration
+	MoveToPosition {
+		target: Vec2,
+		speed: f32,
+		easing: EasingType,
+		#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+		until_arrived: bool,
+	},
 	FollowPlayer { speed: f32, max_distance: Option<f32>, offset: Vec2 },
 	FollowFormation { formation_id: String, position_index: usize, follow_speed: f32 },
 	Drift { velocity: Vec2, variance: f32 },
@@ -581,6 +1037,183 @@ pub enum BehaviorType {
 	FadeIn { fade_speed: f32 },
 	Flash { color: [f32; 4], frequency: f32 },
 	Parallel { behaviors: Vec<Behavior> },
+	/// Fly along a spline in screen space at `speed` units/sec; `mirror` flips it left/right
+	FollowPath {
+		path: PathRef,
+		speed: f32,
+		#[serde(default)]
+		easing: EasingType,
+		#[serde(default)]
+		mirror: bool,
+	},
+	/// Lock onto the nearest player on entry, telegraph for `windup` seconds, then dash along the locked line
+	Charge {
+		windup: f32,
+		speed: f32,
+		/// Seconds of dashing; the charge completes after `windup + dash_time` (None = dash until a transition or death)
+		#[serde(default)]
+		dash_time: Option<f32>,
+		#[serde(default = "default_charge_flash")]
+		flash_color: [f32; 4],
+		#[serde(default)]
+		explode: Option<ChargeExplosion>,
+	},
+}
+
+fn default_charge_flash() -> [f32; 4] {
+	[1.0, 0.3, 0.2, 1.0]
+}
+
+/// Blast a charging enemy sets off, hurting players and other enemies in range
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct ChargeExplosion {
+	pub radius: f32,
+	pub damage: f32,
+	#[serde(default)]
+	pub trigger: ExplodeTrigger,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ExplodeTrigger {
+	/// Detonate on touching a player during the dash
+	#[default]
+	OnContact,
+	/// Detonate when the dash ends (or earlier on touching a player)
+	AtEnd,
+}
+
+/// A flight path given inline or by the name of one of the level's `paths`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum PathRef {
+	Named(String),
+	Inline(FlightPath),
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PathCurve {
+	/// Passes through every point
+	#[default]
+	CatmullRom,
+	/// Chained cubic segments: start, control, control, end, control, control, end...
+	Bezier,
+}
+
+/// Control points in screen space (origin at the center, +Y up)
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FlightPath {
+	#[serde(default)]
+	pub curve: PathCurve,
+	pub points: Vec<Vec2>,
+	/// Flattened curve, baked when the level loads (or on first use)
+	#[serde(skip)]
+	baked: std::sync::OnceLock<BakedPath>,
+}
+
+/// A `FlightPath` flattened into a polyline with the arc length at each point
+#[derive(Clone, Debug)]
+pub struct BakedPath {
+	pub points: Vec<Vec2>,
+	/// Distance along the line at each point (starts at 0)
+	pub distances: Vec<f32>,
+}
+
+impl BakedPath {
+	pub fn length(&self) -> f32 {
+		self.distances.last().copied().unwrap_or(0.0)
+	}
+
+	/// Point `distance` along the line, clamped to its ends
+	pub fn point_at(&self, distance: f32) -> Option<Vec2> {
+		let index = self.distances.partition_point(|&d| d < distance);
+		match index {
+			0 => self.points.first().copied(),
+			i if i >= self.points.len() => self.points.last().copied(),
+			i => {
+				let span = self.distances[i] - self.distances[i - 1];
+				let t = if span > 0.0 { (distance - self.distances[i - 1]) / span } else { 1.0 };
+				Some(self.points[i - 1].lerp(self.points[i], t))
+			}
+		}
+	}
+}
+
+impl FlightPath {
+	const SAMPLES_PER_SEGMENT: usize = 16;
+
+	/// Why this path can't be flown, if it can't
+	pub fn validate(&self) -> Result<(), String> {
+		match self.curve {
+			PathCurve::CatmullRom if self.points.len() < 2 => Err("needs at least 2 points".to_string()),
+			PathCurve::Bezier if self.points.len() < 4 || !(self.points.len() - 1).is_multiple_of(3) => {
+				Err(format!("Bezier needs 3n+1 points, got {}", self.points.len()))
+			}
+			_ => Ok(()),
+		}
+	}
+
+	pub fn new(curve: PathCurve, points: Vec<Vec2>) -> Self {
+		Self { curve, points, baked: Default::default() }
+	}
+
+	/// The unmirrored polyline with its arc lengths, computed once
+	pub fn baked(&self) -> &BakedPath {
+		self.baked.get_or_init(|| {
+			let points = self.polyline(false);
+			let mut distances = Vec::with_capacity(points.len());
+			let mut total = 0.0;
+			for (i, point) in points.iter().enumerate() {
+				if i > 0 {
+					total += points[i - 1].distance(*point);
+				}
+				distances.push(total);
+			}
+			BakedPath { points, distances }
+		})
+	}
+
+	/// The curve flattened into a polyline, mirrored across X = 0 if asked
+	pub fn polyline(&self, mirror: bool) -> Vec<Vec2> {
+		let points: Vec<Vec2> = self.points.iter()
+			.map(|point| if mirror { Vec2::new(-point.x, point.y) } else { *point })
+			.collect();
+		if points.len() < 2 {
+			return points;
+		}
+
+		let mut line = vec![points[0]];
+		match self.curve {
+			PathCurve::CatmullRom => {
+				for i in 0..points.len() - 1 {
+					let p0 = points[i.saturating_sub(1)];
+					let p1 = points[i];
+					let p2 = points[i + 1];
+					let p3 = points[(i + 2).min(points.len() - 1)];
+					for step in 1..=Self::SAMPLES_PER_SEGMENT {
+						let t = step as f32 / Self::SAMPLES_PER_SEGMENT as f32;
+						let (t2, t3) = (t * t, t * t * t);
+						line.push(0.5 * (
+							2.0 * p1
+							+ (p2 - p0) * t
+							+ (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+							+ (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3
+						));
+					}
+				}
+			}
+			PathCurve::Bezier => {
+				for segment in points.windows(4).step_by(3) {
+					let [p0, c0, c1, p1] = [segment[0], segment[1], segment[2], segment[3]];
+					for step in 1..=Self::SAMPLES_PER_SEGMENT {
+						let t = step as f32 / Self::SAMPLES_PER_SEGMENT as f32;
+						let u = 1.0 - t;
+						line.push(u * u * u * p0 + 3.0 * u * u * t * c0 + 3.0 * u * t * t * c1 + t * t * t * p1);
+					}
+				}
+			}
+		}
+		line
+	}
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -689,6 +1322,18 @@ pub struct EnemyProjectileConfig {
 	pub burst_delay: f32, // Delay between burst shots
 }
 
+impl EnemyProjectileConfig {
+	/// Faster bullets and more of them per shot on harder difficulties
+	pub fn for_difficulty(self, difficulty: Difficulty) -> Self {
+		Self {
+			speed: self.speed * difficulty.bullet_speed_multiplier(),
+			count: difficulty.scale_bullet_count(self.count),
+			burst_count: difficulty.scale_bullet_count(self.burst_count),
+			..self
+		}
+	}
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum AimMode {
@@ -762,6 +1407,30 @@ impl EnemyFireConfig {
 			sockets: overrides.sockets.clone().unwrap_or_else(|| self.sockets.clone()),
 		}
 	}
+
+	/// Shorter cooldown and denser patterns on harder difficulties
+	pub fn for_difficulty(&self, difficulty: Difficulty) -> Self {
+		let pattern = match self.pattern {
+			FirePattern::Spread { count, angle_deg } => FirePattern::Spread {
+				count: difficulty.scale_bullet_count(count),
+				angle_deg,
+			},
+			FirePattern::Burst { count, interval } => FirePattern::Burst {
+				count: difficulty.scale_bullet_count(count),
+				interval,
+			},
+			FirePattern::Ring { count } => FirePattern::Ring {
+				count: difficulty.scale_bullet_count(count),
+			},
+			FirePattern::Single => FirePattern::Single,
+		};
+		Self {
+			aim: self.aim.clone(),
+			pattern,
+			cooldown: (self.cooldown * difficulty.fire_cooldown_multiplier()).max(0.05),
+			sockets: self.sockets.clone(),
+		}
+	}
 }
 
 #[derive(Component, Clone, Debug)]
@@ -788,6 +1457,10 @@ pub struct EnemyShooter {
 #[derive(Component)]
 pub struct EnemyPreviousPosition(pub Vec3);
 
+/// How fast the enemy moved last frame, in GU per second (kept by `rotate_enemies_to_movement`)
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub struct EnemyVelocity(pub Vec2);
+
 #[derive(Component, Default, Clone, Copy, Debug)]
 pub struct PlayerVelocity(pub Vec2);
 
@@ -797,17 +1470,35 @@ pub enum SineAxis {
 	Vertical,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub enum EasingType {
+	#[default]
 	Linear,
 	EaseIn,
 	EaseOut,
 	EaseInOut,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+impl EasingType {
+	/// Map linear progress (0..1) onto the eased curve
+	pub fn apply(self, t: f32) -> f32 {
+		let t = t.clamp(0.0, 1.0);
+		match self {
+			EasingType::Linear => t,
+			EasingType::EaseIn => t * t,
+			EasingType::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+			EasingType::EaseInOut => t * t * (3.0 - 2.0 * t),
+		}
+	}
+}
+
+/// When a behavior's conditional transitions are checked
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub enum TransitionType {
+	/// Every frame - a transition interrupts the behavior as soon as its condition holds
 	Immediate,
+	/// Only once the behavior completes (duration elapsed or target reached)
+	#[default]
 	WaitForCompletion,
 }
 
@@ -825,6 +1516,155 @@ pub struct FormationMember {
 	pub offset: Vec2,
 }
 
+/// What a formation's members do when its leader dies
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum LeaderDeathPolicy {
+	/// The member nearest the leader's slot takes over and the formation holds together
+	Promote,
+	/// Members break off and run their own behaviors
+	#[default]
+	Scatter,
+	/// Members break off and dive at the nearest player
+	Kamikaze,
+}
+
+/// A timed formation-wide move, applied around the leader
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FormationManeuver {
+	/// Seconds after the leader spawns
+	pub at: f32,
+	/// Seconds to blend the move in (ignored by `PeelOff`)
+	#[serde(default)]
+	pub duration: f32,
+	#[serde(flatten)]
+	pub kind: ManeuverKind,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum ManeuverKind {
+	/// Turn the whole formation by this many degrees (counter-clockwise)
+	Rotate { degrees: f32 },
+	/// Scale member spacing by this factor: above 1 expands, below 1 contracts
+	Scale { factor: f32 },
+	/// Release members one at a time, outermost first, every `interval` seconds
+	PeelOff { interval: f32 },
+}
+
+/// Runtime state of a formation's maneuvers, kept across leader promotions
+#[derive(Clone, Debug, Default)]
+pub struct FormationPlan {
+	pub maneuvers: Vec<FormationManeuver>,
+	pub on_leader_death: LeaderDeathPolicy,
+	/// Seconds since the leader spawned
+	pub elapsed: f32,
+	/// Members released by `PeelOff` so far
+	pub peeled: usize,
+	/// Members ease into their slots at this rate (per second, exponential) instead of snapping
+	pub follow_rate: Option<f32>,
+}
+
+impl FormationPlan {
+	pub fn new(maneuvers: Vec<FormationManeuver>, on_leader_death: LeaderDeathPolicy, follow_rate: Option<f32>) -> Self {
+		Self { maneuvers, on_leader_death, elapsed: 0.0, peeled: 0, follow_rate }
+	}
+
+	/// Rotation (radians) and spacing scale the maneuvers have reached by now
+	pub fn current_shape(&self) -> (f32, f32) {
+		let mut rotation = 0.0;
+		let mut scale = 1.0;
+		for maneuver in &self.maneuvers {
+			let progress = if maneuver.duration > 0.0 {
+				((self.elapsed - maneuver.at) / maneuver.duration).clamp(0.0, 1.0)
+			} else if self.elapsed >= maneuver.at {
+				1.0
+			} else {
+				0.0
+			};
+			match maneuver.kind {
+				ManeuverKind::Rotate { degrees } => rotation += degrees.to_radians() * progress,
+				ManeuverKind::Scale { factor } => scale *= 1.0 + (factor - 1.0) * progress,
+				ManeuverKind::PeelOff { .. } => {}
+			}
+		}
+		(rotation, scale)
+	}
+
+	/// How many members `PeelOff` should have released by now
+	pub fn peel_due(&self) -> usize {
+		self.maneuvers.iter()
+			.filter_map(|maneuver| match maneuver.kind {
+				ManeuverKind::PeelOff { interval } if self.elapsed >= maneuver.at => {
+					Some(((self.elapsed - maneuver.at) / interval.max(0.05)) as usize + 1)
+				}
+				_ => None,
+			})
+			.sum()
+	}
+}
+
+// === Carrier System ===
+
+/// Launches child enemies from its weapon sockets on a timer
+#[derive(Component)]
+pub struct EnemySpawner {
+	pub config: EnemySpawnerConfig,
+	pub timer: Timer,
+	/// Children still alive, counted against `max_children`
+	pub children: Vec<Entity>,
+	/// Round-robin index into the launch sockets
+	pub next_socket: usize,
+}
+
+impl EnemySpawner {
+	pub fn new(config: EnemySpawnerConfig) -> Self {
+		Self {
+			timer: Timer::from_seconds(config.interval.max(0.1), TimerMode::Repeating),
+			config,
+			children: Vec::new(),
+			next_socket: 0,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EnemySpawnerConfig {
+	/// Enemy type name, as in a level's `enemy_type`
+	pub child_type: String,
+	/// Seconds between launches
+	pub interval: f32,
+	pub max_children: usize,
+	/// Children per launch, spread over the sockets
+	#[serde(default = "default_per_launch")]
+	pub per_launch: usize,
+	#[serde(default)]
+	pub sockets: SocketSelector,
+	/// Behavior template each child starts with (defaults to the child type's own)
+	#[serde(default)]
+	pub behaviors: Vec<Behavior>,
+	#[serde(default)]
+	pub on_parent_death: OrphanPolicy,
+}
+
+fn default_per_launch() -> usize { 1 }
+
+/// What a carrier's children do when it dies
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum OrphanPolicy {
+	/// Removed along with the carrier
+	Despawn,
+	/// Blown outward, then carry on (their `LeaderDead` transitions fire)
+	#[default]
+	Scatter,
+}
+
+/// A child launched by a carrier
+#[derive(Component)]
+pub struct SpawnedBy {
+	pub parent: Entity,
+	pub on_parent_death: OrphanPolicy,
+}
+
 #[derive(Component, Clone, Debug)]
 pub struct WeaponSocket {
 	pub id: String,
@@ -841,6 +1681,50 @@ pub struct EnemyWeaponSockets {
 #[derive(Resource, Default)]
 pub struct FormationRegistry {
 	pub formations: HashMap<String, Entity>,
+	/// Maneuvers and leader-death policy per formation id
+	pub plans: HashMap<String, FormationPlan>,
+}
+
+// === Fragmentation ===
+
+/// Split table (`fragments` in the enemy manifest): the enemies a destroyed enemy breaks into.
+/// Fragments keep the parent's momentum plus an outward push, evenly spread with some jitter.
+#[derive(Clone, Debug, Deserialize)]
+pub struct FragmentTable {
+	/// Manifest key of the enemy each fragment spawns as
+	pub child: String,
+	pub min_count: u32,
+	pub max_count: u32,
+	/// Outward speed added on top of the parent's velocity
+	pub spread_speed: f32,
+	/// How far from the parent's center fragments appear
+	pub spawn_radius: f32,
+}
+
+// === Threat Warnings ===
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThreatClass {
+	/// Asteroids and debris
+	Hazard,
+	Light,
+	/// Rammers and interceptors that close in quickly
+	Fast,
+	Heavy,
+	Boss,
+}
+
+impl ThreatClass {
+	/// Color of the off-screen arrow
+	pub fn color(&self) -> Color {
+		match self {
+			ThreatClass::Hazard => Color::srgb(0.75, 0.65, 0.5),
+			ThreatClass::Light => Color::srgb(1.0, 0.85, 0.2),
+			ThreatClass::Fast => Color::srgb(1.0, 0.5, 0.1),
+			ThreatClass::Heavy => Color::srgb(1.0, 0.2, 0.2),
+			ThreatClass::Boss => Color::srgb(1.0, 0.1, 0.6),
+		}
+	}
 }
 
 // === Particle Components ===
@@ -863,6 +1747,104 @@ pub struct FloatingDamageNumber {
 	pub velocity: Vec2,
 }
 
+// === Difficulty ===
+
+/// Difficulty preset picked in the menu; scales enemy toughness and fire and the player's shield regen
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+	Easy,
+	#[default]
+	Normal,
+	Hard,
+	Insane,
+}
+
+impl Difficulty {
+	pub fn all() -> [Difficulty; 4] {
+		[Difficulty::Easy, Difficulty::Normal, Difficulty::Hard, Difficulty::Insane]
+	}
+
+	pub fn name(&self) -> &'static str {
+		match self {
+			Difficulty::Easy => "Easy",
+			Difficulty::Normal => "Normal",
+			Difficulty::Hard => "Hard",
+			Difficulty::Insane => "Insane",
+		}
+	}
+
+	/// Parse a difficulty name, case-insensitively (for `--difficulty=`)
+	pub fn from_name(name: &str) -> Option<Self> {
+		Self::all().into_iter().find(|difficulty| difficulty.name().eq_ignore_ascii_case(name))
+	}
+
+	pub fn enemy_health_multiplier(&self) -> f32 {
+		match self {
+			Difficulty::Easy => 0.7,
+			Difficulty::Normal => 1.0,
+			Difficulty::Hard => 1.4,
+			Difficulty::Insane => 2.0,
+		}
+	}
+
+	/// Multiplier on enemy fire cooldowns (below 1 fires faster)
+	pub fn fire_cooldown_multiplier(&self) -> f32 {
+		match self {
+			Difficulty::Easy => 1.4,
+			Difficulty::Normal => 1.0,
+			Difficulty::Hard => 0.8,
+			Difficulty::Insane => 0.6,
+		}
+	}
+
+	pub fn bullet_speed_multiplier(&self) -> f32 {
+		match self {
+			Difficulty::Easy => 0.8,
+			Difficulty::Normal => 1.0,
+			Difficulty::Hard => 1.15,
+			Difficulty::Insane => 1.3,
+		}
+	}
+
+	/// Multiplier on bullets per spread, ring or burst
+	pub fn bullet_count_multiplier(&self) -> f32 {
+		match self {
+			Difficulty::Easy => 0.75,
+			Difficulty::Normal => 1.0,
+			Difficulty::Hard => 1.25,
+			Difficulty::Insane => 1.5,
+		}
+	}
+
+	/// Multiplier on the player's shield regen (shield1 rate, and how soon/fast shield2 refills)
+	pub fn shield_regen_multiplier(&self) -> f32 {
+		match self {
+			Difficulty::Easy => 1.5,
+			Difficulty::Normal => 1.0,
+			Difficulty::Hard => 0.75,
+			Difficulty::Insane => 0.5,
+		}
+	}
+
+	/// The next difficulty up, if any
+	pub fn next(&self) -> Option<Self> {
+		match self {
+			Difficulty::Easy => Some(Difficulty::Normal),
+			Difficulty::Normal => Some(Difficulty::Hard),
+			Difficulty::Hard => Some(Difficulty::Insane),
+			Difficulty::Insane => None,
+		}
+	}
+
+	/// Scale a multi-bullet count; single shots stay single
+	pub fn scale_bullet_count(&self, count: u8) -> u8 {
+		if count <= 1 {
+			return count;
+		}
+		(count as f32 * self.bullet_count_multiplier()).round().clamp(2.0, u8::MAX as f32) as u8
+	}
+}
+
 // === Collision & Health Components ===
 
 #[derive(Component)]
@@ -876,7 +1858,8 @@ impl Health {
 		Self { current: max, max }
 	}
 
-	pub fn for_enemy_type(enemy_type: EnemyType) -> Self {
+	/// Base health of the enemy type, scaled by difficulty
+	pub fn for_enemy_type(enemy_type: EnemyType, difficulty: Difficulty) -> Self {
 		let max = match enemy_type {
 			EnemyType::Scout => 10.0,
 			EnemyType::ScoutSting => 500.0,
@@ -894,7 +1877,206 @@ impl Health {
 			EnemyType::StationDebris => 20.0,
 			EnemyType::AsteroidTurret => 180.0,
 		};
-		Self::new(max)
+		Self::new(max * difficulty.enemy_health_multiplier())
+	}
+}
+
+// === Damage Types ===
+
+/// What a hit is made of; enemies take more or less of each (see `DamageResistances`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageType {
+	Kinetic,
+	Energy,
+	Explosive,
+	Electric,
+}
+
+/// Damage multipliers per damage type (1.0 = normal, below resists, above is weak)
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DamageResistances {
+	pub kinetic: f32,
+	pub energy: f32,
+	pub explosive: f32,
+	pub electric: f32,
+}
+
+impl DamageResistances {
+	pub const NEUTRAL: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+
+	pub const fn new(kinetic: f32, energy: f32, explosive: f32, electric: f32) -> Self {
+		Self { kinetic, energy, explosive, electric }
+	}
+
+	pub fn multiplier(&self, damage_type: DamageType) -> f32 {
+		match damage_type {
+			DamageType::Kinetic => self.kinetic,
+			DamageType::Energy => self.energy,
+			DamageType::Explosive => self.explosive,
+			DamageType::Electric => self.electric,
+		}
+	}
+
+	pub fn for_enemy_type(enemy_type: EnemyType) -> Self {
+		match enemy_type {
+			// Light hulls: slugs punch straight through
+			EnemyType::Scout | EnemyType::ScoutSting | EnemyType::Fighter | EnemyType::Interceptor => {
+				Self::new(1.25, 1.0, 1.0, 1.0)
+			}
+			// Unshielded electronics
+			EnemyType::Drone => Self::new(1.0, 1.0, 1.0, 1.5),
+			// Volatile payload
+			EnemyType::Bomber => Self::new(1.0, 1.0, 1.5, 1.0),
+			// Armored hulls shrug off slugs
+			EnemyType::Corvette | EnemyType::HeavyGunship => Self::new(0.6, 1.0, 1.25, 1.0),
+			EnemyType::Boss => Self::new(0.8, 1.0, 1.0, 0.8),
+			EnemyType::Drill => Self::new(0.75, 1.25, 1.0, 1.0),
+			// Rock soaks up heat and arcs, and cracks under blasts
+			EnemyType::SmallAsteroid | EnemyType::MediumAsteroid | EnemyType::LargeAsteroid
+			| EnemyType::AsteroidTurret => Self::new(1.0, 0.5, 1.5, 0.75),
+			EnemyType::StationDebris => Self::new(1.0, 0.75, 1.25, 1.0),
+		}
+	}
+}
+
+/// Cuts damage from hits landing on the enemy's front: within `half_arc` radians
+/// of the direction it faces (local +Y, which `rotate_enemies_to_movement` aims along travel)
+#[derive(Component, Clone, Copy, Debug)]
+pub struct FrontShield {
+	pub half_arc: f32,
+	pub multiplier: f32,
+}
+
+impl FrontShield {
+	pub fn for_enemy_type(enemy_type: EnemyType) -> Option<Self> {
+		match enemy_type {
+			EnemyType::AsteroidTurret => Some(Self { half_arc: 60f32.to_radians(), multiplier: 0.2 }),
+			_ => None,
+		}
+	}
+}
+
+/// Regenerating energy shield on an enemy, soaked up before its `Health` (like the player's shield2)
+#[derive(Component, Clone, Copy, Debug)]
+pub struct EnemyShield {
+	pub current: f32,
+	pub max: f32,
+	/// Seconds after the last hit before it starts recharging
+	pub regen_delay: f32,
+	pub regen_per_sec: f32,
+	pub since_hit: f32,
+	/// How hits on the shield look, in place of the enemy's own `FxPolicy::on_hit`
+	pub hit_fx: HitFx,
+	/// Bubble sprite (a child of the enemy) lit up by `HitFx::ShieldShimmer`
+	pub bubble: Option<Entity>,
+	/// 1.0 on the frame the shield soaks a hit, fading to 0
+	pub flash: f32,
+}
+
+impl EnemyShield {
+	pub fn for_enemy_type(enemy_type: EnemyType, difficulty: Difficulty) -> Option<Self> {
+		let (max, regen_delay, regen_per_sec) = match enemy_type {
+			EnemyType::HeavyGunship => (60.0, 3.0, 20.0),
+			_ => return None,
+		};
+		let max = max * difficulty.enemy_health_multiplier();
+		Some(Self {
+			current: max,
+			max,
+			regen_delay,
+			regen_per_sec,
+			since_hit: 0.0,
+			hit_fx: HitFx::ShieldShimmer,
+			bubble: None,
+			flash: 0.0,
+		})
+	}
+
+	pub fn is_up(&self) -> bool {
+		self.current > 0.0
+	}
+
+	/// Soak as much of `damage` as the shield has left; returns what gets through to the hull
+	pub fn absorb(&mut self, damage: f32) -> f32 {
+		self.since_hit = 0.0;
+		if !self.is_up() {
+			return damage;
+		}
+		let soaked = damage.min(self.current);
+		self.current -= soaked;
+		self.flash = 1.0;
+		damage - soaked
+	}
+}
+
+/// A destructible armor plate; a child entity of the enemy core it covers
+#[derive(Component)]
+pub struct ArmorPlate {
+	pub core: Entity,
+}
+
+/// Plates still covering an enemy. Until they're all gone the core only takes `core_multiplier` damage.
+#[derive(Component, Clone, Debug)]
+pub struct ArmorPlates {
+	pub plates: Vec<Entity>,
+	pub core_multiplier: f32,
+}
+
+impl ArmorPlates {
+	pub fn is_exposed(&self) -> bool {
+		self.plates.is_empty()
+	}
+}
+
+/// One plate in an `ArmorLayout`, placed relative to the enemy's center
+#[derive(Clone, Copy, Debug)]
+pub struct ArmorPlateSpec {
+	pub offset: Vec2,
+	pub radius: f32,
+	pub health: f32,
+}
+
+/// Per-type armor plates and how they look
+#[derive(Clone, Debug)]
+pub struct ArmorLayout {
+	pub sprite: &'static str,
+	pub tint: Color,
+	pub core_multiplier: f32,
+	pub plates: Vec<ArmorPlateSpec>,
+}
+
+impl ArmorLayout {
+	pub fn for_enemy_type(enemy_type: EnemyType) -> Option<Self> {
+		let plate = |x: f32, y: f32, radius: f32, health: f32| ArmorPlateSpec { offset: Vec2::new(x, y), radius, health };
+		match enemy_type {
+			// Wing plates and a nose plate over the hull
+			EnemyType::HeavyGunship => Some(Self {
+				sprite: "particles/debris_metal.png",
+				tint: Color::srgb(0.65, 0.7, 0.78),
+				core_multiplier: 0.25,
+				plates: vec![
+					plate(-48.0, 8.0, 22.0, 40.0),
+					plate(48.0, 8.0, 22.0, 40.0),
+					plate(0.0, -48.0, 20.0, 40.0),
+				],
+			}),
+			// A rocky crust around the core
+			EnemyType::LargeAsteroid => {
+				let ring = 34.0;
+				Some(Self {
+					sprite: "enemies/small_asteroid.png",
+					tint: Color::srgb(0.8, 0.75, 0.7),
+					core_multiplier: 0.5,
+					plates: [45f32, 135.0, 225.0, 315.0].iter()
+						.map(|angle| {
+							let offset = Vec2::from_angle(angle.to_radians()) * ring;
+							plate(offset.x, offset.y, 18.0, 30.0)
+						})
+						.collect(),
+				})
+			}
+			_ => None,
+		}
 	}
 }
 
@@ -927,26 +2109,37 @@ pub struct PlayerDefenses {
 	pub shield2_regen_start_time: Option<f64>,
 	/// Shield2 value at regen start (so regen eases from that value to max).
 	pub shield2_regen_from: f32,
+
+	pub shield2_regen_delay: f64,
+	pub shield2_regen_duration: f64,
+	pub shield1_regen_per_sec: f32,
 }
 
 impl Default for PlayerDefenses {
 	fn default() -> Self {
+		Self::from_profile(&DefenseProfile::default())
+	}
+}
+
+impl PlayerDefenses {
+	pub fn from_profile(profile: &DefenseProfile) -> Self {
 		Self {
-			shield2: 75.0,
-			shield2_max: 75.0,
-			shield1: 200.0,
-			shield1_max: 200.0,
-			armor: 100.0,
-			armor_max: 100.0,
+			shield2: profile.shield2_max,
+			shield2_max: profile.shield2_max,
+			shield1: profile.shield1_max,
+			shield1_max: profile.shield1_max,
+			armor: profile.armor_max,
+			armor_max: profile.armor_max,
 			shield1_grace: false,
 			last_damage_time: 0.0,
 			shield2_regen_start_time: None,
-			shield2_regen_from: 75.0,
+			shield2_regen_from: profile.shield2_max,
+			shield2_regen_delay: profile.shield2_regen_delay,
+			shield2_regen_duration: profile.shield2_regen_duration,
+			shield1_regen_per_sec: profile.shield1_regen_per_sec,
 		}
 	}
-}
 
-impl PlayerDefenses {
 	/// Apply damage. Grace between shield2→shield1, punch-through from shield1→armor.
 	pub fn take_damage(&mut self, damage: f32) -> DamageSink {
 		let mut remaining = damage;
@@ -1172,6 +2365,13 @@ pub struct EnemyHitEvent {
 	pub enemy: Entity,
 	pub damage: f32,
 	pub hit_sound: Option<&'static str>, // Optional custom hit sound (None = default)
+	/// Damage-over-time stack to leave on the enemy
+	pub dot: Option<DamageOverTime>,
+	/// Push to give the enemy (direction * strength)
+	pub knockback: Vec2,
+	pub damage_type: DamageType,
+	/// Where the hit came from, for front shields (None = ignores them)
+	pub source: Option<Vec2>,
 }
 
 #[derive(Event)]
@@ -1179,6 +2379,8 @@ pub struct EnemyDeathEvent {
 	pub entity: Entity,
 	pub position: Vec2,
 	pub enemy_type: EnemyType,
+	/// False when the enemy took itself out (e.g. a kamikaze detonating): no rewards or fragments
+	pub killed_by_player: bool,
 }
 
 /// Marker: this entity is in a death animation (e.g. shader dissolve) and should no longer interact.
@@ -1227,9 +2429,9 @@ pub struct DefenseParticle {
 	pub lifetime: Timer,
 }
 
-// === Charge Meter Resource ===
+// === Charge Meter (per player) ===
 
-#[derive(Resource)]
+#[derive(Component)]
 pub struct ChargeMeter {
 	pub current: f32,
 	pub max: f32,
@@ -1291,6 +2493,8 @@ pub enum IdleFx {
 pub enum HitFx {
 	None,
 	ShaderFlash,
+	/// Light up the enemy's shield bubble (used by `EnemyShield`)
+	ShieldShimmer,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -1340,3 +2544,53 @@ impl Default for ShaderEffects {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn path(curve: PathCurve, points: &[[f32; 2]]) -> FlightPath {
+		FlightPath::new(curve, points.iter().map(|p| Vec2::from_array(*p)).collect())
+	}
+
+	#[test]
+	fn flight_path_validate_point_counts() {
+		assert!(path(PathCurve::CatmullRom, &[[0.0, 0.0]]).validate().is_err());
+		assert!(path(PathCurve::CatmullRom, &[[0.0, 0.0], [1.0, 0.0]]).validate().is_ok());
+
+		let bezier = |count: usize| path(PathCurve::Bezier, &vec![[0.0, 0.0]; count]);
+		assert!(bezier(3).validate().is_err());
+		assert!(bezier(4).validate().is_ok());
+		assert!(bezier(5).validate().is_err());
+		assert!(bezier(7).validate().is_ok());
+	}
+
+	#[test]
+	fn catmull_rom_polyline_hits_every_point() {
+		let flight = path(PathCurve::CatmullRom, &[[-100.0, 50.0], [0.0, 0.0], [100.0, 50.0]]);
+		let line = flight.polyline(false);
+		assert_eq!(line.len(), 1 + 2 * FlightPath::SAMPLES_PER_SEGMENT);
+		for (i, point) in flight.points.iter().enumerate() {
+			assert!(line[i * FlightPath::SAMPLES_PER_SEGMENT].distance(*point) < 1e-3);
+		}
+	}
+
+	#[test]
+	fn mirrored_polyline_flips_x() {
+		let flight = path(PathCurve::Bezier, &[[-300.0, 400.0], [-100.0, 0.0], [100.0, 0.0], [300.0, 400.0]]);
+		for (plain, mirrored) in flight.polyline(false).iter().zip(flight.polyline(true)) {
+			assert_eq!(mirrored, Vec2::new(-plain.x, plain.y));
+		}
+	}
+
+	#[test]
+	fn baked_path_measures_arc_length() {
+		// Collinear control points: a straight 300 unit line
+		let flight = path(PathCurve::Bezier, &[[0.0, 0.0], [100.0, 0.0], [200.0, 0.0], [300.0, 0.0]]);
+		let baked = flight.baked();
+		assert!((baked.length() - 300.0).abs() < 1e-2);
+		assert!(baked.point_at(150.0).unwrap().distance(Vec2::new(150.0, 0.0)) < 1e-2);
+		assert_eq!(baked.point_at(-10.0), Some(Vec2::ZERO));
+		assert_eq!(baked.point_at(1000.0), Some(Vec2::new(300.0, 0.0)));
+	}
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use bevy::prelude::Vec2;
-use crate::components::{Behavior, BehaviorType, SineAxis, TransitionType, ParallaxLayer, EnemyFireOverrides};
+use std::collections::HashMap;
+use crate::components::{Behavior, BehaviorType, SineAxis, TransitionType, BehaviorFlow, ParallaxLayer, EnemyFireOverrides, FlightPath, PathRef,
+	FormationManeuver, LeaderDeathPolicy, EnemySpawnerConfig, Difficulty};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LevelData {
@@ -18,6 +20,9 @@ pub struct LevelData {
 	pub geography: Vec<Geography>,
 	#[serde(default)]
 	pub enemy_waves: Vec<EnemyWave>,
+	/// Named flight paths that `FollowPath` behaviors can reference
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub paths: HashMap<String, FlightPath>,
 	#[serde(default)]
 	pub doodads: Vec<DoodadSpawn>,
 	#[serde(default)]
@@ -26,6 +31,89 @@ pub struct LevelData {
 	pub tutorials: Vec<Tutorial>,
 }
 
+impl LevelData {
+	/// Problems with the level's flight paths: invalid paths and `FollowPath` names that aren't defined
+	pub fn path_problems(&self) -> Vec<String> {
+		let mut problems: Vec<String> = self.paths.iter()
+			.filter_map(|(name, path)| path.validate().err().map(|err| format!("path '{}': {}", name, err)))
+			.collect();
+		for wave in &self.enemy_waves {
+			let spawns = wave.enemies.iter().map(|enemy| (&enemy.enemy_type, &enemy.behaviors));
+			let templates = wave.formations.iter().flat_map(|template| [
+				(&template.enemy_type, &template.behaviors),
+				(&template.enemy_type, &template.member_behaviors),
+			]);
+			for (enemy_type, behaviors) in spawns.chain(templates) {
+				let mut path_refs = Vec::new();
+				collect_path_refs(behaviors, &mut path_refs);
+				for path in path_refs {
+					match path {
+						PathRef::Named(name) if !self.paths.contains_key(name) => {
+							problems.push(format!("{} at {:.0} follows unknown path '{}'", enemy_type, wave.spawn_distance, name));
+						}
+						PathRef::Inline(inline) => {
+							if let Err(err) = inline.validate() {
+								problems.push(format!("{} at {:.0} inline path: {}", enemy_type, wave.spawn_distance, err));
+							}
+						}
+						_ => {}
+					}
+				}
+			}
+		}
+		problems
+	}
+
+	/// Flatten every flight path (named and inline) up front so enemies don't bake them on their first frame
+	fn bake_paths(&self) {
+		for path in self.paths.values() {
+			path.baked();
+		}
+		for wave in &self.enemy_waves {
+			let mut path_refs = Vec::new();
+			for enemy in &wave.enemies {
+				collect_path_refs(&enemy.behaviors, &mut path_refs);
+			}
+			for path in path_refs {
+				if let PathRef::Inline(inline) = path {
+					inline.baked();
+				}
+			}
+		}
+	}
+
+	/// Copy with geography expanded into doodads and structure_grids into structures.
+	/// Authored doodads/structures keep their indices; generated ones are appended.
+	/// Flight paths come out baked.
+	pub fn expanded(&self) -> LevelData {
+		let mut expanded = self.clone();
+		for geo in &self.geography {
+			expanded.doodads.extend(geo.expand_to_doodads());
+		}
+		for grid in &self.structure_grids {
+			expanded.structures.extend(grid.expand_to_structures());
+		}
+		for (wave_idx, wave) in expanded.enemy_waves.iter_mut().enumerate() {
+			for (template_idx, template) in wave.formations.iter().enumerate() {
+				wave.enemies.extend(template.expand(format!("wave{}_formation{}", wave_idx, template_idx)));
+			}
+		}
+		expanded.bake_paths();
+		expanded
+	}
+}
+
+/// Every `FollowPath` reference in a behavior list, including inside `Parallel`
+fn collect_path_refs<'a>(behaviors: &'a [Behavior], out: &mut Vec<&'a PathRef>) {
+	for behavior in behaviors {
+		match &behavior.behavior_type {
+			BehaviorType::FollowPath { path, .. } => out.push(path),
+			BehaviorType::Parallel { behaviors } => collect_path_refs(behaviors, out),
+			_ => {}
+		}
+	}
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BackdropItem {
 	pub sprite: String,
@@ -54,6 +142,9 @@ pub struct EnemyWave {
 	pub spawn_distance: f32,  // Spawn when player has traveled this far
 	#[serde(default)]
 	pub enemies: Vec<EnemySpawn>,
+	/// Shape templates, expanded into `enemies` by `LevelData::expanded()`
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub formations: Vec<FormationTemplate>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -76,6 +167,21 @@ pub struct EnemySpawn {
 	pub formation_role: Option<FormationRole>,
 	#[serde(default)]
 	pub formation_offset: Option<[f32; 2]>,
+	/// Leader only: timed formation-wide moves
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub formation_maneuvers: Vec<FormationManeuver>,
+	/// Leader only: what the members do when it dies (default Scatter)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub on_leader_death: Option<LeaderDeathPolicy>,
+	/// Leader only: members ease into their slots at this rate instead of snapping (per second)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub formation_follow_rate: Option<f32>,
+	/// Launch child enemies at runtime (replaces the type's built-in carrier setup)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub spawner: Option<EnemySpawnerConfig>,
+	/// Only spawn on this difficulty or harder
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub min_difficulty: Option<Difficulty>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -84,6 +190,136 @@ pub enum FormationRole {
 	Member,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum FormationShape {
+	/// Leader at the point, members trailing back on both sides
+	V,
+	/// Side by side, leader in the middle
+	Line,
+	/// Leader in the middle of a ring
+	Circle,
+	/// Rows of equal width, leader at the front center
+	Grid,
+	/// A filled triangle behind the leader
+	Arrow,
+}
+
+/// A whole formation in one entry: expands into a leader spawn plus `count - 1` member spawns
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FormationTemplate {
+	/// Defaults to one unique per wave and template
+	#[serde(default)]
+	pub id: Option<String>,
+	pub shape: FormationShape,
+	/// Ships including the leader
+	pub count: usize,
+	#[serde(default = "default_formation_spacing")]
+	pub spacing: f32,
+	pub enemy_type: String,
+	/// Defaults to `enemy_type`
+	#[serde(default)]
+	pub leader_type: Option<String>,
+	/// Leader position, like an `EnemySpawn`'s
+	pub position: [f32; 2],
+	/// Leader behaviors; the formation follows it
+	#[serde(default)]
+	pub behaviors: Vec<Behavior>,
+	/// Behaviors members run once they leave the formation
+	#[serde(default)]
+	pub member_behaviors: Vec<Behavior>,
+	#[serde(default)]
+	pub firing: Option<EnemyFireOverrides>,
+	#[serde(default)]
+	pub maneuvers: Vec<FormationManeuver>,
+	#[serde(default)]
+	pub on_leader_death: LeaderDeathPolicy,
+	/// Members ease into their slots at this rate instead of snapping (per second)
+	#[serde(default)]
+	pub follow_rate: Option<f32>,
+}
+
+fn default_formation_spacing() -> f32 { 60.0 }
+
+impl FormationShape {
+	/// Slot offsets from the leader (slot 0 is the leader itself), +Y being behind a formation flying down
+	pub fn slots(self, count: usize, spacing: f32) -> Vec<Vec2> {
+		let mut slots = vec![Vec2::ZERO];
+		match self {
+			FormationShape::V | FormationShape::Line => {
+				let depth = if matches!(self, FormationShape::V) { spacing * 0.8 } else { 0.0 };
+				for i in 1..count {
+					let rank = i.div_ceil(2) as f32;
+					let side = if i % 2 == 1 { -1.0 } else { 1.0 };
+					slots.push(Vec2::new(side * rank * spacing, rank * depth));
+				}
+			}
+			FormationShape::Circle => {
+				let ring = count.saturating_sub(1);
+				let radius = (spacing * ring as f32 / std::f32::consts::TAU).max(spacing);
+				for i in 0..ring {
+					let angle = std::f32::consts::TAU * i as f32 / ring as f32;
+					slots.push(Vec2::from_angle(angle) * radius);
+				}
+			}
+			FormationShape::Grid => {
+				let columns = (count as f32).sqrt().ceil().max(1.0) as usize;
+				let mut cells: Vec<Vec2> = (0..count)
+					.map(|i| {
+						let (row, column) = (i / columns, i % columns);
+						let row_width = columns.min(count - row * columns);
+						Vec2::new((column as f32 - (row_width - 1) as f32 * 0.5) * spacing, row as f32 * spacing)
+					})
+					.collect();
+				// Leader takes the front cell nearest the center
+				cells.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x.abs().total_cmp(&b.x.abs())));
+				slots = cells.iter().map(|cell| *cell - cells[0]).collect();
+			}
+			FormationShape::Arrow => {
+				let mut row = 1;
+				while slots.len() < count {
+					for column in 0..=row {
+						if slots.len() >= count {
+							break;
+						}
+						slots.push(Vec2::new((column as f32 - row as f32 * 0.5) * spacing, row as f32 * spacing * 0.8));
+					}
+					row += 1;
+				}
+			}
+		}
+		slots.truncate(count.max(1));
+		slots
+	}
+}
+
+impl FormationTemplate {
+	/// The leader and member spawns this template stands for
+	pub fn expand(&self, default_id: String) -> Vec<EnemySpawn> {
+		let id = self.id.clone().unwrap_or(default_id);
+		self.shape.slots(self.count, self.spacing).into_iter().enumerate()
+			.map(|(slot, offset)| {
+				let leader = slot == 0;
+				EnemySpawn {
+					enemy_type: if leader { self.leader_type.clone().unwrap_or_else(|| self.enemy_type.clone()) } else { self.enemy_type.clone() },
+					// Same Y for everyone so the whole formation spawns on the same frame
+					position: [self.position[0] + offset.x, self.position[1]],
+					movement: None,
+					behaviors: if leader { self.behaviors.clone() } else { self.member_behaviors.clone() },
+					firing: self.firing.clone(),
+					formation_id: Some(id.clone()),
+					formation_role: Some(if leader { FormationRole::Leader } else { FormationRole::Member }),
+					formation_offset: (!leader).then_some([offset.x, offset.y]),
+					formation_maneuvers: if leader { self.maneuvers.clone() } else { Vec::new() },
+					on_leader_death: leader.then_some(self.on_leader_death),
+					formation_follow_rate: self.follow_rate.filter(|_| leader),
+					spawner: None,
+					min_difficulty: None,
+				}
+			})
+			.collect()
+	}
+}
+
 impl EnemySpawn {
 	pub fn get_behaviors(&self) -> Vec<Behavior> {
 		if !self.behaviors.is_empty() {
@@ -108,6 +344,7 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			},
 			"PassBy" => Behavior {
 				behavior_type: BehaviorType::MoveStraight {
@@ -115,6 +352,7 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			},
 			"Circle" => Behavior {
 				behavior_type: BehaviorType::MoveCircular {
@@ -125,6 +363,7 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			},
 			"Straight" => Behavior {
 				behavior_type: BehaviorType::MoveStraight {
@@ -132,6 +371,7 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			},
 			_ => Behavior {
 				behavior_type: BehaviorType::MoveStraight {
@@ -139,6 +379,7 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			},
 		}
 	}
@@ -149,6 +390,7 @@ impl EnemySpawn {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -120.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"Fighter" => vec![Behavior {
 				behavior_type: BehaviorType::MoveSineWave {
@@ -159,11 +401,13 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"Interceptor" => vec![Behavior {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -250.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"Drone" => vec![Behavior {
 				behavior_type: BehaviorType::Drift {
@@ -172,11 +416,13 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"Bomber" => vec![Behavior {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -60.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"HeavyGunship" => vec![Behavior {
 				behavior_type: BehaviorType::MoveCircular {
@@ -187,26 +433,31 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"Corvette" => vec![Behavior {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -90.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"SmallAsteroid" => vec![Behavior {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -200.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"MediumAsteroid" => vec![Behavior {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -150.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"LargeAsteroid" => vec![Behavior {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -100.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"StationDebris" => vec![Behavior {
 				behavior_type: BehaviorType::Drift {
@@ -215,16 +466,19 @@ impl EnemySpawn {
 				},
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			"AsteroidTurret" => vec![Behavior {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -60.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 			_ => vec![Behavior {
 				behavior_type: BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -100.0) },
 				duration: None,
 				transition: TransitionType::WaitForCompletion,
+				flow: BehaviorFlow::default(),
 			}],
 		}
 	}
@@ -280,6 +534,23 @@ pub enum DoodadLayer {
 	Foreground,
 }
 
+impl DoodadLayer {
+	/// Scroll speed relative to the gameplay layer
+	pub fn speed_multiplier(&self) -> f32 {
+		match self {
+			DoodadLayer::DeepSpace => ParallaxLayer::DeepSpace.speed_multiplier(),
+			DoodadLayer::FarField => ParallaxLayer::FarField.speed_multiplier(),
+			DoodadLayer::DeepStructures => ParallaxLayer::DeepStructures.speed_multiplier(),
+			DoodadLayer::MegaStructures => ParallaxLayer::MegaStructures.speed_multiplier(),
+			DoodadLayer::MidDistance => ParallaxLayer::MidDistance.speed_multiplier(),
+			DoodadLayer::StructureDetails => ParallaxLayer::StructureDetails.speed_multiplier(),
+			DoodadLayer::NearBackground => ParallaxLayer::NearBackground.speed_multiplier(),
+			DoodadLayer::Gameplay => 1.0,
+			DoodadLayer::Foreground => ParallaxLayer::Foreground.speed_multiplier(),
+		}
+	}
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DoodadSpawn {
 	pub spawn_distance: f32,  // Spawn when player has traveled this far
@@ -1004,6 +1275,9 @@ pub struct LevelDataV2 {
 	pub doodad_pools: Vec<DoodadPool>,
 	#[serde(default)]
 	pub enemy_waves: Vec<EnemyWave>,
+	/// Named flight paths that `FollowPath` behaviors can reference
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub paths: HashMap<String, FlightPath>,
 	#[serde(default)]
 	pub events: Vec<LevelEvent>,
 	#[serde(default)]
@@ -1182,6 +1456,7 @@ impl LevelDataV2 {
 			structure_grids: Vec::new(),
 			geography: Vec::new(),
 			enemy_waves: self.enemy_waves.clone(),
+			paths: self.paths.clone(),
 			doodads,
 			events: self.events.clone(),
 			tutorials: self.tutorials.clone(),
@@ -1285,6 +1560,9 @@ pub struct LevelDataV3 {
 	pub sections: Vec<Section>,
 	#[serde(default)]
 	pub enemy_waves: Vec<EnemyWave>,
+	/// Named flight paths that `FollowPath` behaviors can reference
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub paths: HashMap<String, FlightPath>,
 	#[serde(default)]
 	pub events: Vec<LevelEvent>,
 	#[serde(default)]
@@ -1488,6 +1766,7 @@ impl LevelDataV3 {
 			structure_grids: Vec::new(),
 			geography: Vec::new(),
 			enemy_waves: self.enemy_waves.clone(),
+			paths: self.paths.clone(),
 			doodads,
 			events: self.events.clone(),
 			tutorials: self.tutorials.clone(),
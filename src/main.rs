@@ -14,29 +14,48 @@ mod materials;
 use materials::{MaterialsPlugin, ProjectileMaterial, ProjectileMaterialHandles};
 
 use systems::background::{scroll_background, spawn_background};
-use systems::player::{spawn_player, player_movement};
-use systems::weapons::{fire_weapons, move_projectiles_straight, move_projectiles_sine, move_angled_projectiles, move_homing_projectiles, manage_orbital_entities, orbital_auto_fire, cleanup_projectiles};
+use systems::player::{spawn_player, read_player_input, player_movement, update_focus_mode};
+use systems::weapons::{fire_weapons, move_projectiles_straight, move_projectiles_sine, move_angled_projectiles, move_homing_projectiles, manage_orbital_entities, orbital_auto_fire, cleanup_projectiles, update_evolution_beams};
 use systems::lightning::{update_charge_meter, render_lightning_bolts, render_lightning_arcs, spawn_pending_baby_whips, cleanup_lightning_visuals, render_lightning_impacts, render_lightning_aoe, update_lightning_glitter, render_lightning_glitter, render_defensive_field, update_defensive_field_damage, DefensiveFieldHitTracker};
 use systems::audio::{PlaySfxEvent, SfxGateConfig, SfxGateState, process_sfx_gate, process_fading_sounds};
 use systems::level::{load_level, update_level_timer, process_enemy_waves, process_doodads, update_distance_locked, process_level_events, process_tutorials, process_phases, apply_doodad_drift, scroll_doodads, cleanup_doodads, MusicState, TitleMusicState, MusicEnabled, DebugSpeed, toggle_debug_speed, toggle_music, SelectedLevel, GamePaused, toggle_pause, InfoOverlayEnabled, toggle_info_overlay, play_title_music, stop_title_music, SoundVolume, adjust_sound_volume};
 use systems::parallax::{init_parallax_timers, spawn_procedural_parallax, scroll_parallax, cleanup_parallax};
-use systems::enemies::{update_enemy_movement, cleanup_enemies, execute_enemy_behaviors, update_formations, setup_enemy_shooters, enemy_shooting, move_enemy_projectiles, init_enemy_rotation, rotate_enemies_to_movement, shimmer_enemies};
-use systems::menu::{setup_ship_selection_menu, handle_ship_selection, handle_weapon_selection, handle_start_game, cleanup_menu};
-use systems::weapon_upgrade::{handle_weapon_switch, handle_weapon_upgrade, handle_player_hit, debug_weapon_controls};
+use systems::formations::{update_formations, peel_off_formation_members, handle_formation_leader_deaths};
+use systems::carriers::{launch_carrier_children, handle_orphaned_children};
+use systems::fragmentation::fragment_enemies;
+use systems::charge::{telegraph_charges, detonate_chargers};
+use systems::offscreen_warnings::{IndicatorMesh, update_offscreen_indicators, warn_incoming_bosses, update_warning_banner};
+use systems::enemies::{update_enemy_movement, cleanup_enemies, execute_enemy_behaviors, setup_enemy_shooters, enemy_shooting, move_enemy_projectiles, init_enemy_rotation, rotate_enemies_to_movement, shimmer_enemies};
+use systems::menu::{setup_ship_selection_menu, handle_ship_selection, handle_weapon_selection, handle_sub_weapon_selection, handle_difficulty_selection, handle_coop_selection, handle_start_game, cleanup_menu};
+use systems::weapon_upgrade::{handle_weapon_switch, swap_weapons, handle_weapon_upgrade, handle_player_hit, debug_weapon_controls};
 use systems::pickups::{collect_pickups, move_pickups, cleanup_pickups};
-use components::{FormationRegistry, WeaponSwitchEvent, WeaponUpgradeEvent, PlayerHitEvent, EnemyHitEvent, EnemyDeathEvent, ShipType, WeaponType, ChargeMeter};
+use systems::status_effects::{tick_damage_over_time, apply_enemy_knockback};
+use systems::evolution::{spawn_evolution_pickups, cycle_evolution_pickups, collect_evolution_pickups};
+use components::{FormationRegistry, WeaponSwitchEvent, WeaponUpgradeEvent, EvolutionOfferEvent, PlayerHitEvent, EnemyHitEvent, EnemyDeathEvent, ShipType, WeaponType, Difficulty};
 use systems::particles::{spawn_engine_particles, update_particles, spawn_player_hit_particles, spawn_enemy_hit_particles, spawn_floating_damage_numbers, update_floating_damage_numbers};
 use systems::collision::{check_projectile_enemy_collisions, apply_enemy_damage, check_player_enemy_collisions, update_invincibility, check_enemy_projectile_player_collisions, update_shield2_regen, update_shield1_regen, play_enemy_hit_sound, play_enemy_death_sound, play_player_hit_sound};
 use systems::visual::{apply_atmospheric_tint, apply_ambient_occlusion};
 use systems::world::WORLD_HEIGHT;
 use systems::info_overlay::{spawn_info_overlay, update_info_overlay, toggle_info_overlay_visibility};
-use systems::player_hud::{spawn_player_hud, animate_defense_hexagons, update_digital_display_text, update_charge_meter_ui, render_enhanced_mode_sparks, render_capacitor_glow, reset_hud_spawn_state, HudSpawnState};
+use systems::player_hud::{spawn_player_hud, animate_defense_hexagons, update_digital_display_text, update_bomb_stock_text, update_graze_count_text, update_lives_text, update_weapon_slot_text, update_player_two_hud_text, update_charge_meter_ui, render_enhanced_mode_sparks, render_capacitor_glow, reset_hud_spawn_state, HudSpawnState};
+use systems::enemy_defenses::{update_enemy_shields, apply_armor_plate_damage, shed_armor_on_death};
 use systems::effects::{apply_shader_hit_flash, update_shader_effects, cleanup_dissolved_entities};
 use systems::death_fx::process_enemy_death_fx;
 use systems::sprite_animation::{animate_sprite_frames, cleanup_oneshot_effects};
 use systems::thruster_fx::animate_thrusters;
 use systems::hanabi_fx::{setup_explosion_effects, cleanup_explosion_effects, spawn_debug_effect_grid};
-use resources::{SelectedShip, SelectedWeapon, GameState, BloomLevel, DamageNumbersEnabled, EnemyAssetRegistry};
+use systems::level_editor::{LevelEditorState, editor_inactive, toggle_level_editor, editor_scrub_distance, editor_mouse_input, editor_keyboard_input, render_level_editor, update_level_editor_panel};
+use systems::timeline::{TimelineJumpEvent, timeline_debug_controls, handle_timeline_jump};
+use systems::hot_reload::{HotReloadState, hot_reload_level, hot_reload_enemy_manifest, hot_reload_weapon_tuning};
+use systems::weapon_tuning::WeaponTuning;
+use systems::abilities::{trigger_ship_ability, update_ship_abilities, update_ability_barriers};
+use systems::graze::detect_grazes;
+use systems::level_tally::{LevelTallyState, reset_level_tally, show_level_tally};
+use systems::lives::{Lives, game_running, reset_lives, update_checkpoint, award_extra_lives, handle_player_death, handle_continue};
+use systems::rank::{Rank, reset_rank, update_rank};
+use systems::bomb::{BombStock, reset_bomb_stock, trigger_bomb, update_bomb_flash, move_score_items, spawn_bomb_drops, collect_bomb_pickups};
+use systems::debug_overlay::{DebugOverlayEnabled, toggle_debug_overlay, render_debug_hitboxes, render_debug_sockets_and_formations, render_debug_player_ranges, render_debug_wave_timeline};
+use resources::{SelectedShip, SelectedWeapon, SelectedDifficulty, HighScores, CoopEnabled, GameState, BloomLevel, DamageNumbersEnabled, EnemyAssetRegistry, Score};
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use materials::noise::{generate_noise_texture, EffectsNoiseTexture};
 
@@ -57,12 +76,15 @@ fn main() {
 		println!("  --volume=N               Set sound volume 0-100 (default: 100)");
 		println!("  --bloom=N                Set bloom glow 0-100 (default: 0=off)");
 		println!("  --no-music               Disable music");
+		println!("  --coop                   Two players: second ship on gamepad or numpad");
+		println!("  --difficulty=NAME        Easy, Normal (default), Hard or Insane");
 		println!("  --help, -h               Show this help message");
 		return;
 	}
 
 	let skip_menu = args.iter().any(|arg| arg == "--skip-menu" || arg == "--random");
 	let no_music = args.iter().any(|arg| arg == "--no-music");
+	let coop = args.iter().any(|arg| arg == "--coop");
 
 	// Parse --start=N argument
 	let start_distance: f32 = args.iter()
@@ -88,6 +110,15 @@ fn main() {
 		.unwrap_or(0)
 		.min(100); // Clamp to max 100
 
+	// Parse --difficulty=NAME argument (default Normal)
+	let difficulty = args.iter()
+		.find_map(|arg| arg.strip_prefix("--difficulty="))
+		.map(|name| Difficulty::from_name(name).unwrap_or_else(|| {
+			println!("⚠️  Unknown difficulty '{}', using Normal", name);
+			Difficulty::Normal
+		}))
+		.unwrap_or_default();
+
 	if volume_percent != 100 {
 		println!("🔊 Starting with volume: {}%", volume_percent);
 	}
@@ -96,8 +127,16 @@ fn main() {
 		println!("✨ Bloom level: {}%", bloom_level);
 	}
 
+	if difficulty != Difficulty::Normal {
+		println!("🎚️  Difficulty: {}", difficulty.name());
+	}
+
+	if coop {
+		println!("👥 Co-op: player 2 on gamepad (or numpad)");
+	}
+
 	// If skipping menu, select random ship and weapon
-	let (initial_ship, initial_weapon, initial_state) = if skip_menu {
+	let (initial_ship, initial_weapon, initial_sub_weapon, initial_state) = if skip_menu {
 		let mut rng = rand::thread_rng();
 
 		let ships = ShipType::all();
@@ -114,11 +153,17 @@ fn main() {
 		];
 		let random_weapon = weapons[rng.gen_range(0..weapons.len())];
 
-		println!("🎲 Random selection: {:?} with {:?}", random_ship, random_weapon);
+		let subs: Vec<WeaponType> = weapons.iter()
+			.copied()
+			.filter(|w| w.can_be_sub_weapon() && *w != random_weapon)
+			.collect();
+		let random_sub = subs[rng.gen_range(0..subs.len())];
 
-		(Some(random_ship), random_weapon, GameState::Playing)
+		println!("🎲 Random selection: {:?} with {:?} + {:?}", random_ship, random_weapon, random_sub);
+
+		(Some(random_ship), random_weapon, Some(random_sub), GameState::Playing)
 	} else {
-		(None, WeaponType::BasicBlaster, GameState::ShipSelection)
+		(None, WeaponType::BasicBlaster, None, GameState::ShipSelection)
 	};
 
 	App::new()
@@ -137,7 +182,11 @@ fn main() {
 		.add_plugins(HanabiPlugin)
 		.insert_state(initial_state)
 		.insert_resource(SelectedShip { ship_type: initial_ship })
-		.insert_resource(SelectedWeapon { weapon_type: initial_weapon })
+		.insert_resource(SelectedWeapon { weapon_type: initial_weapon, sub_weapon_type: initial_sub_weapon })
+		.insert_resource(SelectedDifficulty { difficulty })
+		.insert_resource(HighScores::load_from_disk())
+		.insert_resource(CoopEnabled(coop))
+		.insert_resource(WeaponTuning::load_from_disk())
 		.init_resource::<MusicState>()
 		.init_resource::<TitleMusicState>()
 		.insert_resource(MusicEnabled::new(!no_music))
@@ -146,8 +195,16 @@ fn main() {
 		.insert_resource(DebugSpeed::new())
 		.init_resource::<GamePaused>()
 		.init_resource::<HudSpawnState>()
+		.init_resource::<BombStock>()
+		.init_resource::<Score>()
+		.init_resource::<LevelTallyState>()
+		.init_resource::<Lives>()
+		.init_resource::<Rank>()
+		.init_resource::<IndicatorMesh>()
 		.insert_resource(InfoOverlayEnabled(true))
-		.init_resource::<ChargeMeter>()
+		.init_resource::<DebugOverlayEnabled>()
+		.init_resource::<LevelEditorState>()
+		.init_resource::<HotReloadState>()
 		.init_resource::<DefensiveFieldHitTracker>()
 		.insert_resource(SoundVolume::new(initial_volume))
 		.insert_resource(BloomLevel::new(bloom_level))
@@ -157,23 +214,25 @@ fn main() {
 		.add_event::<PlaySfxEvent>()
 		.add_event::<WeaponSwitchEvent>()
 		.add_event::<WeaponUpgradeEvent>()
+		.add_event::<EvolutionOfferEvent>()
 		.add_event::<PlayerHitEvent>()
 		.add_event::<EnemyHitEvent>()
 		.add_event::<EnemyDeathEvent>()
+		.add_event::<TimelineJumpEvent>()
 		// Startup: camera only
 		.add_systems(Startup, (setup, spawn_exit_button, setup_explosion_effects).chain())
 		// Menu state systems
 		.add_systems(OnEnter(GameState::ShipSelection), (setup_ship_selection_menu, play_title_music))
 		.add_systems(
 			Update,
-			(handle_ship_selection, handle_weapon_selection, handle_start_game, toggle_music)
+			(handle_ship_selection, handle_weapon_selection, handle_sub_weapon_selection, handle_difficulty_selection, handle_coop_selection, handle_start_game, toggle_music)
 				.run_if(in_state(GameState::ShipSelection))
 		)
 		.add_systems(OnExit(GameState::ShipSelection), (cleanup_menu, stop_title_music))
 		// Playing state: spawn game on enter
 		.add_systems(
 			OnEnter(GameState::Playing),
-			(reset_hud_spawn_state, spawn_background, init_parallax_timers, spawn_player, load_level, spawn_info_overlay).chain()
+			(reset_hud_spawn_state, reset_bomb_stock, reset_level_tally, reset_lives, reset_rank, spawn_background, init_parallax_timers, spawn_player, load_level, spawn_info_overlay).chain()
 		)
 		// Exit button and info button work in all states
 		.add_systems(Update, (exit_button_system, info_button_system))
@@ -183,19 +242,19 @@ fn main() {
 			scroll_parallax,
 			spawn_procedural_parallax,
 			cleanup_parallax,
-			player_movement,
+			(read_player_input, update_focus_mode, player_movement).chain().run_if(game_running).run_if(editor_inactive),
 			toggle_debug_speed,
-			toggle_pause,
+			toggle_pause.run_if(game_running),
 			toggle_info_overlay,
 			toggle_music,
 			adjust_sound_volume,
-			update_level_timer,
+			update_level_timer.run_if(game_running),
 			update_info_overlay,
 			toggle_info_overlay_visibility,
 		).run_if(in_state(GameState::Playing)))
 		// Charge meter must run before fire_weapons to set pending_fire_tier
 		.add_systems(Update, (update_charge_meter, fire_weapons).chain()
-			.run_if(in_state(GameState::Playing)))
+			.run_if(in_state(GameState::Playing)).run_if(editor_inactive).run_if(game_running))
 		.add_systems(Update, (
 			move_projectiles_straight,
 			move_projectiles_sine,
@@ -208,7 +267,8 @@ fn main() {
 			cleanup_lightning_visuals,
 			update_lightning_glitter,
 			update_defensive_field_damage,
-		).run_if(in_state(GameState::Playing)))
+			update_evolution_beams,
+		).run_if(in_state(GameState::Playing)).run_if(game_running))
 		// Centralized SFX gate (priority/cooldowns/budget) + fading.
 		.add_systems(PostUpdate, (process_sfx_gate, process_fading_sounds))
 		.add_systems(Update, (
@@ -216,16 +276,21 @@ fn main() {
 			move_pickups,
 			cleanup_pickups,
 			handle_weapon_switch,
+			swap_weapons,
 			handle_weapon_upgrade,
 			handle_player_hit,
 			debug_weapon_controls,
-		).run_if(in_state(GameState::Playing)))
+			spawn_evolution_pickups,
+			cycle_evolution_pickups,
+			collect_evolution_pickups,
+		).run_if(in_state(GameState::Playing)).run_if(game_running))
 		.add_systems(Update, (
 			process_phases,
 			process_enemy_waves,
 			animate_sprite_frames,
 			cleanup_oneshot_effects,
 			update_enemy_movement,
+			(handle_formation_leader_deaths, peel_off_formation_members).before(execute_enemy_behaviors),
 			execute_enemy_behaviors,
 			update_formations,
 			init_enemy_rotation,
@@ -234,16 +299,22 @@ fn main() {
 			setup_enemy_shooters,
 			enemy_shooting,
 			move_enemy_projectiles,
-		).run_if(in_state(GameState::Playing)))
+			launch_carrier_children,
+			handle_orphaned_children.before(execute_enemy_behaviors),
+			fragment_enemies.after(apply_enemy_damage),
+			// Before contact checks so a detonating charger's collider is already gone
+			detonate_chargers.before(execute_enemy_behaviors).before(check_player_enemy_collisions),
+			telegraph_charges.after(execute_enemy_behaviors).after(shimmer_enemies),
+		).run_if(in_state(GameState::Playing)).run_if(editor_inactive).run_if(game_running))
 		.add_systems(Update, (
 			cleanup_enemies,
-			process_doodads,
+			process_doodads.run_if(editor_inactive),
 			update_distance_locked,
 			scroll_doodads,
 			apply_doodad_drift,
 			cleanup_doodads,
-			process_level_events,
-			process_tutorials,
+			process_level_events.run_if(editor_inactive),
+			process_tutorials.run_if(editor_inactive),
 			animate_thrusters,
 			spawn_engine_particles,
 			update_particles,
@@ -257,21 +328,24 @@ fn main() {
 		// Collision systems
 		.add_systems(Update, (
 			check_projectile_enemy_collisions,
-			apply_enemy_damage,
+			tick_damage_over_time,
+			(apply_enemy_damage, apply_armor_plate_damage, update_enemy_shields),
+			apply_enemy_knockback,
 			apply_shader_hit_flash,
 			play_enemy_hit_sound,
 			spawn_enemy_hit_particles,
 			spawn_floating_damage_numbers,
 			play_enemy_death_sound,
-			process_enemy_death_fx,
+			(process_enemy_death_fx, shed_armor_on_death),
 			check_player_enemy_collisions,
 			check_enemy_projectile_player_collisions,
+			detect_grazes,
 			spawn_player_hit_particles,
 			play_player_hit_sound,
 			update_invincibility,
 			update_shield2_regen,
 			update_shield1_regen,
-		).chain().run_if(in_state(GameState::Playing)))
+		).chain().run_if(in_state(GameState::Playing)).run_if(editor_inactive).run_if(game_running))
 		// Lightning visual rendering
 		.add_systems(Update, (
 			render_lightning_bolts,
@@ -285,6 +359,12 @@ fn main() {
 			spawn_player_hud,
 			animate_defense_hexagons,
 			update_digital_display_text,
+			update_bomb_stock_text,
+			update_graze_count_text,
+			update_lives_text,
+			update_weapon_slot_text,
+			update_player_two_hud_text,
+			show_level_tally,
 			update_charge_meter_ui,
 			render_enhanced_mode_sparks,
 			render_capacitor_glow,
@@ -293,6 +373,57 @@ fn main() {
 			cleanup_explosion_effects,
 			spawn_debug_effect_grid,
 		).run_if(in_state(GameState::Playing)))
+		// Bomb (X): hits must land in the same frame's damage pass; drops follow deaths
+		.add_systems(Update, (
+			trigger_bomb.before(apply_enemy_damage),
+			update_bomb_flash,
+			move_score_items,
+			spawn_bomb_drops.after(apply_enemy_damage),
+			collect_bomb_pickups,
+		).run_if(in_state(GameState::Playing)).run_if(editor_inactive).run_if(game_running))
+		// Lives, checkpoints, continues and rank; deaths are resolved after the collision chain.
+		// Once every ship is down, game_running stops gameplay until a continue
+		.add_systems(Update, (
+			(update_checkpoint, award_extra_lives).run_if(game_running),
+			handle_player_death.after(update_shield1_regen),
+			handle_continue,
+			update_rank.run_if(game_running),
+		).run_if(in_state(GameState::Playing)).run_if(editor_inactive))
+		// Off-screen enemy arrows and the boss WARNING banner
+		.add_systems(Update, (
+			update_offscreen_indicators.after(rotate_enemies_to_movement),
+			warn_incoming_bosses,
+			update_warning_banner,
+		).run_if(in_state(GameState::Playing)).run_if(editor_inactive).run_if(game_running))
+		// Ship abilities (C)
+		.add_systems(Update, (
+			trigger_ship_ability,
+			update_ship_abilities,
+			update_ability_barriers,
+		).chain().after(player_movement).run_if(in_state(GameState::Playing)).run_if(editor_inactive).run_if(game_running))
+		// Level editor (F5)
+		.add_systems(Update, (
+			toggle_level_editor,
+			editor_scrub_distance,
+			editor_mouse_input,
+			editor_keyboard_input,
+			render_level_editor,
+			update_level_editor_panel,
+		).chain().run_if(in_state(GameState::Playing)))
+		// Timeline scrubbing (F6/F7)
+		.add_systems(Update, (timeline_debug_controls, handle_timeline_jump).chain()
+			.run_if(in_state(GameState::Playing)))
+		// Level YAML / enemy manifest / weapon tuning hot-reload
+		.add_systems(Update, (hot_reload_level, hot_reload_enemy_manifest, hot_reload_weapon_tuning).chain()
+			.run_if(in_state(GameState::Playing)))
+		// Debug gizmo overlay (F4)
+		.add_systems(Update, (
+			toggle_debug_overlay,
+			render_debug_hitboxes,
+			render_debug_sockets_and_formations,
+			render_debug_player_ranges,
+			render_debug_wave_timeline,
+		).run_if(in_state(GameState::Playing)))
 		.run();
 }
 
@@ -301,12 +432,13 @@ fn setup(
 	bloom_level: Res<BloomLevel>,
 	mut images: ResMut<Assets<Image>>,
 	mut projectile_materials: ResMut<Assets<ProjectileMaterial>>,
+	mut meshes: ResMut<Assets<Mesh>>,
 ) {
 	// Shared noise texture for shader effects (dissolve/glow, etc.)
 	// Safe to create once at startup; reused by all `EffectsMaterial` instances.
 	let noise = generate_noise_texture(&mut images);
 	commands.insert_resource(EffectsNoiseTexture(noise));
-	commands.insert_resource(ProjectileMaterialHandles::new(&mut projectile_materials));
+	commands.insert_resource(ProjectileMaterialHandles::new(&mut projectile_materials, &mut meshes));
 	commands.insert_resource(EnemyAssetRegistry::load_from_disk());
 
 	let mut camera = commands.spawn((
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy::render::render_resource::{AsBindGroup, ShaderRef, ShaderType};
 use bevy::sprite::{AlphaMode2d, Material2d};
+use std::collections::HashMap;
+use crate::components::WeaponEvolution;
 
 #[derive(Clone, Copy, ShaderType, Default)]
 pub struct ProjectileParams {
@@ -33,6 +35,34 @@ impl ProjectileMaterial {
 			},
 		}
 	}
+
+	/// Glow preset for an evolved weapon's projectiles, tinted to match the base weapon
+	pub fn evolution(evolution: WeaponEvolution) -> Self {
+		// (core color, glow color, radius, softness, glow width, glow intensity)
+		let (color, glow_color, radius, softness, glow_width, glow_intensity) = match evolution {
+			WeaponEvolution::PiercingBeam => ((0.5, 1.3, 1.6), (0.6, 1.8, 2.8), 0.95, 0.08, 0.5, 1.0),
+			WeaponEvolution::PlasmaNova => ((0.7, 1.2, 1.8), (1.2, 0.6, 2.6), 0.8, 0.25, 0.6, 1.1),
+			WeaponEvolution::TwinHelix => ((0.5, 1.5, 0.9), (0.4, 2.4, 1.2), 0.85, 0.15, 0.4, 0.8),
+			WeaponEvolution::Tsunami => ((0.3, 0.9, 1.6), (0.3, 1.4, 2.8), 0.9, 0.2, 0.45, 0.8),
+			WeaponEvolution::SevenWay => ((1.6, 0.9, 0.2), (2.6, 1.4, 0.3), 0.9, 0.12, 0.35, 0.8),
+			WeaponEvolution::RearGuard => ((1.5, 0.4, 0.3), (2.6, 0.7, 0.4), 0.9, 0.12, 0.35, 0.8),
+			WeaponEvolution::Swarm => ((1.4, 1.4, 0.6), (2.4, 2.0, 0.6), 0.85, 0.15, 0.4, 0.9),
+			WeaponEvolution::Torpedo => ((1.8, 0.6, 0.2), (2.8, 0.9, 0.2), 0.9, 0.18, 0.5, 1.2),
+			WeaponEvolution::PrismFan => ((1.2, 0.6, 1.8), (2.2, 1.0, 2.8), 0.95, 0.06, 0.4, 0.9),
+			WeaponEvolution::Crossfire => ((1.8, 0.5, 0.5), (2.8, 0.6, 0.9), 0.95, 0.06, 0.4, 0.9),
+		};
+		Self {
+			params: ProjectileParams {
+				color: LinearRgba::new(color.0, color.1, color.2, 1.0),
+				glow_color: LinearRgba::new(glow_color.0, glow_color.1, glow_color.2, 1.0),
+				radius,
+				softness,
+				glow_width,
+				glow_intensity,
+				_padding: 0.0,
+			},
+		}
+	}
 }
 
 impl Default for ProjectileMaterial {
@@ -56,12 +86,23 @@ impl Material2d for ProjectileMaterial {
 #[derive(Resource, Clone)]
 pub struct ProjectileMaterialHandles {
 	pub orange_pellet: Handle<ProjectileMaterial>,
+	/// Unit quad shared by evolved projectiles; sized through `Transform::scale`
+	pub quad: Handle<Mesh>,
+	evolutions: HashMap<WeaponEvolution, Handle<ProjectileMaterial>>,
 }
 
 impl ProjectileMaterialHandles {
-	pub fn new(materials: &mut Assets<ProjectileMaterial>) -> Self {
+	pub fn new(materials: &mut Assets<ProjectileMaterial>, meshes: &mut Assets<Mesh>) -> Self {
 		Self {
 			orange_pellet: materials.add(ProjectileMaterial::orange_pellet()),
+			quad: meshes.add(Rectangle::new(1.0, 1.0)),
+			evolutions: WeaponEvolution::ALL.iter()
+				.map(|&evolution| (evolution, materials.add(ProjectileMaterial::evolution(evolution))))
+				.collect(),
 		}
 	}
+
+	pub fn evolution(&self, evolution: WeaponEvolution) -> Handle<ProjectileMaterial> {
+		self.evolutions[&evolution].clone()
+	}
 }
@@ -1,9 +1,10 @@
 use bevy::prelude::*;
-use crate::components::{ShipType, WeaponType, EnemyType, CollisionShape};
-use serde::Deserialize;
-use std::collections::HashMap;
+use crate::components::{ShipType, WeaponType, EnemyType, CollisionShape, Difficulty, FragmentTable};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 use std::process::Command;
 
 #[derive(Resource, Default)]
@@ -14,16 +15,82 @@ pub struct SelectedShip {
 #[derive(Resource)]
 pub struct SelectedWeapon {
 	pub weapon_type: WeaponType,
+	/// Optional sub-weapon picked alongside the primary
+	pub sub_weapon_type: Option<WeaponType>,
 }
 
 impl Default for SelectedWeapon {
 	fn default() -> Self {
 		Self {
 			weapon_type: WeaponType::BasicBlaster,
+			sub_weapon_type: None,
 		}
 	}
 }
 
+#[derive(Resource, Default)]
+pub struct SelectedDifficulty {
+	pub difficulty: Difficulty,
+}
+
+const HIGH_SCORES_PATH: &str = "high_scores.yaml";
+
+/// Best score per difficulty, kept in `high_scores.yaml` next to where the game runs
+#[derive(Resource, Default, Deserialize, Serialize)]
+pub struct HighScores {
+	#[serde(default)]
+	pub best: BTreeMap<Difficulty, u64>,
+}
+
+impl HighScores {
+	pub fn load_from_disk() -> Self {
+		let Ok(yaml) = fs::read_to_string(HIGH_SCORES_PATH) else {
+			return Self::default();
+		};
+		match serde_yaml::from_str::<Self>(&yaml) {
+			Ok(scores) => scores,
+			Err(err) => {
+				warn!("Failed to parse high scores ({}): {}", HIGH_SCORES_PATH, err);
+				Self::default()
+			}
+		}
+	}
+
+	pub fn best(&self, difficulty: Difficulty) -> u64 {
+		self.best.get(&difficulty).copied().unwrap_or(0)
+	}
+
+	/// Keep `points` as the difficulty's best if it beats it; returns true if it did
+	pub fn insert(&mut self, difficulty: Difficulty, points: u64) -> bool {
+		if points == 0 || points <= self.best(difficulty) {
+			return false;
+		}
+		self.best.insert(difficulty, points);
+		true
+	}
+
+	/// Record a finished run's score; saves and returns true if it beats the difficulty's best
+	pub fn record(&mut self, difficulty: Difficulty, points: u64) -> bool {
+		if !self.insert(difficulty, points) {
+			return false;
+		}
+		info!("🏆 New {} high score: {}", difficulty.name(), points);
+		match serde_yaml::to_string(self) {
+			Ok(yaml) => {
+				if let Err(err) = fs::write(HIGH_SCORES_PATH, yaml) {
+					warn!("Failed to save high scores ({}): {}", HIGH_SCORES_PATH, err);
+				}
+			}
+			Err(err) => warn!("Failed to serialize high scores: {}", err),
+		}
+		true
+	}
+}
+
+/// Local co-op: spawn a second ship driven by a gamepad (or the numpad)
+#[derive(Resource, Default)]
+pub struct CoopEnabled(pub bool);
+
 const ENEMY_MANIFEST_PATH: &str = "assets/enemies/enemy_manifest.yaml";
 
 #[derive(Resource, Default)]
@@ -73,17 +140,50 @@ impl EnemyAssetRegistry {
 	pub fn get(&self, enemy_type: EnemyType) -> Option<&EnemySpriteMeta> {
 		self.entries.get(enemy_type.manifest_key())
 	}
+
+	/// Re-read the manifest in place. Keeps the current entries if the file is
+	/// missing or fails to parse (e.g. saved mid-edit).
+	pub fn reload(&mut self) -> bool {
+		let yaml = match read_manifest() {
+			Ok(contents) => contents,
+			Err(err) => {
+				warn!("Enemy manifest reload failed ({}): {}", ENEMY_MANIFEST_PATH, err);
+				return false;
+			}
+		};
+		match serde_yaml::from_str::<EnemyAssetManifest>(&yaml) {
+			Ok(manifest) => {
+				info!("🔄 Reloaded enemy manifest: {} entries", manifest.enemies.len());
+				self.entries = manifest.enemies;
+				true
+			}
+			Err(err) => {
+				error!("Failed to parse enemy manifest, keeping previous: {}", err);
+				false
+			}
+		}
+	}
+
+	/// Last modification time of the manifest file (for hot-reload polling)
+	pub fn manifest_modified() -> Option<SystemTime> {
+		fs::metadata(manifest_path()).and_then(|meta| meta.modified()).ok()
+	}
 }
 
-fn read_manifest() -> Result<String, std::io::Error> {
-	if let Ok(contents) = fs::read_to_string(ENEMY_MANIFEST_PATH) {
-		return Ok(contents);
+fn manifest_path() -> PathBuf {
+	let path = PathBuf::from(ENEMY_MANIFEST_PATH);
+	if path.exists() {
+		return path;
 	}
 	let exe_dir = std::env::current_exe()
 		.ok()
 		.and_then(|path| path.parent().map(|p| p.to_path_buf()))
 		.unwrap_or_default();
-	fs::read_to_string(exe_dir.join(ENEMY_MANIFEST_PATH))
+	exe_dir.join(ENEMY_MANIFEST_PATH)
+}
+
+fn read_manifest() -> Result<String, std::io::Error> {
+	fs::read_to_string(manifest_path())
 }
 
 fn try_generate_manifest() -> bool {
@@ -144,6 +244,9 @@ pub struct EnemySpriteMeta {
 	pub frame_group: Option<EnemyFrameGroup>,
 	#[serde(default)]
 	pub sockets: Vec<EnemySocketDef>,
+	/// What the enemy breaks into when it's destroyed
+	#[serde(default)]
+	pub fragments: Option<FragmentTable>,
 }
 
 #[derive(Deserialize, Clone)]
@@ -191,6 +294,14 @@ impl BloomLevel {
 	}
 }
 
+/// Player score for the current run
+#[derive(Resource, Default)]
+pub struct Score {
+	pub points: u64,
+	/// Enemy projectiles grazed (near-missed) this run
+	pub grazes: u32,
+}
+
 #[derive(Resource)]
 pub struct DamageNumbersEnabled(pub bool);
 
@@ -199,3 +310,39 @@ impl Default for DamageNumbersEnabled {
 		Self(true)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn high_scores_keep_the_best_per_difficulty() {
+		let mut scores = HighScores::default();
+		assert!(scores.insert(Difficulty::Normal, 1200));
+		assert!(!scores.insert(Difficulty::Normal, 800));
+		assert!(!scores.insert(Difficulty::Normal, 1200));
+		assert!(scores.insert(Difficulty::Normal, 1500));
+		assert!(scores.insert(Difficulty::Hard, 300));
+
+		assert_eq!(scores.best(Difficulty::Normal), 1500);
+		assert_eq!(scores.best(Difficulty::Hard), 300);
+		assert_eq!(scores.best(Difficulty::Easy), 0);
+	}
+
+	#[test]
+	fn high_scores_ignore_empty_runs() {
+		let mut scores = HighScores::default();
+		assert!(!scores.insert(Difficulty::Insane, 0));
+		assert!(scores.best.is_empty());
+	}
+
+	#[test]
+	fn high_scores_are_ordered_by_difficulty() {
+		let mut scores = HighScores::default();
+		for difficulty in [Difficulty::Insane, Difficulty::Easy, Difficulty::Hard, Difficulty::Normal] {
+			scores.insert(difficulty, 100);
+		}
+		let order: Vec<Difficulty> = scores.best.keys().copied().collect();
+		assert_eq!(order, vec![Difficulty::Easy, Difficulty::Normal, Difficulty::Hard, Difficulty::Insane]);
+	}
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use crate::components::{
+	Player, PlayerInput, PlayerDefenses, PlayerVelocity, Invincible, EnemyProjectile, ShipAbility, ShipAbilityState,
+};
+use crate::systems::level::GamePaused;
+use crate::systems::world::player_bounds;
+
+const DASH_SPEED: f32 = 2200.0;
+const DASH_IFRAMES_SECS: f32 = 0.35;
+const OVERCHARGE_ARMOR: f32 = 60.0;
+const SURGE_SHIELD1_FRACTION: f32 = 0.5;
+
+const BARRIER_SIZE: Vec2 = Vec2::new(170.0, 14.0);
+const BARRIER_OFFSET_Y: f32 = 85.0;
+const BARRIER_COLOR: Color = Color::srgba(0.4, 0.8, 1.0, 0.7);
+
+/// Bastion's deployable barrier; follows the ship that placed it and eats enemy projectiles
+#[derive(Component)]
+pub struct AbilityBarrier {
+	pub owner: Entity,
+	pub lifetime: Timer,
+}
+
+/// C (or player two's ability button): trigger that ship's ability when it's off cooldown
+#[allow(clippy::type_complexity)]
+pub fn trigger_ship_ability(
+	mut commands: Commands,
+	paused: Res<GamePaused>,
+	mut player_query: Query<(Entity, &Transform, &PlayerInput, &PlayerVelocity, &mut PlayerDefenses, &mut ShipAbilityState, Option<&Invincible>), With<Player>>,
+) {
+	if paused.0 {
+		return;
+	}
+	for (entity, transform, input, velocity, mut defenses, mut state, invincible) in player_query.iter_mut() {
+		if input.ability && state.cooldown.finished() {
+			activate_ability(&mut commands, entity, transform, velocity, &mut defenses, &mut state, invincible);
+		}
+	}
+}
+
+fn activate_ability(
+	commands: &mut Commands,
+	entity: Entity,
+	transform: &Transform,
+	velocity: &PlayerVelocity,
+	defenses: &mut PlayerDefenses,
+	state: &mut ShipAbilityState,
+	invincible: Option<&Invincible>,
+) {
+	state.cooldown.reset();
+	state.active.reset();
+
+	match state.ability {
+		ShipAbility::Dash => {
+			state.dash_direction = velocity.0.try_normalize().unwrap_or(Vec2::Y);
+			// Don't cut short a longer invincibility (e.g. from a bomb)
+			if invincible.is_none_or(|inv| inv.timer.remaining_secs() < DASH_IFRAMES_SECS) {
+				commands.entity(entity).insert(Invincible::new(DASH_IFRAMES_SECS));
+			}
+		}
+		ShipAbility::ShieldSurge => {
+			defenses.shield2 = defenses.shield2_max;
+			defenses.shield2_regen_start_time = None;
+			defenses.shield1 = (defenses.shield1 + defenses.shield1_max * SURGE_SHIELD1_FRACTION)
+				.min(defenses.shield1_max);
+		}
+		ShipAbility::ArmorOvercharge => {
+			defenses.armor += OVERCHARGE_ARMOR;
+		}
+		ShipAbility::Afterburner => {}
+		ShipAbility::Barrier => {
+			let pos = transform.translation;
+			commands.spawn((
+				Sprite {
+					color: BARRIER_COLOR,
+					custom_size: Some(BARRIER_SIZE),
+					..default()
+				},
+				Transform::from_xyz(pos.x, pos.y + BARRIER_OFFSET_Y, pos.z + 0.1),
+				AbilityBarrier {
+					owner: entity,
+					lifetime: Timer::from_seconds(state.ability.duration_secs(), TimerMode::Once),
+				},
+			));
+		}
+	}
+
+	info!("✨ Ship ability: {} (cooldown {:.0}s)", state.ability.name(), state.ability.cooldown_secs());
+}
+
+/// Tick ability timers and apply per-frame effects (dash motion, overcharge expiry)
+pub fn update_ship_abilities(
+	time: Res<Time>,
+	mut player_query: Query<(&mut Transform, &mut PlayerDefenses, &mut ShipAbilityState), With<Player>>,
+) {
+	for (mut transform, mut defenses, mut state) in player_query.iter_mut() {
+		state.cooldown.tick(time.delta());
+		let was_active = !state.active.finished();
+		state.active.tick(time.delta());
+
+		if state.is_active(ShipAbility::Dash) {
+			let step = state.dash_direction * DASH_SPEED * time.delta_secs();
+			transform.translation.x = (transform.translation.x + step.x).clamp(player_bounds::MIN_X, player_bounds::MAX_X);
+			transform.translation.y = (transform.translation.y + step.y).clamp(player_bounds::MIN_Y, player_bounds::MAX_Y);
+		}
+
+		// Overcharge ends: anything above max is lost
+		if was_active && state.active.finished() && state.ability == ShipAbility::ArmorOvercharge {
+			defenses.armor = defenses.armor.min(defenses.armor_max);
+		}
+	}
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_ability_barriers(
+	mut commands: Commands,
+	time: Res<Time>,
+	player_query: Query<&Transform, (With<Player>, Without<AbilityBarrier>)>,
+	mut barrier_query: Query<(Entity, &mut Transform, &mut Sprite, &mut AbilityBarrier)>,
+	projectile_query: Query<(Entity, &Transform), (With<EnemyProjectile>, Without<AbilityBarrier>)>,
+) {
+	for (entity, mut transform, mut sprite, mut barrier) in barrier_query.iter_mut() {
+		barrier.lifetime.tick(time.delta());
+		if barrier.lifetime.finished() {
+			commands.entity(entity).despawn();
+			continue;
+		}
+
+		if let Ok(owner_transform) = player_query.get(barrier.owner) {
+			let pos = owner_transform.translation;
+			transform.translation.x = pos.x;
+			transform.translation.y = pos.y + BARRIER_OFFSET_Y;
+		}
+		// Fade over the last second
+		let remaining = barrier.lifetime.remaining_secs();
+		sprite.color.set_alpha(BARRIER_COLOR.alpha() * remaining.min(1.0));
+
+		let center = transform.translation.truncate();
+		let half = BARRIER_SIZE / 2.0;
+		for (projectile_entity, projectile_transform) in projectile_query.iter() {
+			let offset = projectile_transform.translation.truncate() - center;
+			if offset.x.abs() <= half.x && offset.y.abs() <= half.y + 6.0 {
+				commands.entity(projectile_entity).despawn_recursive();
+			}
+		}
+	}
+}
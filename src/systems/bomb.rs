@@ -0,0 +1,250 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::{
+	Player, PlayerDown, PlayerInput, Enemy, EnemyProjectile, Invincible, Dying, ShaderEffects, BombPickup, ScoreItem,
+	EnemyHitEvent, EnemyDeathEvent, EnemyType, DamageType,
+};
+use crate::resources::Score;
+use crate::systems::audio::PlaySfxEvent;
+use crate::systems::rank::Rank;
+use crate::systems::hanabi_fx::{ParticleEffects, spawn_named_effect};
+use crate::systems::pickups::{PICKUP_RADIUS, touching_player};
+use crate::systems::world::{HALF_PLAY_WIDTH, HALF_WORLD_HEIGHT, sizes};
+
+const STARTING_BOMBS: u8 = 3;
+const MAX_BOMBS: u8 = 5;
+/// Enough to clear anything short of heavies/bosses in one go
+const BOMB_DAMAGE: f32 = 150.0;
+const BOMB_INVINCIBILITY_SECS: f32 = 2.5;
+/// Minimum time between bombs (covers the flash so they can't be chained in one frame)
+const BOMB_COOLDOWN_SECS: f32 = 1.0;
+const FLASH_DURATION_SECS: f32 = 0.45;
+
+const SCORE_ITEM_VALUE: u32 = 10;
+const SCORE_ITEM_SIZE: f32 = 8.0;
+const SCORE_ITEM_HOME_DELAY_SECS: f32 = 0.4;
+const SCORE_ITEM_HOME_SPEED: f32 = 700.0;
+
+/// Bomb stock plus the cooldown between uses
+#[derive(Resource)]
+pub struct BombStock {
+	pub count: u8,
+	pub cooldown: Timer,
+}
+
+impl Default for BombStock {
+	fn default() -> Self {
+		let mut cooldown = Timer::from_seconds(BOMB_COOLDOWN_SECS, TimerMode::Once);
+		cooldown.tick(cooldown.duration());
+		Self { count: STARTING_BOMBS, cooldown }
+	}
+}
+
+/// Full-screen white flash that fades out after a bomb
+#[derive(Component)]
+pub struct BombFlash {
+	pub timer: Timer,
+}
+
+/// Reset stock and score when a run starts - call this on OnEnter(Playing)
+pub fn reset_bomb_stock(mut commands: Commands) {
+	commands.insert_resource(BombStock::default());
+	commands.insert_resource(Score::default());
+}
+
+/// X (or player two's bomb button): detonate a bomb from the shared stock - damage everything on screen,
+/// cancel enemy shots into score, brief invincibility for the ship that bombed
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn trigger_bomb(
+	mut commands: Commands,
+	time: Res<Time>,
+	mut stock: ResMut<BombStock>,
+	mut rank: ResMut<Rank>,
+	player_query: Query<(Entity, &Transform, &PlayerInput), With<Player>>,
+	enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<Dying>)>,
+	projectile_query: Query<(Entity, &Transform), With<EnemyProjectile>>,
+	mut shader_query: Query<&mut ShaderEffects, With<Enemy>>,
+	mut hit_events: EventWriter<EnemyHitEvent>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+	particle_effects: Option<Res<ParticleEffects>>,
+) {
+	stock.cooldown.tick(time.delta());
+	let Some((player_entity, player_transform, _)) = player_query.iter().find(|(_, _, input)| input.bomb) else { return };
+	if stock.count == 0 || !stock.cooldown.finished() {
+		return;
+	}
+	stock.count -= 1;
+	stock.cooldown.reset();
+	rank.on_bomb();
+
+	let on_screen = |pos: Vec3| pos.x.abs() <= HALF_PLAY_WIDTH && pos.y.abs() <= HALF_WORLD_HEIGHT;
+
+	let mut enemies_hit = 0;
+	for (entity, transform) in enemy_query.iter() {
+		if !on_screen(transform.translation) {
+			continue;
+		}
+		hit_events.send(EnemyHitEvent {
+			enemy: entity,
+			damage: BOMB_DAMAGE,
+			hit_sound: None,
+			dot: None,
+			knockback: Vec2::ZERO,
+			damage_type: DamageType::Explosive,
+			source: None,
+		});
+		if let Ok(mut effects) = shader_query.get_mut(entity) {
+			effects.flash_amount = 1.0;
+			effects.flash_decay_speed = 3.0;
+		}
+		enemies_hit += 1;
+	}
+
+	let mut cancelled = 0;
+	for (entity, transform) in projectile_query.iter() {
+		commands.entity(entity).despawn_recursive();
+		if on_screen(transform.translation) {
+			spawn_score_item(&mut commands, transform.translation.truncate());
+			cancelled += 1;
+		}
+	}
+
+	commands.entity(player_entity).insert(Invincible::new(BOMB_INVINCIBILITY_SECS));
+
+	if let Some(effects) = particle_effects {
+		spawn_named_effect(&mut commands, &effects, "bomb_shockwave", player_transform.translation);
+	}
+	commands.spawn((
+		Sprite {
+			color: Color::srgba(1.0, 1.0, 1.0, 0.8),
+			custom_size: Some(Vec2::new(HALF_PLAY_WIDTH * 2.0, HALF_WORLD_HEIGHT * 2.0)),
+			..default()
+		},
+		Transform::from_xyz(0.0, 0.0, 20.0),
+		BombFlash {
+			timer: Timer::from_seconds(FLASH_DURATION_SECS, TimerMode::Once),
+		},
+	));
+	sfx_events.send(PlaySfxEvent::simple("sounds/explosion_large.ogg", 0.9, 200, 0.0));
+
+	info!("💣 Bomb! Hit {} enemies, cancelled {} shots ({} left)", enemies_hit, cancelled, stock.count);
+}
+
+fn spawn_score_item(commands: &mut Commands, position: Vec2) {
+	commands.spawn((
+		Sprite {
+			color: Color::srgb(1.0, 0.85, 0.2),
+			custom_size: Some(Vec2::splat(SCORE_ITEM_SIZE)),
+			..default()
+		},
+		Transform::from_xyz(position.x, position.y, 5.0)
+			.with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+		ScoreItem {
+			value: SCORE_ITEM_VALUE,
+			home_delay: Timer::from_seconds(SCORE_ITEM_HOME_DELAY_SECS, TimerMode::Once),
+		},
+	));
+}
+
+pub fn update_bomb_flash(
+	mut commands: Commands,
+	time: Res<Time>,
+	mut query: Query<(Entity, &mut BombFlash, &mut Sprite)>,
+) {
+	for (entity, mut flash, mut sprite) in query.iter_mut() {
+		flash.timer.tick(time.delta());
+		sprite.color.set_alpha(0.8 * (1.0 - flash.timer.fraction()));
+		if flash.timer.finished() {
+			commands.entity(entity).despawn();
+		}
+	}
+}
+
+/// Score items hang briefly where the shot was cancelled, then home to the nearest ship
+#[allow(clippy::type_complexity)]
+pub fn move_score_items(
+	mut commands: Commands,
+	time: Res<Time>,
+	mut score: ResMut<Score>,
+	player_query: Query<&Transform, (With<Player>, Without<PlayerDown>, Without<ScoreItem>)>,
+	mut item_query: Query<(Entity, &mut Transform, &mut ScoreItem)>,
+) {
+	let dt = time.delta_secs();
+
+	for (entity, mut transform, mut item) in item_query.iter_mut() {
+		item.home_delay.tick(time.delta());
+		if !item.home_delay.finished() {
+			continue;
+		}
+
+		let pos = transform.translation.truncate();
+		let Some(player_pos) = player_query.iter()
+			.map(|t| t.translation.truncate())
+			.min_by(|a, b| a.distance_squared(pos).total_cmp(&b.distance_squared(pos)))
+		else {
+			continue;
+		};
+		let to_player = player_pos - pos;
+		if to_player.length() < PICKUP_RADIUS * 0.5 {
+			score.points += item.value as u64;
+			commands.entity(entity).despawn();
+			continue;
+		}
+		let step = to_player.normalize_or_zero() * SCORE_ITEM_HOME_SPEED * dt;
+		transform.translation += step.extend(0.0);
+	}
+}
+
+/// Chance for a destroyed enemy to drop a bomb pickup
+fn bomb_drop_chance(enemy_type: EnemyType) -> f32 {
+	match enemy_type {
+		EnemyType::Boss => 1.0,
+		EnemyType::HeavyGunship | EnemyType::Corvette => 0.25,
+		EnemyType::Bomber | EnemyType::AsteroidTurret => 0.1,
+		_ => 0.02,
+	}
+}
+
+pub fn spawn_bomb_drops(
+	mut commands: Commands,
+	mut death_events: EventReader<EnemyDeathEvent>,
+) {
+	let mut rng = rand::thread_rng();
+	for event in death_events.read().filter(|event| event.killed_by_player) {
+		if rng.gen::<f32>() < bomb_drop_chance(event.enemy_type) {
+			spawn_bomb_pickup(&mut commands, event.position);
+		}
+	}
+}
+
+/// Spawn a bomb pickup that drifts down like other pickups
+pub fn spawn_bomb_pickup(commands: &mut Commands, position: Vec2) {
+	commands.spawn((
+		Sprite {
+			color: Color::srgb(1.0, 0.3, 0.6),
+			custom_size: Some(Vec2::splat(sizes::POWER_UP * 0.6)),
+			..default()
+		},
+		Transform::from_xyz(position.x, position.y, 5.0)
+			.with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+		BombPickup,
+	));
+}
+
+#[allow(clippy::type_complexity)]
+pub fn collect_bomb_pickups(
+	mut commands: Commands,
+	mut stock: ResMut<BombStock>,
+	player_query: Query<(Entity, &Transform), (With<Player>, Without<PlayerDown>)>,
+	pickup_query: Query<(Entity, &Transform), With<BombPickup>>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+	for (entity, pickup_transform) in pickup_query.iter() {
+		if touching_player(&player_query, pickup_transform.translation.truncate()).is_some() {
+			stock.count = (stock.count + 1).min(MAX_BOMBS);
+			commands.entity(entity).despawn();
+			sfx_events.send(PlaySfxEvent::simple("sounds/powerup_collect.ogg", 0.6, 80, 0.08));
+			info!("💣 Bomb collected ({} in stock)", stock.count);
+		}
+	}
+}
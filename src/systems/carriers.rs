@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy::ecs::entity::Entities;
+use rand::Rng;
+use crate::components::{
+	Enemy, EnemySpawner, SpawnedBy, OrphanPolicy, EnemyBehavior, EnemyWeaponSockets, KnockbackVelocity, Dying,
+};
+use crate::level::EnemySpawn;
+use super::enemies::resolve_sockets;
+use super::spawn::EnemySpawnAssets;
+use super::world::HALF_WORLD_HEIGHT;
+
+/// Outward push given to children when their carrier dies
+const SCATTER_SPEED: f32 = 600.0;
+
+/// Launch children from each carrier's sockets on its timer, up to its cap, once it's on screen
+pub fn launch_carrier_children(
+	mut commands: Commands,
+	mut spawn_assets: EnemySpawnAssets,
+	mut carrier_query: Query<(Entity, &Transform, Option<&EnemyWeaponSockets>, &mut EnemySpawner), Without<Dying>>,
+	alive_query: Query<(), (With<Enemy>, Without<Dying>)>,
+	time: Res<Time>,
+) {
+	for (carrier, transform, sockets, mut spawner) in carrier_query.iter_mut() {
+		spawner.children.retain(|child| alive_query.contains(*child));
+
+		if transform.translation.y > HALF_WORLD_HEIGHT {
+			continue;
+		}
+		spawner.timer.tick(time.delta());
+		if !spawner.timer.just_finished() {
+			continue;
+		}
+
+		let launch_points = resolve_sockets(transform, sockets, &spawner.config.sockets);
+		let behaviors = if spawner.config.behaviors.is_empty() {
+			EnemySpawn::get_default_behavior_for_type(&spawner.config.child_type)
+		} else {
+			spawner.config.behaviors.clone()
+		};
+
+		for _ in 0..spawner.config.per_launch {
+			if spawner.children.len() >= spawner.config.max_children {
+				break;
+			}
+			let socket = &launch_points[spawner.next_socket % launch_points.len()];
+			spawner.next_socket += 1;
+
+			let child = spawn_assets.spawn_with_behavior(
+				&mut commands,
+				&spawner.config.child_type,
+				Transform::from_translation(socket.world_pos.extend(transform.translation.z)),
+				EnemyBehavior::new(behaviors.clone(), socket.world_pos),
+			);
+			commands.entity(child).insert(SpawnedBy {
+				parent: carrier,
+				on_parent_death: spawner.config.on_parent_death,
+			});
+			spawner.children.push(child);
+		}
+		info!("🛸 Carrier launched {} ({} out)", spawner.config.child_type, spawner.children.len());
+	}
+}
+
+/// Despawn or scatter children whose carrier died or left
+pub fn handle_orphaned_children(
+	mut commands: Commands,
+	entities: &Entities,
+	parent_query: Query<&Transform, With<Dying>>,
+	mut child_query: Query<(Entity, &Transform, &SpawnedBy, Option<&mut EnemyBehavior>), Without<Dying>>,
+) {
+	let mut rng = rand::thread_rng();
+
+	for (child, transform, spawned_by, behavior) in child_query.iter_mut() {
+		let parent_dying = parent_query.get(spawned_by.parent).ok();
+		if entities.contains(spawned_by.parent) && parent_dying.is_none() {
+			continue;
+		}
+
+		match spawned_by.on_parent_death {
+			OrphanPolicy::Despawn => {
+				commands.entity(child).despawn_recursive();
+			}
+			OrphanPolicy::Scatter => {
+				let position = transform.translation.truncate();
+				let away = parent_dying
+					.map(|parent| position - parent.translation.truncate())
+					.and_then(|offset| offset.try_normalize())
+					.unwrap_or_else(|| Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)));
+				commands.entity(child)
+					.remove::<SpawnedBy>()
+					.insert(KnockbackVelocity(away * SCATTER_SPEED));
+				if let Some(mut behavior) = behavior {
+					behavior.leader_lost = true;
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use crate::components::{
+	Enemy, Player, PlayerDown, PlayerDefenses, Collider, Invincible, Dying, ProjectileHitbox, EnemyBehavior, BehaviorType,
+	ShaderEffects, ExplodeTrigger, EnemyHitEvent, EnemyDeathEvent, PlayerHitEvent, DamageType,
+};
+use crate::systems::audio::PlaySfxEvent;
+use crate::systems::collision::apply_player_damage;
+use crate::systems::enemies::find_charge;
+use crate::systems::hanabi_fx::{ParticleEffects, spawn_named_effect};
+use std::collections::HashMap;
+
+/// Flash rate at the start of a windup; it doubles and triples as the dash gets closer
+const TELEGRAPH_FLASH_HZ: f32 = 3.0;
+/// Long enough to cross the whole screen from any corner
+const WARNING_LINE_LENGTH: f32 = 1600.0;
+/// Push given to enemies caught in a blast
+const BLAST_KNOCKBACK: f32 = 120.0;
+
+/// Where an enemy is in its current `Charge`, if it's running one
+fn charge_elapsed(state: &EnemyBehavior) -> Option<(&BehaviorType, f32)> {
+	let current = state.behaviors.get(state.current_index)?;
+	let charge = find_charge(&current.behavior_type)?;
+	Some((charge, state.total_time_alive - state.behavior_start_time))
+}
+
+/// Flash charging enemies and draw their warning line during the windup.
+/// `base_colors` holds each flashing sprite's own color, put back once the windup ends.
+#[allow(clippy::type_complexity)]
+pub fn telegraph_charges(
+	mut gizmos: Gizmos,
+	mut query: Query<(Entity, &Transform, &EnemyBehavior, Option<&mut Sprite>, Option<&mut ShaderEffects>), (With<Enemy>, Without<Dying>)>,
+	mut base_colors: Local<HashMap<Entity, Color>>,
+) {
+	let mut winding_up: Vec<Entity> = Vec::new();
+
+	for (entity, transform, state, sprite, shader) in query.iter_mut() {
+		let Some((BehaviorType::Charge { windup, flash_color, .. }, elapsed)) = charge_elapsed(state) else { continue };
+		let Some(direction) = state.charge_direction else { continue };
+		if elapsed >= *windup {
+			continue;
+		}
+		winding_up.push(entity);
+
+		// Chirp: the flash speeds up towards the dash
+		let phase = TELEGRAPH_FLASH_HZ * (elapsed + elapsed * elapsed / windup.max(0.01)) * std::f32::consts::TAU;
+		let pulse = phase.sin() * 0.5 + 0.5;
+		let [r, g, b, _] = *flash_color;
+
+		if let Some(mut sprite) = sprite {
+			let base = base_colors.entry(entity).or_insert(sprite.color).to_srgba();
+			sprite.color = Color::srgba(
+				base.red + (r - base.red) * pulse,
+				base.green + (g - base.green) * pulse,
+				base.blue + (b - base.blue) * pulse,
+				base.alpha,
+			);
+		}
+		if let Some(mut shader) = shader {
+			shader.flash_amount = shader.flash_amount.max(pulse * 0.6);
+		}
+
+		let start = transform.translation.truncate();
+		gizmos.line_2d(start, start + direction * WARNING_LINE_LENGTH, Color::srgba(r, g, b, 0.25 + 0.5 * pulse));
+	}
+
+	// Windup over (dash started, behavior changed, or the enemy is gone): put the color back
+	base_colors.retain(|entity, color| {
+		if winding_up.contains(entity) {
+			return true;
+		}
+		if let Ok((_, _, _, Some(mut sprite), _)) = query.get_mut(*entity) {
+			sprite.color = *color;
+		}
+		false
+	});
+}
+
+/// Set off exploding chargers: on touching a player, or as their dash ends.
+/// Runs before the behavior update so a charge that ends this frame is still current.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn detonate_chargers(
+	mut commands: Commands,
+	chargers: Query<(Entity, &Transform, &Collider, &Enemy, &EnemyBehavior), Without<Dying>>,
+	targets: Query<(Entity, &Transform), (With<Enemy>, Without<Dying>)>,
+	mut players: Query<(Entity, &Transform, &Collider, &mut PlayerDefenses, Option<&Invincible>), (With<Player>, Without<PlayerDown>)>,
+	mut hit_events: EventWriter<EnemyHitEvent>,
+	mut player_hit_events: EventWriter<PlayerHitEvent>,
+	mut death_events: EventWriter<EnemyDeathEvent>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+	particle_effects: Option<Res<ParticleEffects>>,
+	time: Res<Time>,
+) {
+	let delta = time.delta_secs();
+	let mut detonated: Vec<Entity> = Vec::new();
+
+	for (entity, transform, collider, enemy, state) in chargers.iter() {
+		let Some((BehaviorType::Charge { windup, dash_time, explode: Some(explosion), .. }, elapsed)) = charge_elapsed(state) else { continue };
+		if elapsed < *windup {
+			continue;
+		}
+		let center = transform.translation.truncate();
+
+		let touching = players.iter().any(|(_, player_transform, player_collider, _, _)| {
+			player_transform.translation.truncate().distance(center) < player_collider.radius + collider.radius
+		});
+		let dash_over = explosion.trigger == ExplodeTrigger::AtEnd
+			&& dash_time.is_some_and(|dash_time| elapsed + delta >= windup + dash_time);
+		if !touching && !dash_over {
+			continue;
+		}
+		detonated.push(entity);
+
+		for (player_entity, player_transform, player_collider, mut defenses, invincible) in players.iter_mut() {
+			if invincible.is_some() || player_transform.translation.truncate().distance(center) >= explosion.radius + player_collider.radius {
+				continue;
+			}
+			let (sink, depleted) = apply_player_damage(&mut defenses, explosion.damage, time.elapsed_secs_f64());
+			info!("Player caught in a blast for {:.0} damage! Hit: {:?}, Armor: {:.0}/{:.0}",
+				explosion.damage, sink, defenses.armor, defenses.armor_max);
+			player_hit_events.send(PlayerHitEvent { player: player_entity, sink, depleted });
+			commands.entity(player_entity).insert(Invincible::new(0.05));
+		}
+
+		for (target, target_transform) in targets.iter() {
+			let offset = target_transform.translation.truncate() - center;
+			if detonated.contains(&target) || offset.length() >= explosion.radius {
+				continue;
+			}
+			hit_events.send(EnemyHitEvent {
+				enemy: target,
+				damage: explosion.damage,
+				hit_sound: None,
+				dot: None,
+				knockback: offset.normalize_or_zero() * BLAST_KNOCKBACK,
+				damage_type: DamageType::Explosive,
+				source: Some(center),
+			});
+		}
+
+		death_events.send(EnemyDeathEvent {
+			entity,
+			position: center,
+			enemy_type: enemy.enemy_type,
+			killed_by_player: false,
+		});
+		commands.entity(entity)
+			.insert(Dying)
+			.remove::<Collider>()
+			.remove::<ProjectileHitbox>();
+
+		if let Some(effects) = particle_effects.as_ref() {
+			spawn_named_effect(&mut commands, effects, "large_sparks", transform.translation);
+		}
+		sfx_events.send(PlaySfxEvent::simple("sounds/explosion_large.ogg", 0.8, 160, 0.05));
+		info!("💥 {:?} detonated ({:.0} radius, {:.0} damage)", enemy.enemy_type, explosion.radius, explosion.damage);
+	}
+}
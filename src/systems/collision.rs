@@ -1,77 +1,129 @@
 use bevy::prelude::*;
 use crate::components::{
 	Enemy, Player, Projectile, Collider, Health, PlayerDefenses, DamageSink,
-	Invincible, ContactDamage, EnemyHitEvent, EnemyDeathEvent, PlayerHitEvent,
-	EnemyProjectile, ProjectileHitbox, HitboxShape, CapsuleAxis,
+	Invincible, PlayerDown, ContactDamage, EnemyHitEvent, EnemyDeathEvent, PlayerHitEvent,
+	EnemyProjectile, ProjectileHitbox, HitboxShape, CapsuleAxis, PlayerCoreHitbox, SplashDamage,
+	Pierce, Ricochet, ProjectileHits, DamageOverTime, Knockback, AngledShot, SineMotion, HomingProjectile,
+	DotStacks, KnockbackVelocity, EnemyType, DamageResistances, FrontShield, EnemyShield, ArmorPlate, ArmorPlates,
 };
 use crate::systems::level::GamePaused;
+use crate::resources::SelectedDifficulty;
 use crate::systems::audio::PlaySfxEvent;
+use crate::systems::status_effects::{add_dot_stack, new_dot_stacks};
+use std::collections::HashMap;
 
-const SHIELD2_REGEN_DELAY_SECS: f64 = 2.0;
-const SHIELD2_REGEN_DURATION_SECS: f64 = 1.5;
-const SHIELD1_REGEN_PER_SEC: f32 = 5.0;
 const DEFAULT_ENEMY_DEATH_CAP: u8 = 3;
-
+/// Collision radius of every enemy projectile against a ship's core
+pub const ENEMY_PROJECTILE_RADIUS: f32 = 5.0;
+/// Cap on how fast stacked knockback can push an enemy
+const MAX_KNOCKBACK_SPEED: f32 = 150.0;
+
+/// Player projectiles vs enemies. A hit despawns the projectile unless it can still
+/// ricochet to another enemy or pierce through; burn/EMP and knockback ride on the hit event.
+#[allow(clippy::type_complexity)]
 pub fn check_projectile_enemy_collisions(
 	mut commands: Commands,
-	projectiles: Query<(Entity, &Transform, &Projectile)>,
-	enemies: Query<(Entity, &Transform, &Collider, Option<&ProjectileHitbox>, &Enemy), Without<Player>>,
+	mut projectiles: Query<(
+		Entity,
+		&mut Transform,
+		&mut Projectile,
+		Option<&SplashDamage>,
+		Option<&mut Pierce>,
+		Option<&mut Ricochet>,
+		Option<&mut ProjectileHits>,
+		Option<&DamageOverTime>,
+		Option<&Knockback>,
+		Option<&mut AngledShot>,
+	), Without<Enemy>>,
+	enemies: Query<(Entity, &Transform, &Collider, Option<&ProjectileHitbox>, Option<&ArmorPlates>, Option<&EnemyShield>), (With<Enemy>, Without<Player>)>,
+	plate_query: Query<(&GlobalTransform, &ProjectileHitbox), With<ArmorPlate>>,
 	mut hit_events: EventWriter<EnemyHitEvent>,
 ) {
-	for (proj_entity, proj_transform, projectile) in projectiles.iter() {
+	for (proj_entity, mut proj_transform, mut projectile, splash, mut pierce, mut ricochet, mut hits, dot, knockback, mut angled) in projectiles.iter_mut() {
 		let proj_pos = proj_transform.translation.truncate();
 		let proj_radius = projectile.damage.sqrt() * 2.0; // Rough projectile size from damage
 
-		for (enemy_entity, enemy_transform, collider, projectile_hitbox, _enemy) in enemies.iter() {
-			let enemy_pos = enemy_transform.translation.truncate();
-			let hit = if let Some(hitbox) = projectile_hitbox {
-				let inv_rotation = enemy_transform.rotation.conjugate();
-				let local_proj = inv_rotation * Vec3::new(
-					proj_pos.x - enemy_pos.x,
-					proj_pos.y - enemy_pos.y,
-					0.0,
-				);
-				let local = Vec2::new(local_proj.x, local_proj.y) - hitbox.offset;
-
-				match hitbox.shape {
-					HitboxShape::Circle { radius } => {
-						local.length_squared() <= (proj_radius + radius).powi(2)
-					}
-					HitboxShape::Ellipse { radii } => {
-						let rx = radii.x + proj_radius;
-						let ry = radii.y + proj_radius;
-						if rx <= 0.0 || ry <= 0.0 {
-							false
-						} else {
-							(local.x * local.x) / (rx * rx) + (local.y * local.y) / (ry * ry) <= 1.0
-						}
-					}
-					HitboxShape::Capsule { radius, half_length, axis } => {
-						let effective_radius = radius + proj_radius;
-						let (dx, dy) = match axis {
-							CapsuleAxis::Vertical => {
-								let clamped_y = local.y.clamp(-half_length, half_length);
-								(local.x, local.y - clamped_y)
-							}
-							CapsuleAxis::Horizontal => {
-								let clamped_x = local.x.clamp(-half_length, half_length);
-								(local.x - clamped_x, local.y)
-							}
-						};
-						dx * dx + dy * dy <= effective_radius * effective_radius
-					}
-				}
-			} else {
-				let distance = proj_pos.distance(enemy_pos);
-				distance < proj_radius + collider.radius
+		for (enemy_entity, enemy_transform, collider, projectile_hitbox, armor, shield) in enemies.iter() {
+			if hits.as_ref().is_some_and(|hits| hits.0.contains(&enemy_entity)) {
+				continue;
+			}
+			// Armor plates take hits before the core they cover, unless a shield is in the way
+			let plate_hit = match armor {
+				Some(armor) if !shield.is_some_and(EnemyShield::is_up) => armor.plates.iter().copied().find(|&plate| {
+					plate_query.get(plate).is_ok_and(|(plate_transform, hitbox)| {
+						hitbox_contains(&plate_transform.compute_transform(), hitbox, proj_pos, proj_radius)
+					})
+				}),
+				_ => None,
+			};
+			let hit = plate_hit.is_some() || match projectile_hitbox {
+				Some(hitbox) => hitbox_contains(enemy_transform, hitbox, proj_pos, proj_radius),
+				None => proj_pos.distance(enemy_transform.translation.truncate()) < proj_radius + collider.radius,
 			};
 
 			if hit {
+				let direction = match angled.as_ref() {
+					Some(angled) => angled.velocity.normalize_or_zero(),
+					None => (proj_transform.rotation * Vec3::Y).truncate(),
+				};
 				hit_events.send(EnemyHitEvent {
-					enemy: enemy_entity,
+					enemy: plate_hit.unwrap_or(enemy_entity),
 					damage: projectile.damage,
 					hit_sound: None,
+					dot: dot.copied(),
+					knockback: knockback.map_or(Vec2::ZERO, |knockback| direction * knockback.strength),
+					damage_type: projectile.weapon_type.damage_type(),
+					source: Some(proj_pos),
 				});
+				if let Some(splash) = splash {
+					for (other, other_transform, other_collider, ..) in enemies.iter() {
+						let reach = splash.radius + other_collider.radius;
+						if other != enemy_entity && other_transform.translation.truncate().distance(proj_pos) < reach {
+							hit_events.send(EnemyHitEvent {
+								enemy: other,
+								damage: projectile.damage * splash.fraction,
+								hit_sound: None,
+								dot: dot.copied(),
+								knockback: Vec2::ZERO,
+								damage_type: projectile.weapon_type.damage_type(),
+								source: Some(proj_pos),
+							});
+						}
+					}
+				}
+				if let Some(hits) = hits.as_mut() {
+					hits.0.push(enemy_entity);
+				}
+
+				// Ricochet first: bounce toward the nearest enemy this projectile hasn't hit yet
+				if let Some(ricochet) = ricochet.as_mut().filter(|ricochet| ricochet.remaining > 0) {
+					let next_target = enemies.iter()
+						.filter(|(other, ..)| hits.as_ref().is_none_or(|hits| !hits.0.contains(other)))
+						.map(|(_, other_transform, ..)| other_transform.translation.truncate())
+						.filter(|other_pos| other_pos.distance(proj_pos) < ricochet.range)
+						.min_by(|a, b| a.distance_squared(proj_pos).total_cmp(&b.distance_squared(proj_pos)));
+					if let Some(target_pos) = next_target {
+						ricochet.remaining -= 1;
+						let velocity = (target_pos - proj_pos).normalize_or_zero() * projectile.speed;
+						proj_transform.rotation = Quat::from_rotation_z(velocity.y.atan2(velocity.x) - std::f32::consts::FRAC_PI_2);
+						projectile.lifetime.reset();
+						match angled.as_mut() {
+							Some(angled) => angled.velocity = velocity,
+							None => {
+								commands.entity(proj_entity)
+									.insert(AngledShot { velocity })
+									.remove::<(SineMotion, HomingProjectile)>();
+							}
+						}
+						break;
+					}
+				}
+
+				if let Some(pierce) = pierce.as_mut().filter(|pierce| pierce.remaining > 0) {
+					pierce.remaining -= 1;
+					break;
+				}
+
 				commands.entity(proj_entity).despawn();
 				break;
 			}
@@ -79,21 +131,144 @@ pub fn check_projectile_enemy_collisions(
 	}
 }
 
+/// Whether a projectile of `proj_radius` at `point` overlaps the hitbox of an entity at `transform`
+fn hitbox_contains(transform: &Transform, hitbox: &ProjectileHitbox, point: Vec2, proj_radius: f32) -> bool {
+	let inv_rotation = transform.rotation.conjugate();
+	let local_proj = inv_rotation * Vec3::new(
+		point.x - transform.translation.x,
+		point.y - transform.translation.y,
+		0.0,
+	);
+	let local = Vec2::new(local_proj.x, local_proj.y) - hitbox.offset;
+
+	match hitbox.shape {
+		HitboxShape::Circle { radius } => {
+			local.length_squared() <= (proj_radius + radius).powi(2)
+		}
+		HitboxShape::Ellipse { radii } => {
+			let rx = radii.x + proj_radius;
+			let ry = radii.y + proj_radius;
+			if rx <= 0.0 || ry <= 0.0 {
+				false
+			} else {
+				(local.x * local.x) / (rx * rx) + (local.y * local.y) / (ry * ry) <= 1.0
+			}
+		}
+		HitboxShape::Capsule { radius, half_length, axis } => {
+			let effective_radius = radius + proj_radius;
+			let (dx, dy) = match axis {
+				CapsuleAxis::Vertical => {
+					let clamped_y = local.y.clamp(-half_length, half_length);
+					(local.x, local.y - clamped_y)
+				}
+				CapsuleAxis::Horizontal => {
+					let clamped_x = local.x.clamp(-half_length, half_length);
+					(local.x - clamped_x, local.y)
+				}
+			};
+			dx * dx + dy * dy <= effective_radius * effective_radius
+		}
+	}
+}
+
+/// Damage multiplier for a hit on this enemy: its resistance to the hit's type, times
+/// its front shield if the hit came from in front, times its armor while plates still cover the core
+pub fn hit_multiplier(
+	event: &EnemyHitEvent,
+	transform: &Transform,
+	resistances: Option<&DamageResistances>,
+	shield: Option<&FrontShield>,
+	armor: Option<&ArmorPlates>,
+) -> f32 {
+	let resistance = resistances.unwrap_or(&DamageResistances::NEUTRAL).multiplier(event.damage_type)
+		* armor.filter(|armor| !armor.is_exposed()).map_or(1.0, |armor| armor.core_multiplier);
+	let shielded = match (shield, event.source) {
+		(Some(shield), Some(source)) => {
+			let facing = (transform.rotation * Vec3::Y).truncate();
+			let to_source = source - transform.translation.truncate();
+			to_source != Vec2::ZERO && facing.angle_to(to_source).abs() <= shield.half_arc
+		}
+		_ => false,
+	};
+	if shielded {
+		resistance * shield.map_or(1.0, |shield| shield.multiplier)
+	} else {
+		resistance
+	}
+}
+
+/// Damage multiplier for a hit on one of the core's armor plates: the core's resistance and front
+/// shield apply, but not its armor (the plate is the armor)
+pub fn plate_hit_multiplier(
+	event: &EnemyHitEvent,
+	core_transform: &Transform,
+	resistances: Option<&DamageResistances>,
+	shield: Option<&FrontShield>,
+) -> f32 {
+	hit_multiplier(event, core_transform, resistances, shield, None)
+}
+
+/// Apply hit damage scaled by the enemy's resistances (energy shields soak it first), plus any
+/// burn/EMP stack and knockback the hit carries (bosses don't budge)
+#[allow(clippy::type_complexity)]
 pub fn apply_enemy_damage(
 	mut commands: Commands,
 	mut hit_events: EventReader<EnemyHitEvent>,
-	mut enemies: Query<(Entity, &mut Health, &Transform, &Enemy), Without<crate::components::Dying>>,
+	mut enemies: Query<(
+		Entity,
+		&mut Health,
+		&Transform,
+		&Enemy,
+		Option<&DamageResistances>,
+		Option<&FrontShield>,
+		Option<&ArmorPlates>,
+		Option<&mut EnemyShield>,
+		Option<&mut DotStacks>,
+		Option<&mut KnockbackVelocity>,
+	), Without<crate::components::Dying>>,
 	mut death_events: EventWriter<EnemyDeathEvent>,
 ) {
+	// First burn/EMP or knockback on an enemy this frame; inserted once the events are read
+	let mut new_dots: HashMap<Entity, DotStacks> = HashMap::new();
+	let mut new_knockback: HashMap<Entity, Vec2> = HashMap::new();
+
 	for event in hit_events.read() {
-		if let Ok((entity, mut health, transform, enemy)) = enemies.get_mut(event.enemy) {
-			health.current -= event.damage;
+		if let Ok((entity, mut health, transform, enemy, resistances, shield, armor, energy_shield, dots, knockback)) = enemies.get_mut(event.enemy) {
+			let damage = event.damage * hit_multiplier(event, transform, resistances, shield, armor);
+			let hull_damage = match energy_shield {
+				Some(mut energy_shield) => {
+					let was_up = energy_shield.is_up();
+					let through = energy_shield.absorb(damage);
+					if was_up && !energy_shield.is_up() {
+						info!("🛡️  {:?} shield down", enemy.enemy_type);
+					}
+					through
+				}
+				None => damage,
+			};
+			health.current -= hull_damage;
+
+			if let Some(dot) = event.dot {
+				match dots {
+					Some(mut dots) => add_dot_stack(&mut dots, dot),
+					None => add_dot_stack(new_dots.entry(entity).or_insert_with(new_dot_stacks), dot),
+				}
+			}
+			if event.knockback != Vec2::ZERO && enemy.enemy_type != EnemyType::Boss {
+				let velocity = match knockback {
+					Some(knockback) => &mut knockback.into_inner().0,
+					None => new_knockback.entry(entity).or_insert(Vec2::ZERO),
+				};
+				*velocity = (*velocity + event.knockback).clamp_length_max(MAX_KNOCKBACK_SPEED);
+			}
 
 			if enemy.enemy_type == crate::components::EnemyType::Boss {
-				info!("💥 Boss hit! Damage: {:.1}, HP: {:.1}/{:.1}", event.damage, health.current, health.max);
+				info!("💥 Boss hit! Damage: {:.1} ({:?}), HP: {:.1}/{:.1}", damage, event.damage_type, health.current, health.max);
 			}
 
-			if health.current <= 0.0 {
+			// `Dying` lands with the commands, so later hits this frame still reach the enemy;
+			// only the hit that takes it past zero reports the death
+			if health.current <= 0.0 && health.current + hull_damage > 0.0 {
 				if enemy.enemy_type == crate::components::EnemyType::Boss {
 					info!("☠️  BOSS DESTROYED!");
 				}
@@ -101,6 +276,7 @@ pub fn apply_enemy_damage(
 					entity,
 					position: transform.translation.truncate(),
 					enemy_type: enemy.enemy_type,
+					killed_by_player: true,
 				});
 				// Presentation (dissolve/particles/despawn) is owned by the centralized DeathFX system.
 				// Here we only mark the entity as non-interactive immediately.
@@ -112,59 +288,69 @@ pub fn apply_enemy_damage(
 			}
 		}
 	}
+
+	for (entity, dots) in new_dots {
+		if let Some(mut entity_commands) = commands.get_entity(entity) {
+			entity_commands.insert(dots);
+		}
+	}
+	for (entity, velocity) in new_knockback {
+		if let Some(mut entity_commands) = commands.get_entity(entity) {
+			entity_commands.insert(KnockbackVelocity(velocity));
+		}
+	}
 }
 
+#[allow(clippy::type_complexity)]
 pub fn check_player_enemy_collisions(
 	mut commands: Commands,
-	player_query: Query<(Entity, &Transform, &Collider), (With<Player>, Without<Invincible>)>,
+	mut player_query: Query<(Entity, &Transform, &Collider, &mut PlayerDefenses), (With<Player>, Without<Invincible>, Without<PlayerDown>)>,
 	enemies: Query<(&Transform, &Collider, &Enemy), Without<Player>>,
-	mut player_defenses: Query<&mut PlayerDefenses>,
 	mut hit_events: EventWriter<PlayerHitEvent>,
 	time: Res<Time>,
 ) {
-	let Ok((player_entity, player_transform, player_collider)) = player_query.get_single() else {
-		return;
-	};
-	let player_pos = player_transform.translation.truncate();
-
-	for (enemy_transform, enemy_collider, enemy) in enemies.iter() {
-		let enemy_pos = enemy_transform.translation.truncate();
-		let distance = player_pos.distance(enemy_pos);
-
-		if distance < player_collider.radius + enemy_collider.radius {
-			let damage = ContactDamage::for_enemy_type(enemy.enemy_type);
-
-			let mut sink = DamageSink::Armor;
-			let mut depleted = false;
-
-			if let Ok(mut defenses) = player_defenses.get_single_mut() {
-				// Any hit resets shield regen cooldown/state.
-				defenses.last_damage_time = time.elapsed_secs_f64();
-				defenses.shield2_regen_start_time = None;
-				defenses.shield2_regen_from = defenses.shield2;
-
-				sink = defenses.take_damage(damage);
-				depleted = match sink {
-					DamageSink::Shield2 => defenses.shield2 <= 0.0,
-					DamageSink::Shield1 => defenses.shield1 <= 0.0,
-					DamageSink::Armor => defenses.armor <= 0.0,
-					DamageSink::Dead => true,
-				};
+	for (player_entity, player_transform, player_collider, mut defenses) in player_query.iter_mut() {
+		let player_pos = player_transform.translation.truncate();
+
+		for (enemy_transform, enemy_collider, enemy) in enemies.iter() {
+			let enemy_pos = enemy_transform.translation.truncate();
+			let distance = player_pos.distance(enemy_pos);
+
+			if distance < player_collider.radius + enemy_collider.radius {
+				let damage = ContactDamage::for_enemy_type(enemy.enemy_type);
+				let (sink, depleted) = apply_player_damage(&mut defenses, damage, time.elapsed_secs_f64());
 				info!("Player hit for {:.0} damage! Hit: {:?}, Armor: {:.0}/{:.0}",
 					damage, sink, defenses.armor, defenses.armor_max);
 
 				if sink == DamageSink::Dead {
 					info!("Player armor destroyed! Game Over!");
 				}
-			}
 
-			hit_events.send(PlayerHitEvent { sink, depleted });
-			commands.entity(player_entity).insert(Invincible::new(0.05));
-			break;
+				hit_events.send(PlayerHitEvent { player: player_entity, sink, depleted });
+				commands.entity(player_entity).insert(Invincible::new(0.05));
+				break;
+			}
 		}
 	}
 }
 
+/// Apply a hit to one ship's defenses; returns the layer that took it and whether it broke
+pub fn apply_player_damage(defenses: &mut PlayerDefenses, damage: f32, now: f64) -> (DamageSink, bool) {
+	// Any hit resets shield regen cooldown/state.
+	defenses.last_damage_time = now;
+	defenses.shield2_regen_start_time = None;
+	defenses.shield2_regen_from = defenses.shield2;
+
+	let sink = defenses.take_damage(damage);
+	let depleted = match sink {
+		DamageSink::Shield2 => defenses.shield2 <= 0.0,
+		DamageSink::Shield1 => defenses.shield1 <= 0.0,
+		DamageSink::Armor => defenses.armor <= 0.0,
+		DamageSink::Dead => true,
+	};
+	(sink, depleted)
+}
+
 pub fn update_invincibility(
 	mut commands: Commands,
 	mut query: Query<(Entity, &mut Invincible, &mut Sprite)>,
@@ -184,54 +370,44 @@ pub fn update_invincibility(
 	}
 }
 
+#[allow(clippy::type_complexity)]
 pub fn check_enemy_projectile_player_collisions(
 	mut commands: Commands,
 	projectiles: Query<(Entity, &Transform, &EnemyProjectile)>,
-	player_query: Query<(Entity, &Transform, &Collider), (With<Player>, Without<Invincible>)>,
-	mut player_defenses: Query<&mut PlayerDefenses>,
+	mut player_query: Query<(Entity, &Transform, &Collider, Option<&PlayerCoreHitbox>, &mut PlayerDefenses), (With<Player>, Without<Invincible>, Without<PlayerDown>)>,
 	mut hit_events: EventWriter<PlayerHitEvent>,
 	time: Res<Time>,
 ) {
-	let Ok((player_entity, player_transform, player_collider)) = player_query.get_single() else {
-		return;
-	};
-	let player_pos = player_transform.translation.truncate();
+	// Despawns are deferred, so remember spent shots so two ships can't take the same one
+	let mut spent: Vec<Entity> = Vec::new();
 
-	for (proj_entity, proj_transform, projectile) in projectiles.iter() {
-		let proj_pos = proj_transform.translation.truncate();
-		let proj_radius = 5.0; // Small collision radius for projectiles
-
-		let distance = player_pos.distance(proj_pos);
-
-		if distance < proj_radius + player_collider.radius {
-			let mut sink = DamageSink::Armor;
-			let mut depleted = false;
-
-			if let Ok(mut defenses) = player_defenses.get_single_mut() {
-				// Any hit resets shield regen cooldown/state.
-				defenses.last_damage_time = time.elapsed_secs_f64();
-				defenses.shield2_regen_start_time = None;
-				defenses.shield2_regen_from = defenses.shield2;
-
-				sink = defenses.take_damage(projectile.damage);
-				depleted = match sink {
-					DamageSink::Shield2 => defenses.shield2 <= 0.0,
-					DamageSink::Shield1 => defenses.shield1 <= 0.0,
-					DamageSink::Armor => defenses.armor <= 0.0,
-					DamageSink::Dead => true,
-				};
+	for (player_entity, player_transform, player_collider, core_hitbox, mut defenses) in player_query.iter_mut() {
+		let player_pos = player_transform.translation.truncate();
+		// Projectiles only hit the small core; the full collider is for body contact
+		let player_radius = core_hitbox.map_or(player_collider.radius, |core| core.radius);
+
+		for (proj_entity, proj_transform, projectile) in projectiles.iter() {
+			if spent.contains(&proj_entity) {
+				continue;
+			}
+			let proj_pos = proj_transform.translation.truncate();
+			let distance = player_pos.distance(proj_pos);
+
+			if distance < ENEMY_PROJECTILE_RADIUS + player_radius {
+				let (sink, depleted) = apply_player_damage(&mut defenses, projectile.damage, time.elapsed_secs_f64());
 				info!("Player hit by projectile for {:.0} damage! Hit: {:?}, Armor: {:.0}/{:.0}",
 					projectile.damage, sink, defenses.armor, defenses.armor_max);
 
 				if sink == DamageSink::Dead {
 					info!("Player armor destroyed! Game Over!");
 				}
-			}
 
-			commands.entity(proj_entity).despawn();
-			hit_events.send(PlayerHitEvent { sink, depleted });
-			commands.entity(player_entity).insert(Invincible::new(0.05));
-			break; // Only one hit per frame
+				commands.entity(proj_entity).despawn();
+				spent.push(proj_entity);
+				hit_events.send(PlayerHitEvent { player: player_entity, sink, depleted });
+				commands.entity(player_entity).insert(Invincible::new(0.05));
+				break; // Only one hit per ship per frame
+			}
 		}
 	}
 }
@@ -239,80 +415,87 @@ pub fn check_enemy_projectile_player_collisions(
 /// Regenerate the outer shield (shield2) if the player hasn't been hit recently.
 ///
 /// Behavior:
-/// - Wait `SHIELD2_REGEN_DELAY_SECS` after the last hit (any hit) before starting regen.
-/// - Once started, regen eases from the current value to max over `SHIELD2_REGEN_DURATION_SECS`.
+/// - Wait the ship's `shield2_regen_delay` after the last hit (any hit) before starting regen.
+/// - Once started, regen eases from the current value to max over `shield2_regen_duration`.
 /// - Easing is quadratic ease-in: slow at first, then faster near the end.
+/// - Difficulty scales both: easier starts sooner and refills faster.
 pub fn update_shield2_regen(
 	time: Res<Time>,
 	paused: Res<GamePaused>,
+	difficulty: Res<SelectedDifficulty>,
 	mut player_defenses: Query<&mut PlayerDefenses, With<Player>>,
 ) {
 	if paused.0 {
 		return;
 	}
+	let regen_scale = difficulty.difficulty.shield_regen_multiplier() as f64;
+
+	for mut defenses in player_defenses.iter_mut() {
+		// No need to regen if already full (or max is invalid).
+		if defenses.shield2_max <= 0.0 || defenses.shield2 >= defenses.shield2_max {
+			defenses.shield2 = defenses.shield2.clamp(0.0, defenses.shield2_max.max(0.0));
+			defenses.shield2_regen_start_time = None;
+			continue;
+		}
 
-	let Ok(mut defenses) = player_defenses.get_single_mut() else { return };
-
-	// No need to regen if already full (or max is invalid).
-	if defenses.shield2_max <= 0.0 || defenses.shield2 >= defenses.shield2_max {
-		defenses.shield2 = defenses.shield2.clamp(0.0, defenses.shield2_max.max(0.0));
-		defenses.shield2_regen_start_time = None;
-		return;
-	}
-
-	let now = time.elapsed_secs_f64();
-	let since_hit = now - defenses.last_damage_time;
+		let now = time.elapsed_secs_f64();
+		let since_hit = now - defenses.last_damage_time;
 
-	// Cooldown window: do nothing.
-	if since_hit < SHIELD2_REGEN_DELAY_SECS {
-		defenses.shield2_regen_start_time = None;
-		defenses.shield2_regen_from = defenses.shield2;
-		return;
-	}
+		// Cooldown window: do nothing.
+		if since_hit < defenses.shield2_regen_delay / regen_scale {
+			defenses.shield2_regen_start_time = None;
+			defenses.shield2_regen_from = defenses.shield2;
+			continue;
+		}
 
-	// Start regen if needed.
-	if defenses.shield2_regen_start_time.is_none() {
-		defenses.shield2_regen_from = defenses.shield2;
-		defenses.shield2_regen_start_time = Some(now);
-	}
-	let start = defenses.shield2_regen_start_time.unwrap_or(now);
+		// Start regen if needed.
+		if defenses.shield2_regen_start_time.is_none() {
+			defenses.shield2_regen_from = defenses.shield2;
+			defenses.shield2_regen_start_time = Some(now);
+		}
+		let start = defenses.shield2_regen_start_time.unwrap_or(now);
 
-	let t = ((now - start) / SHIELD2_REGEN_DURATION_SECS).clamp(0.0, 1.0) as f32;
-	let eased = t * t; // quadratic ease-in (slow → fast)
-	defenses.shield2 = defenses.shield2_regen_from
-		+ (defenses.shield2_max - defenses.shield2_regen_from) * eased;
+		let duration = defenses.shield2_regen_duration / regen_scale;
+		let t = ((now - start) / duration.max(f64::EPSILON)).clamp(0.0, 1.0) as f32;
+		let eased = t * t; // quadratic ease-in (slow → fast)
+		defenses.shield2 = defenses.shield2_regen_from
+			+ (defenses.shield2_max - defenses.shield2_regen_from) * eased;
 
-	if t >= 1.0 {
-		defenses.shield2 = defenses.shield2_max;
-		defenses.shield2_regen_start_time = None;
+		if t >= 1.0 {
+			defenses.shield2 = defenses.shield2_max;
+			defenses.shield2_regen_start_time = None;
+		}
 	}
 }
 
 /// Regenerate the inner shield (shield1) constantly at a fixed rate.
 ///
 /// Behavior:
-/// - Always regenerating at SHIELD1_REGEN_PER_SEC (no delay, even through damage)
+/// - Always regenerating at the ship's `shield1_regen_per_sec` (no delay, even through damage),
+///   scaled by difficulty
 /// - Provides consistent recovery against light/scattered damage
 pub fn update_shield1_regen(
 	time: Res<Time>,
 	paused: Res<GamePaused>,
+	difficulty: Res<SelectedDifficulty>,
 	mut player_defenses: Query<&mut PlayerDefenses, With<Player>>,
 ) {
 	if paused.0 {
 		return;
 	}
+	let regen_scale = difficulty.difficulty.shield_regen_multiplier();
 
-	let Ok(mut defenses) = player_defenses.get_single_mut() else { return };
+	for mut defenses in player_defenses.iter_mut() {
+		// No need to regen if already full (or max is invalid).
+		if defenses.shield1_max <= 0.0 || defenses.shield1 >= defenses.shield1_max {
+			defenses.shield1 = defenses.shield1.clamp(0.0, defenses.shield1_max.max(0.0));
+			continue;
+		}
 
-	// No need to regen if already full (or max is invalid).
-	if defenses.shield1_max <= 0.0 || defenses.shield1 >= defenses.shield1_max {
-		defenses.shield1 = defenses.shield1.clamp(0.0, defenses.shield1_max.max(0.0));
-		return;
+		// Constant regen - no delay, always active
+		defenses.shield1 = (defenses.shield1 + defenses.shield1_regen_per_sec * regen_scale * time.delta_secs())
+			.min(defenses.shield1_max);
 	}
-
-	// Constant regen - no delay, always active
-	defenses.shield1 = (defenses.shield1 + SHIELD1_REGEN_PER_SEC * time.delta_secs())
-		.min(defenses.shield1_max);
 }
 
 pub fn play_enemy_hit_sound(
@@ -369,3 +552,65 @@ pub fn play_player_hit_sound(
 		sfx_events.send(PlaySfxEvent::simple(sound_path, 0.7, 150, 0.05));
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::DamageType;
+
+	fn hit(damage_type: DamageType, source: Option<Vec2>) -> EnemyHitEvent {
+		EnemyHitEvent {
+			enemy: Entity::PLACEHOLDER,
+			damage: 10.0,
+			hit_sound: None,
+			dot: None,
+			knockback: Vec2::ZERO,
+			damage_type,
+			source,
+		}
+	}
+
+	#[test]
+	fn hit_multiplier_uses_the_damage_type_resistance() {
+		let resistances = DamageResistances::new(0.5, 1.0, 2.0, 1.0);
+		let transform = Transform::default();
+		assert_eq!(hit_multiplier(&hit(DamageType::Kinetic, None), &transform, Some(&resistances), None, None), 0.5);
+		assert_eq!(hit_multiplier(&hit(DamageType::Explosive, None), &transform, Some(&resistances), None, None), 2.0);
+		assert_eq!(hit_multiplier(&hit(DamageType::Energy, None), &transform, None, None, None), 1.0);
+	}
+
+	#[test]
+	fn front_shield_only_covers_hits_from_in_front() {
+		let shield = FrontShield { half_arc: 60f32.to_radians(), multiplier: 0.2 };
+		// Facing +Y: a shot from above is in front, one from below is behind
+		let transform = Transform::default();
+		let front = hit(DamageType::Kinetic, Some(Vec2::new(0.0, 100.0)));
+		let behind = hit(DamageType::Kinetic, Some(Vec2::new(0.0, -100.0)));
+		let no_source = hit(DamageType::Kinetic, None);
+		assert_eq!(hit_multiplier(&front, &transform, None, Some(&shield), None), 0.2);
+		assert_eq!(hit_multiplier(&behind, &transform, None, Some(&shield), None), 1.0);
+		assert_eq!(hit_multiplier(&no_source, &transform, None, Some(&shield), None), 1.0);
+	}
+
+	#[test]
+	fn armor_protects_the_core_until_every_plate_is_gone() {
+		let transform = Transform::default();
+		let event = hit(DamageType::Energy, None);
+		let resistances = DamageResistances::new(1.0, 0.5, 1.0, 1.0);
+		let covered = ArmorPlates { plates: vec![Entity::PLACEHOLDER], core_multiplier: 0.25 };
+		let exposed = ArmorPlates { plates: Vec::new(), core_multiplier: 0.25 };
+		assert_eq!(hit_multiplier(&event, &transform, Some(&resistances), None, Some(&covered)), 0.125);
+		assert_eq!(hit_multiplier(&event, &transform, Some(&resistances), None, Some(&exposed)), 0.5);
+	}
+
+	#[test]
+	fn armor_plates_take_the_core_resistances() {
+		let transform = Transform::default();
+		let resistances = DamageResistances::for_enemy_type(EnemyType::LargeAsteroid);
+		let explosive = plate_hit_multiplier(&hit(DamageType::Explosive, None), &transform, Some(&resistances), None);
+		let energy = plate_hit_multiplier(&hit(DamageType::Energy, None), &transform, Some(&resistances), None);
+		assert_eq!(explosive, 1.5);
+		assert_eq!(energy, 0.5);
+		assert_eq!(plate_hit_multiplier(&hit(DamageType::Kinetic, None), &transform, None, None), 1.0);
+	}
+}
@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+use crate::components::{
+	Player, Enemy, Projectile, EnemyProjectile, Collider, ProjectileHitbox, HitboxShape,
+	CapsuleAxis, EnemyWeaponSockets, FormationMember, FormationRegistry, Weapon, WeaponType,
+	ChargeMeter, PlayerCoreHitbox,
+};
+use crate::systems::level::CurrentLevel;
+use crate::systems::lightning::{ENHANCED_MODE_LEVEL, quantize_charge_tier};
+use crate::systems::weapon_tuning::WeaponTuning;
+use crate::systems::collision::ENEMY_PROJECTILE_RADIUS;
+use crate::systems::graze::GRAZE_RADIUS;
+use crate::systems::pickups::PICKUP_RADIUS;
+use crate::systems::world::{HALF_PLAY_WIDTH, HALF_WORLD_HEIGHT};
+
+/// How many upcoming waves are drawn (spawn markers + timeline strip)
+const TIMELINE_WAVES: usize = 8;
+/// Distance window covered by the timeline strip (GU ahead of the player)
+const TIMELINE_LOOKAHEAD: f32 = 3000.0;
+const TIMELINE_X: f32 = HALF_PLAY_WIDTH - 20.0;
+const TIMELINE_TOP: f32 = HALF_WORLD_HEIGHT - 40.0;
+const TIMELINE_BOTTOM: f32 = -HALF_WORLD_HEIGHT + 40.0;
+
+const COLLIDER_COLOR: Color = Color::srgba(0.2, 1.0, 0.3, 0.8);
+const HITBOX_COLOR: Color = Color::srgba(1.0, 0.3, 0.3, 0.9);
+const SOCKET_COLOR: Color = Color::srgba(1.0, 0.9, 0.2, 0.9);
+const FORMATION_COLOR: Color = Color::srgba(0.4, 0.6, 1.0, 0.6);
+const PROJECTILE_COLOR: Color = Color::srgba(0.3, 0.9, 1.0, 0.6);
+const ENEMY_PROJECTILE_COLOR: Color = Color::srgba(1.0, 0.5, 0.1, 0.7);
+const CORE_HITBOX_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+const GRAZE_COLOR: Color = Color::srgba(0.8, 0.95, 1.0, 0.35);
+const PICKUP_COLOR: Color = Color::srgba(0.8, 0.8, 0.8, 0.4);
+const CHAIN_RANGE_COLOR: Color = Color::srgba(0.6, 0.5, 1.0, 0.5);
+const CHAIN_AOE_COLOR: Color = Color::srgba(0.6, 0.5, 1.0, 0.25);
+const WAVE_COLOR: Color = Color::srgb(1.0, 0.2, 0.8);
+
+/// Debug gizmo overlay (hitboxes, sockets, formations, upcoming waves)
+#[derive(Resource, Default)]
+pub struct DebugOverlayEnabled(pub bool);
+
+pub fn toggle_debug_overlay(
+	keyboard: Res<ButtonInput<KeyCode>>,
+	mut overlay_enabled: ResMut<DebugOverlayEnabled>,
+) {
+	if keyboard.just_pressed(KeyCode::F4) {
+		overlay_enabled.0 = !overlay_enabled.0;
+		info!("🔍 Debug overlay: {}", if overlay_enabled.0 { "ON" } else { "OFF" });
+	}
+}
+
+/// Draw collision shapes for everything that can hit or be hit
+pub fn render_debug_hitboxes(
+	overlay_enabled: Res<DebugOverlayEnabled>,
+	mut gizmos: Gizmos,
+	colliders: Query<(&Transform, &Collider, Option<&ProjectileHitbox>)>,
+	projectiles: Query<(&Transform, &Projectile)>,
+	enemy_projectiles: Query<&Transform, With<EnemyProjectile>>,
+) {
+	if !overlay_enabled.0 {
+		return;
+	}
+
+	for (transform, collider, hitbox) in colliders.iter() {
+		let pos = transform.translation.truncate();
+		gizmos.circle_2d(pos, collider.radius, COLLIDER_COLOR);
+
+		let Some(hitbox) = hitbox else { continue };
+		let angle = transform.rotation.to_euler(EulerRot::XYZ).2;
+		let center = pos + Vec2::from_angle(angle).rotate(hitbox.offset);
+
+		match hitbox.shape {
+			HitboxShape::Circle { radius } => {
+				gizmos.circle_2d(center, radius, HITBOX_COLOR);
+			}
+			HitboxShape::Ellipse { radii } => {
+				gizmos.ellipse_2d(Isometry2d::new(center, Rot2::radians(angle)), radii, HITBOX_COLOR);
+			}
+			HitboxShape::Capsule { radius, half_length, axis } => {
+				// Capsule2d is vertical; rotate a quarter turn for horizontal capsules
+				let axis_angle = match axis {
+					CapsuleAxis::Vertical => 0.0,
+					CapsuleAxis::Horizontal => std::f32::consts::FRAC_PI_2,
+				};
+				gizmos.primitive_2d(
+					&Capsule2d::new(radius, half_length * 2.0),
+					Isometry2d::new(center, Rot2::radians(angle + axis_angle)),
+					HITBOX_COLOR,
+				);
+			}
+		}
+	}
+
+	for (transform, projectile) in projectiles.iter() {
+		let radius = projectile.damage.sqrt() * 2.0;
+		gizmos.circle_2d(transform.translation.truncate(), radius, PROJECTILE_COLOR);
+	}
+
+	for transform in enemy_projectiles.iter() {
+		gizmos.circle_2d(transform.translation.truncate(), ENEMY_PROJECTILE_RADIUS, ENEMY_PROJECTILE_COLOR);
+	}
+}
+
+/// Draw weapon sockets and formation leader→member links
+pub fn render_debug_sockets_and_formations(
+	overlay_enabled: Res<DebugOverlayEnabled>,
+	mut gizmos: Gizmos,
+	socket_query: Query<(&Transform, &EnemyWeaponSockets), With<Enemy>>,
+	member_query: Query<(&Transform, &FormationMember)>,
+	leader_query: Query<&Transform, With<Enemy>>,
+	formation_registry: Res<FormationRegistry>,
+) {
+	if !overlay_enabled.0 {
+		return;
+	}
+
+	for (transform, sockets) in socket_query.iter() {
+		let pos = transform.translation.truncate();
+		let angle = transform.rotation.to_euler(EulerRot::XYZ).2;
+		for socket in &sockets.sockets {
+			let world_pos = pos + Vec2::from_angle(angle).rotate(socket.local_offset);
+			gizmos.line_2d(pos, world_pos, SOCKET_COLOR.with_alpha(0.3));
+			gizmos.cross_2d(world_pos, 4.0, SOCKET_COLOR);
+		}
+	}
+
+	for (member_transform, member) in member_query.iter() {
+		let Some(&leader) = formation_registry.formations.get(&member.formation_id) else { continue };
+		let Ok(leader_transform) = leader_query.get(leader) else { continue };
+		gizmos.line_2d(
+			leader_transform.translation.truncate(),
+			member_transform.translation.truncate(),
+			FORMATION_COLOR,
+		);
+	}
+}
+
+/// Draw each ship's core hitbox, graze ring, pickup radius and current lightning chain reach
+pub fn render_debug_player_ranges(
+	overlay_enabled: Res<DebugOverlayEnabled>,
+	mut gizmos: Gizmos,
+	player_query: Query<(&Transform, &Weapon, &ChargeMeter, Option<&PlayerCoreHitbox>), With<Player>>,
+	tuning: Res<WeaponTuning>,
+) {
+	if !overlay_enabled.0 {
+		return;
+	}
+
+	for (transform, weapon, charge_meter, core_hitbox) in player_query.iter() {
+		let pos = transform.translation.truncate();
+
+		if let Some(core) = core_hitbox {
+			gizmos.circle_2d(pos, core.radius, CORE_HITBOX_COLOR);
+			gizmos.circle_2d(pos, core.radius + ENEMY_PROJECTILE_RADIUS + GRAZE_RADIUS, GRAZE_COLOR);
+		}
+		gizmos.circle_2d(pos, PICKUP_RADIUS, PICKUP_COLOR);
+
+		if weapon.weapon_type == WeaponType::LightningChain && weapon.level >= ENHANCED_MODE_LEVEL {
+			let tier = quantize_charge_tier(charge_meter.current);
+			let params = tuning.lightning.tier_params(weapon.level, tier);
+			gizmos.circle_2d(pos, params.range, CHAIN_RANGE_COLOR);
+			gizmos.circle_2d(pos, params.aoe_radius, CHAIN_AOE_COLOR);
+		}
+	}
+}
+
+/// Draw spawn markers for the next waves and a distance timeline strip along the right edge
+pub fn render_debug_wave_timeline(
+	overlay_enabled: Res<DebugOverlayEnabled>,
+	mut gizmos: Gizmos,
+	level: Option<Res<CurrentLevel>>,
+) {
+	if !overlay_enabled.0 {
+		return;
+	}
+	let Some(level) = level else { return };
+
+	let current_distance = level.distance;
+	let strip_height = TIMELINE_TOP - TIMELINE_BOTTOM;
+
+	// Strip spine + "now" marker at the bottom
+	gizmos.line_2d(
+		Vec2::new(TIMELINE_X, TIMELINE_BOTTOM),
+		Vec2::new(TIMELINE_X, TIMELINE_TOP),
+		Color::srgba(1.0, 1.0, 1.0, 0.4),
+	);
+	gizmos.line_2d(
+		Vec2::new(TIMELINE_X - 12.0, TIMELINE_BOTTOM),
+		Vec2::new(TIMELINE_X + 12.0, TIMELINE_BOTTOM),
+		Color::srgb(1.0, 1.0, 1.0),
+	);
+
+	// Phase boundaries within the lookahead window
+	for phase in &level.data.phases {
+		let ahead = phase.start_distance - current_distance;
+		if ahead > 0.0 && ahead <= TIMELINE_LOOKAHEAD {
+			let y = TIMELINE_BOTTOM + ahead / TIMELINE_LOOKAHEAD * strip_height;
+			gizmos.line_2d(
+				Vec2::new(TIMELINE_X - 20.0, y),
+				Vec2::new(TIMELINE_X + 20.0, y),
+				Color::srgba(0.3, 1.0, 1.0, 0.7),
+			);
+		}
+	}
+
+	let upcoming = level.data.enemy_waves.iter()
+		.enumerate()
+		.filter(|(idx, wave)| {
+			!level.processed_waves.contains(idx) && wave.spawn_distance >= current_distance
+		})
+		.take(TIMELINE_WAVES);
+
+	for (wave_idx, wave) in upcoming {
+		let ahead = wave.spawn_distance - current_distance;
+		// Nearer waves are drawn brighter
+		let alpha = (1.0 - ahead / TIMELINE_LOOKAHEAD).clamp(0.2, 1.0);
+		let color = WAVE_COLOR.with_alpha(alpha);
+
+		if ahead <= TIMELINE_LOOKAHEAD {
+			let y = TIMELINE_BOTTOM + ahead / TIMELINE_LOOKAHEAD * strip_height;
+			// Tick length scales with wave size
+			let tick = 6.0 + wave.enemies.len() as f32 * 2.0;
+			gizmos.line_2d(Vec2::new(TIMELINE_X - tick, y), Vec2::new(TIMELINE_X, y), color);
+		}
+
+		// Entry points along the top edge (enemies always enter at Y=600)
+		for (enemy_idx, enemy) in wave.enemies.iter().enumerate() {
+			if level.spawned_enemies.contains(&(wave_idx, enemy_idx)) {
+				continue;
+			}
+			let entry = Vec2::new(enemy.position[0], HALF_WORLD_HEIGHT - 12.0);
+			gizmos.cross_2d(entry, 8.0, color);
+			gizmos.line_2d(entry, Vec2::new(enemy.position[0], enemy.position[1]), color.with_alpha(alpha * 0.25));
+		}
+	}
+}
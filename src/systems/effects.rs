@@ -1,17 +1,21 @@
 use bevy::prelude::*;
 use bevy::sprite::MeshMaterial2d;
-use crate::components::{EnemyHitEvent, FxPolicy, HitFx, ShaderEffects};
+use crate::components::{EnemyHitEvent, FxPolicy, HitFx, ShaderEffects, EnemyShield};
 use crate::materials::EffectsMaterial;
 
 pub fn apply_shader_hit_flash(
 	mut hit_events: EventReader<EnemyHitEvent>,
-	mut query: Query<(&mut ShaderEffects, Option<&FxPolicy>)>,
+	mut query: Query<(&mut ShaderEffects, Option<&FxPolicy>, Option<&EnemyShield>)>,
 ) {
 	for event in hit_events.read() {
-		let Ok((mut effects, policy)) = query.get_mut(event.enemy) else { continue };
+		let Ok((mut effects, policy, shield)) = query.get_mut(event.enemy) else { continue };
 		if !matches!(policy.map(|p| p.on_hit), Some(HitFx::ShaderFlash)) {
 			continue;
 		}
+		// Hits on a raised shield show the shield's own FX instead
+		if shield.is_some_and(EnemyShield::is_up) {
+			continue;
+		}
 
 		// A rugged, high-contrast "impact" flash for shader-rendered entities.
 		effects.flash_amount = (effects.flash_amount + 0.7).min(1.0);
@@ -1,16 +1,20 @@
 use bevy::prelude::*;
 use crate::components::{
-	Enemy, EnemyType, EnemyMovement, MovementPattern, Player, EnemyBehavior, BehaviorType, SineAxis,
-	EasingType, FormationLeader, FormationMember, EnemyShooter, EnemyProjectile, EnemyPreviousPosition,
+	Enemy, EnemyType, EnemyMovement, MovementPattern, Player, PlayerDown, EnemyBehavior, BehaviorType, SineAxis,
+	EasingType, FormationMember, EnemyShooter, EnemyProjectile, EnemyPreviousPosition, EnemyVelocity,
 	EnemyProjectileType, EnemyFireOverride, EnemyFireConfig, EnemyWeaponSockets, FirePattern, AimMode,
-	SocketSelector, PlayerVelocity, WeaponSocket,
+	SocketSelector, PlayerVelocity, WeaponSocket, DotStacks, DotKind, Behavior, BehaviorCondition, TransitionType,
+	Health, NEXT_STATE, HOLD_STATE, FlightPath, PathRef,
 };
 use crate::materials::ProjectileMaterialHandles;
-use crate::resources::EnemyAssetRegistry;
+use crate::resources::{EnemyAssetRegistry, SelectedDifficulty};
+use super::rank::Rank;
 use bevy::render::mesh::Mesh2d;
 use bevy::sprite::MeshMaterial2d;
-use super::world::HALF_WORLD_HEIGHT;
+use super::world::{HALF_WORLD_HEIGHT, HALF_PLAY_WIDTH};
 use super::level::CurrentLevel;
+use super::status_effects::EMP_FIRE_RATE;
+use std::collections::HashMap;
 use std::f32::consts::{PI, FRAC_PI_2};
 
 pub fn update_enemy_movement(
@@ -59,54 +63,47 @@ pub fn cleanup_enemies(
 	for (entity, transform, enemy) in query.iter() {
 		if transform.translation.y < despawn_y {
 			info!("🗑️  Despawning {:?} at y={:.1} (below {:.1})", enemy.enemy_type, transform.translation.y, despawn_y);
-			commands.entity(entity).despawn();
+			commands.entity(entity).despawn_recursive();
 		}
 	}
 }
 
 // === New Behavior System ===
 
+/// Cap on state hops per frame so zero-length loops can't spin forever
+const MAX_STATE_HOPS: usize = 8;
+/// How close a MoveToPosition with `until_arrived` has to get to count as complete
+const ARRIVE_DISTANCE: f32 = 1.0;
+/// How long a FollowPath takes to close the gap from where the enemy was to the path's start
+const PATH_BLEND_SECS: f32 = 0.6;
+
+#[allow(clippy::type_complexity)]
 pub fn execute_enemy_behaviors(
-	mut query: Query<(&mut Transform, &mut EnemyBehavior, &mut Sprite), (Without<Player>, Without<FormationMember>, Without<crate::components::Dying>)>,
-	mut query_no_sprite: Query<(&mut Transform, &mut EnemyBehavior), (Without<Player>, Without<FormationMember>, Without<Sprite>, Without<crate::components::Dying>)>,
+	mut query: Query<(&mut Transform, &mut EnemyBehavior, &mut Sprite, Option<&Health>), (Without<Player>, Without<FormationMember>, Without<crate::components::Dying>)>,
+	mut query_no_sprite: Query<(&mut Transform, &mut EnemyBehavior, Option<&Health>), (Without<Player>, Without<FormationMember>, Without<Sprite>, Without<crate::components::Dying>)>,
 	time: Res<Time>,
-	player_query: Query<&Transform, With<Player>>,
+	player_query: Query<&Transform, (With<Player>, Without<PlayerDown>)>,
 	level: Option<Res<CurrentLevel>>,
 ) {
 	let delta = time.delta_secs();
-	let _scroll_speed = level
+	let players: Vec<Vec2> = player_query.iter().map(|t| t.translation.truncate()).collect();
+	let _scroll_speed = level.as_ref()
 		.and_then(|l| l.get_current_phase().map(|p| p.scroll_speed))
 		.unwrap_or(100.0);
+	let no_paths = HashMap::new();
+	let paths = level.as_ref().map(|l| &l.data.paths).unwrap_or(&no_paths);
 
-	for (mut transform, mut behavior_state, mut sprite) in query.iter_mut() {
-		behavior_state.total_time_alive += delta;
-
+	for (mut transform, mut behavior_state, mut sprite, health) in query.iter_mut() {
 		// Enemies with explicit behaviors handle their own positioning
 		// Don't auto-scroll them or they fight against MoveToPosition/MoveCircular
+		update_behavior_state(&mut behavior_state, transform.translation.truncate(), health, &players, paths, delta);
+		lock_charge(&mut behavior_state, transform.translation.truncate(), &players);
+		behavior_state.entry_position.get_or_insert(transform.translation.truncate());
 
-		if behavior_state.current_index >= behavior_state.behaviors.len() {
+		let Some(current) = behavior_state.behaviors.get(behavior_state.current_index) else {
 			continue;
-		}
-
-		let current = &behavior_state.behaviors[behavior_state.current_index];
-		let elapsed = behavior_state.total_time_alive - behavior_state.behavior_start_time;
-
-// 		// Debug logging for boss every 0.5s
-// 		if transform.translation.y > 600.0 && (behavior_state.total_time_alive * 2.0) as i32 % 1 == 0 {
-// 			info!("🎯 Boss behavior update: pos=({:.1}, {:.1}), behavior={:?}, elapsed={:.2}s",
-// 				transform.translation.x, transform.translation.y, current.behavior_type, elapsed);
-// 		}
-
-		let should_advance = match current.duration {
-			Some(dur) => elapsed >= dur,
-			None => false,
 		};
-
-		if should_advance {
-			behavior_state.current_index += 1;
-			behavior_state.behavior_start_time = behavior_state.total_time_alive;
-			continue;
-		}
+		let elapsed = behavior_state.total_time_alive - behavior_state.behavior_start_time;
 
 		execute_behavior(
 			&current.behavior_type,
@@ -115,31 +112,21 @@ pub fn execute_enemy_behaviors(
 			&behavior_state,
 			elapsed,
 			delta,
-			&player_query,
+			&players,
+			paths,
 		);
 	}
 
 	// Material-based enemies (e.g. shader asteroids) don't have `Sprite`, but they may still use behaviors.
-	for (mut transform, mut behavior_state) in query_no_sprite.iter_mut() {
-		behavior_state.total_time_alive += delta;
+	for (mut transform, mut behavior_state, health) in query_no_sprite.iter_mut() {
+		update_behavior_state(&mut behavior_state, transform.translation.truncate(), health, &players, paths, delta);
+		lock_charge(&mut behavior_state, transform.translation.truncate(), &players);
+		behavior_state.entry_position.get_or_insert(transform.translation.truncate());
 
-		if behavior_state.current_index >= behavior_state.behaviors.len() {
+		let Some(current) = behavior_state.behaviors.get(behavior_state.current_index) else {
 			continue;
-		}
-
-		let current = &behavior_state.behaviors[behavior_state.current_index];
-		let elapsed = behavior_state.total_time_alive - behavior_state.behavior_start_time;
-
-		let should_advance = match current.duration {
-			Some(dur) => elapsed >= dur,
-			None => false,
 		};
-
-		if should_advance {
-			behavior_state.current_index += 1;
-			behavior_state.behavior_start_time = behavior_state.total_time_alive;
-			continue;
-		}
+		let elapsed = behavior_state.total_time_alive - behavior_state.behavior_start_time;
 
 		execute_behavior_no_sprite(
 			&current.behavior_type,
@@ -147,11 +134,169 @@ pub fn execute_enemy_behaviors(
 			&behavior_state,
 			elapsed,
 			delta,
-			&player_query,
+			&players,
+			paths,
 		);
 	}
 }
 
+/// Advance the state machine: take the first transition whose condition holds (every frame for
+/// `Immediate`, on completion for `WaitForCompletion`), else follow `then` once the behavior completes.
+/// Running past the end of the list stops the enemy's behaviors; `then: hold` keeps the current one going.
+fn update_behavior_state(
+	state: &mut EnemyBehavior,
+	position: Vec2,
+	health: Option<&Health>,
+	players: &[Vec2],
+	paths: &HashMap<String, FlightPath>,
+	delta: f32,
+) {
+	state.total_time_alive += delta;
+	if is_on_screen(position) {
+		state.entered_screen = true;
+	}
+
+	for _ in 0..MAX_STATE_HOPS {
+		let Some(current) = state.behaviors.get(state.current_index) else { return };
+		let elapsed = state.total_time_alive - state.behavior_start_time;
+		let completed = behavior_completed(current, elapsed, position, paths);
+
+		let check_transitions = completed || matches!(current.transition, TransitionType::Immediate);
+		let fired = check_transitions
+			.then(|| current.flow.transitions.iter()
+				.find(|transition| condition_met(&transition.when, state, elapsed, position, health, players)))
+			.flatten();
+
+		let target = match fired {
+			Some(transition) => transition.goto.as_str(),
+			None if completed => current.flow.then.as_deref().unwrap_or(NEXT_STATE),
+			None => return,
+		};
+
+		if target == HOLD_STATE {
+			return;
+		}
+		let next_index = if target == NEXT_STATE {
+			state.current_index + 1
+		} else {
+			state.find_state(target).unwrap_or(usize::MAX)
+		};
+		// End of the list (or an unknown state): nothing left to run
+		state.enter_state(next_index.min(state.behaviors.len()));
+	}
+}
+
+/// Lock a `Charge` onto the nearest player the first frame its state runs (straight down if nobody is left)
+fn lock_charge(state: &mut EnemyBehavior, position: Vec2, players: &[Vec2]) {
+	if state.charge_direction.is_some() {
+		return;
+	}
+	let Some(current) = state.behaviors.get(state.current_index) else { return };
+	if find_charge(&current.behavior_type).is_none() {
+		return;
+	}
+	let direction = nearest_player(players, position)
+		.map(|player| (player - position).normalize_or_zero())
+		.filter(|direction| *direction != Vec2::ZERO)
+		.unwrap_or(Vec2::NEG_Y);
+	state.charge_direction = Some(direction);
+}
+
+/// The `Charge` in this behavior, looking inside `Parallel`
+pub fn find_charge(behavior: &BehaviorType) -> Option<&BehaviorType> {
+	match behavior {
+		BehaviorType::Charge { .. } => Some(behavior),
+		BehaviorType::Parallel { behaviors } => behaviors.iter()
+			.find_map(|sub_behavior| find_charge(&sub_behavior.behavior_type)),
+		_ => None,
+	}
+}
+
+fn behavior_completed(behavior: &Behavior, elapsed: f32, position: Vec2, paths: &HashMap<String, FlightPath>) -> bool {
+	match (behavior.duration, &behavior.behavior_type) {
+		(Some(duration), _) => elapsed >= duration,
+		(None, BehaviorType::MoveToPosition { target, until_arrived: true, .. }) => position.distance(*target) <= ARRIVE_DISTANCE,
+		(None, BehaviorType::Charge { windup, dash_time: Some(dash_time), .. }) => elapsed >= windup + dash_time,
+		(None, BehaviorType::FollowPath { path, speed, easing, mirror }) => resolve_path(path, paths)
+			.and_then(|path| path_position(path, *speed, *easing, *mirror, elapsed))
+			.is_some_and(|(_, progress)| progress >= 1.0),
+		(None, _) => false,
+	}
+}
+
+fn resolve_path<'a>(path: &'a PathRef, paths: &'a HashMap<String, FlightPath>) -> Option<&'a FlightPath> {
+	match path {
+		PathRef::Named(name) => paths.get(name),
+		PathRef::Inline(path) => Some(path),
+	}
+}
+
+fn mirror_x(point: Vec2, mirror: bool) -> Vec2 {
+	if mirror { Vec2::new(-point.x, point.y) } else { point }
+}
+
+/// Point reached after flying `elapsed` seconds along the path, plus linear progress (0..1)
+fn path_position(path: &FlightPath, speed: f32, easing: EasingType, mirror: bool, elapsed: f32) -> Option<(Vec2, f32)> {
+	let baked = path.baked();
+	let length = baked.length();
+	if length <= 0.0 || speed <= 0.0 {
+		return baked.points.first().map(|start| (mirror_x(*start, mirror), 1.0));
+	}
+
+	let progress = (speed * elapsed / length).min(1.0);
+	baked.point_at(easing.apply(progress) * length)
+		.map(|point| (mirror_x(point, mirror), progress))
+}
+
+/// Fly along the path. An enemy that enters the state away from the path's first point
+/// eases the gap out over `PATH_BLEND_SECS` instead of snapping onto it.
+#[allow(clippy::too_many_arguments)]
+fn follow_path(
+	transform: &mut Transform,
+	path: &PathRef,
+	speed: f32,
+	easing: EasingType,
+	mirror: bool,
+	elapsed: f32,
+	entry_position: Option<Vec2>,
+	paths: &HashMap<String, FlightPath>,
+) {
+	let Some(path) = resolve_path(path, paths) else { return };
+	let Some((mut position, _)) = path_position(path, speed, easing, mirror, elapsed) else { return };
+	let start = path.baked().points.first().map(|start| mirror_x(*start, mirror));
+	if let (Some(entry), Some(start)) = (entry_position, start) {
+		let blend = (elapsed / PATH_BLEND_SECS).clamp(0.0, 1.0);
+		position += (entry - start) * (1.0 - blend * blend * (3.0 - 2.0 * blend));
+	}
+	transform.translation.x = position.x;
+	transform.translation.y = position.y;
+}
+
+fn condition_met(
+	condition: &BehaviorCondition,
+	state: &EnemyBehavior,
+	elapsed: f32,
+	position: Vec2,
+	health: Option<&Health>,
+	players: &[Vec2],
+) -> bool {
+	match condition {
+		BehaviorCondition::HealthBelow { fraction } => health
+			.is_some_and(|health| health.current <= health.max * fraction),
+		BehaviorCondition::PlayerWithin { radius } => players.iter()
+			.any(|player| player.distance(position) <= *radius),
+		BehaviorCondition::LeaderDead => state.leader_lost,
+		BehaviorCondition::OffScreen => state.entered_screen && !is_on_screen(position),
+		BehaviorCondition::After { seconds } => elapsed >= *seconds,
+		BehaviorCondition::Random { chance } => state.state_roll < *chance,
+	}
+}
+
+fn is_on_screen(position: Vec2) -> bool {
+	position.x.abs() <= HALF_PLAY_WIDTH && position.y.abs() <= HALF_WORLD_HEIGHT
+}
+
+#[allow(clippy::too_many_arguments)]
 fn execute_behavior(
 	behavior: &BehaviorType,
 	transform: &mut Transform,
@@ -159,7 +304,8 @@ fn execute_behavior(
 	state: &EnemyBehavior,
 	elapsed: f32,
 	delta: f32,
-	player_query: &Query<&Transform, With<Player>>,
+	players: &[Vec2],
+	paths: &HashMap<String, FlightPath>,
 ) {
 	match behavior {
 		BehaviorType::MoveStraight { velocity } => {
@@ -190,7 +336,7 @@ fn execute_behavior(
 			transform.translation.y = center.y + angle.sin() * radius;
 		}
 
-		BehaviorType::MoveToPosition { target, speed, easing } => {
+		BehaviorType::MoveToPosition { target, speed, easing, .. } => {
 			let current_pos = transform.translation.truncate();
 			let direction = (*target - current_pos).normalize_or_zero();
 			let distance = current_pos.distance(*target);
@@ -221,8 +367,8 @@ fn execute_behavior(
 		}
 
 		BehaviorType::FollowPlayer { speed, max_distance, offset } => {
-			if let Ok(player_transform) = player_query.get_single() {
-				let target = player_transform.translation.truncate() + *offset;
+			if let Some(player_pos) = nearest_player(players, transform.translation.truncate()) {
+				let target = player_pos + *offset;
 				let current_pos = transform.translation.truncate();
 				let distance = current_pos.distance(target);
 
@@ -259,8 +405,8 @@ fn execute_behavior(
 		}
 
 		BehaviorType::FacePlayer { rotation_speed } => {
-			if let Ok(player_transform) = player_query.get_single() {
-				let to_player = player_transform.translation.truncate() - transform.translation.truncate();
+			if let Some(player_pos) = nearest_player(players, transform.translation.truncate()) {
+				let to_player = player_pos - transform.translation.truncate();
 				let target_angle = to_player.y.atan2(to_player.x) - FRAC_PI_2;
 
 				if *rotation_speed > 0.0 {
@@ -292,8 +438,19 @@ fn execute_behavior(
 		}
 
 		BehaviorType::FaceVelocity => {
-			// Track velocity based on position change (simplified)
-			// In a real implementation, you'd store velocity in the component
+			// Handled by rotate_enemies_to_movement
+		}
+
+		BehaviorType::FollowPath { path, speed, easing, mirror } => {
+			follow_path(transform, path, *speed, *easing, *mirror, elapsed, state.entry_position, paths);
+		}
+
+		BehaviorType::Charge { windup, speed, .. } => {
+			// Hold still while telegraphing (see telegraph_charges), then dash
+			if elapsed >= *windup {
+				let direction = state.charge_direction.unwrap_or(Vec2::NEG_Y);
+				transform.translation += (direction * speed * delta).extend(0.0);
+			}
 		}
 
 		BehaviorType::FadeOut { fade_speed } => {
@@ -320,7 +477,8 @@ fn execute_behavior(
 					state,
 					elapsed,
 					delta,
-					player_query,
+					players,
+					paths,
 				);
 			}
 		}
@@ -333,7 +491,8 @@ fn execute_behavior_no_sprite(
 	state: &EnemyBehavior,
 	elapsed: f32,
 	delta: f32,
-	player_query: &Query<&Transform, With<Player>>,
+	players: &[Vec2],
+	paths: &HashMap<String, FlightPath>,
 ) {
 	match behavior {
 		BehaviorType::MoveStraight { velocity } => {
@@ -364,7 +523,7 @@ fn execute_behavior_no_sprite(
 			transform.translation.y = center.y + angle.sin() * radius;
 		}
 
-		BehaviorType::MoveToPosition { target, speed, easing } => {
+		BehaviorType::MoveToPosition { target, speed, easing, .. } => {
 			let current_pos = transform.translation.truncate();
 			let direction = (*target - current_pos).normalize_or_zero();
 			let distance = current_pos.distance(*target);
@@ -395,8 +554,8 @@ fn execute_behavior_no_sprite(
 		}
 
 		BehaviorType::FollowPlayer { speed, max_distance, offset } => {
-			if let Ok(player_transform) = player_query.get_single() {
-				let target = player_transform.translation.truncate() + *offset;
+			if let Some(player_pos) = nearest_player(players, transform.translation.truncate()) {
+				let target = player_pos + *offset;
 				let current_pos = transform.translation.truncate();
 				let distance = current_pos.distance(target);
 
@@ -433,8 +592,8 @@ fn execute_behavior_no_sprite(
 		}
 
 		BehaviorType::FacePlayer { rotation_speed } => {
-			if let Ok(player_transform) = player_query.get_single() {
-				let to_player = player_transform.translation.truncate() - transform.translation.truncate();
+			if let Some(player_pos) = nearest_player(players, transform.translation.truncate()) {
+				let to_player = player_pos - transform.translation.truncate();
 				let target_angle = to_player.y.atan2(to_player.x) - FRAC_PI_2;
 
 				if *rotation_speed > 0.0 {
@@ -466,7 +625,19 @@ fn execute_behavior_no_sprite(
 		}
 
 		BehaviorType::FaceVelocity => {
-			// Not implemented
+			// Handled by rotate_enemies_to_movement
+		}
+
+		BehaviorType::FollowPath { path, speed, easing, mirror } => {
+			follow_path(transform, path, *speed, *easing, *mirror, elapsed, state.entry_position, paths);
+		}
+
+		BehaviorType::Charge { windup, speed, .. } => {
+			// Hold still while telegraphing (see telegraph_charges), then dash
+			if elapsed >= *windup {
+				let direction = state.charge_direction.unwrap_or(Vec2::NEG_Y);
+				transform.translation += (direction * speed * delta).extend(0.0);
+			}
 		}
 
 		BehaviorType::FadeOut { .. } | BehaviorType::FadeIn { .. } | BehaviorType::Flash { .. } => {
@@ -481,38 +652,20 @@ fn execute_behavior_no_sprite(
 					state,
 					elapsed,
 					delta,
-					player_query,
+					players,
+					paths,
 				);
 			}
 		}
 	}
 }
 
-pub fn update_formations(
-	leader_query: Query<(&Transform, &FormationLeader)>,
-	mut member_query: Query<(&mut Transform, &FormationMember), Without<FormationLeader>>,
-) {
-	use std::collections::HashMap;
-
-	// Build a quick lookup so members can follow leaders even if spawn order was "member first".
-	let mut leader_positions: HashMap<&str, Vec2> = HashMap::new();
-	for (leader_transform, leader) in leader_query.iter() {
-		leader_positions.insert(leader.formation_id.as_str(), leader_transform.translation.truncate());
-	}
-
-	for (mut member_transform, member) in member_query.iter_mut() {
-		if let Some(leader_pos) = leader_positions.get(member.formation_id.as_str()) {
-			let target_pos = *leader_pos + member.offset;
-			member_transform.translation = target_pos.extend(member_transform.translation.z);
-		}
-	}
-}
-
 // === Enemy Shooting System ===
 
 pub fn setup_enemy_shooters(
 	mut commands: Commands,
 	enemy_assets: Res<EnemyAssetRegistry>,
+	difficulty: Res<SelectedDifficulty>,
 	query: Query<(Entity, &Enemy, Option<&EnemyFireOverride>), Without<EnemyShooter>>,
 ) {
 	for (entity, enemy, override_config) in query.iter() {
@@ -535,7 +688,8 @@ pub fn setup_enemy_shooters(
 
 		let fire_config = override_config
 			.map(|override_config| base_config.apply_overrides(&override_config.overrides))
-			.unwrap_or(base_config);
+			.unwrap_or(base_config)
+			.for_difficulty(difficulty.difficulty);
 
 		let burst_interval = match fire_config.pattern {
 			FirePattern::Burst { interval, .. } => interval.max(0.01),
@@ -552,24 +706,44 @@ pub fn setup_enemy_shooters(
 	}
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn enemy_shooting(
 	mut commands: Commands,
 	asset_server: Res<AssetServer>,
 	mut meshes: ResMut<Assets<Mesh>>,
 	projectile_materials: Res<ProjectileMaterialHandles>,
-	mut shooters: Query<(&Transform, &Enemy, &mut EnemyShooter, Option<&EnemyWeaponSockets>), With<Enemy>>,
-	player_query: Query<(&Transform, Option<&PlayerVelocity>), With<Player>>,
+	mut shooters: Query<(&Transform, &Enemy, &mut EnemyShooter, Option<&EnemyWeaponSockets>, Option<&DotStacks>), With<Enemy>>,
+	player_query: Query<(&Transform, Option<&PlayerVelocity>), (With<Player>, Without<PlayerDown>)>,
+	difficulty: Res<SelectedDifficulty>,
+	rank: Res<Rank>,
 	time: Res<Time>,
 ) {
-	let Ok((player_transform, player_velocity)) = player_query.get_single() else { return };
-	let player_pos = player_transform.translation.truncate();
-	let player_vel = player_velocity.map(|v| v.0).unwrap_or(Vec2::ZERO);
-
-	for (transform, enemy, mut shooter, sockets) in shooters.iter_mut() {
-		shooter.fire_timer.tick(time.delta());
-		shooter.burst_timer.tick(time.delta());
+	let players: Vec<(Vec2, Vec2)> = player_query.iter()
+		.map(|(t, v)| (t.translation.truncate(), v.map(|v| v.0).unwrap_or(Vec2::ZERO)))
+		.collect();
+	if players.is_empty() {
+		return;
+	}
 
-		let config = shooter.projectile_type.config();
+	for (transform, enemy, mut shooter, sockets, dots) in shooters.iter_mut() {
+		// EMP stacks jam the enemy's weapons; high rank shortens cooldowns
+		let delta = if dots.is_some_and(|dots| dots.has(DotKind::Emp)) {
+			time.delta().mul_f32(EMP_FIRE_RATE)
+		} else {
+			time.delta()
+		}.mul_f32(rank.fire_rate_multiplier());
+		shooter.fire_timer.tick(delta);
+		shooter.burst_timer.tick(delta);
+
+		// Aim at whichever living ship is closest to this shooter
+		let enemy_pos = transform.translation.truncate();
+		let (player_pos, player_vel) = players.iter()
+			.copied()
+			.min_by(|a, b| a.0.distance_squared(enemy_pos).total_cmp(&b.0.distance_squared(enemy_pos)))
+			.unwrap_or_default();
+
+		let mut config = shooter.projectile_type.config().for_difficulty(difficulty.difficulty);
+		config.speed *= rank.bullet_speed_multiplier();
 		let fire_config = shooter.fire_config.clone();
 
 		let mut emit = |commands: &mut Commands| {
@@ -619,6 +793,14 @@ pub fn enemy_shooting(
 	}
 }
 
+/// Closest living ship to `from`, if any
+pub fn nearest_player(players: &[Vec2], from: Vec2) -> Option<Vec2> {
+	players.iter()
+		.copied()
+		.min_by(|a, b| a.distance_squared(from).total_cmp(&b.distance_squared(from)))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn emit_projectiles(
 	commands: &mut Commands,
 	asset_server: &AssetServer,
@@ -707,12 +889,12 @@ fn emit_projectiles(
 	socket_count
 }
 
-struct ResolvedSocket {
-	world_pos: Vec2,
-	angle_offset: f32,
+pub struct ResolvedSocket {
+	pub world_pos: Vec2,
+	pub angle_offset: f32,
 }
 
-fn resolve_sockets(
+pub fn resolve_sockets(
 	transform: &Transform,
 	sockets: Option<&EnemyWeaponSockets>,
 	selector: &SocketSelector,
@@ -819,23 +1001,54 @@ pub fn init_enemy_rotation(
 	query: Query<(Entity, &Transform), (With<Enemy>, Without<EnemyPreviousPosition>)>,
 ) {
 	for (entity, transform) in query.iter() {
-		commands.entity(entity).insert(EnemyPreviousPosition(transform.translation));
+		commands.entity(entity).insert((EnemyPreviousPosition(transform.translation), EnemyVelocity::default()));
+	}
+}
+
+/// Who decides an enemy's rotation while a behavior is active
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Facing {
+	/// Ships face movement, asteroids tumble
+	Default,
+	/// `FaceVelocity`: always face movement, asteroids included
+	Velocity,
+	/// `FacePlayer` / `FaceDirection` rotate the enemy themselves
+	Behavior,
+}
+
+fn behavior_facing(behavior: &BehaviorType) -> Facing {
+	match behavior {
+		BehaviorType::FaceVelocity => Facing::Velocity,
+		BehaviorType::FacePlayer { .. } | BehaviorType::FaceDirection { .. } => Facing::Behavior,
+		BehaviorType::Parallel { behaviors } => behaviors.iter()
+			.map(|sub_behavior| behavior_facing(&sub_behavior.behavior_type))
+			.find(|facing| *facing != Facing::Default)
+			.unwrap_or(Facing::Default),
+		_ => Facing::Default,
 	}
 }
 
+#[allow(clippy::type_complexity)]
 pub fn rotate_enemies_to_movement(
-	mut query: Query<(&mut Transform, &mut EnemyPreviousPosition, &Enemy), With<Enemy>>,
+	mut query: Query<(&mut Transform, &mut EnemyPreviousPosition, &mut EnemyVelocity, &Enemy, Option<&EnemyBehavior>), With<Enemy>>,
 	time: Res<Time>,
 ) {
 	let delta = time.delta_secs();
 	if delta < 0.001 { return; }
 
-	for (mut transform, mut prev_pos, enemy) in query.iter_mut() {
+	for (mut transform, mut prev_pos, mut velocity, enemy, behavior) in query.iter_mut() {
 		let current_pos = transform.translation;
 		let movement = current_pos - prev_pos.0;
-
-		// Asteroids tumble slowly instead of facing movement direction
-		if matches!(enemy.enemy_type, EnemyType::SmallAsteroid | EnemyType::MediumAsteroid | EnemyType::LargeAsteroid | EnemyType::StationDebris) {
+		velocity.0 = movement.truncate() / delta;
+		let facing = behavior
+			.and_then(|behavior| behavior.behaviors.get(behavior.current_index))
+			.map(|current| behavior_facing(&current.behavior_type))
+			.unwrap_or(Facing::Default);
+
+		if facing == Facing::Behavior {
+			// The behavior already set the rotation this frame
+		} else if facing == Facing::Default && matches!(enemy.enemy_type, EnemyType::SmallAsteroid | EnemyType::MediumAsteroid | EnemyType::LargeAsteroid | EnemyType::StationDebris) {
+			// Asteroids tumble slowly instead of facing movement direction
 			// Slow tumble rotation for asteroids
 			let tumble_speed = 0.3;  // radians per second
 			let current_rotation = transform.rotation.to_euler(bevy::math::EulerRot::XYZ).2;
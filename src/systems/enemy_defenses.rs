@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::{
+	EnemyHitEvent, EnemyShield, HitFx, ArmorPlate, ArmorPlates, Health, Particle, Dying,
+	DamageResistances, FrontShield,
+};
+use crate::systems::audio::PlaySfxEvent;
+use crate::systems::collision::plate_hit_multiplier;
+use crate::systems::level::GamePaused;
+
+/// Peak opacity of a shield bubble right after a hit
+const BUBBLE_MAX_ALPHA: f32 = 0.75;
+/// How fast the bubble fades after a hit (per second)
+const BUBBLE_FADE_RATE: f32 = 4.0;
+
+/// Recharge enemy shields after their delay and fade their hit shimmer
+pub fn update_enemy_shields(
+	mut shield_query: Query<&mut EnemyShield>,
+	mut sprite_query: Query<&mut Sprite, Without<EnemyShield>>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+	paused: Res<GamePaused>,
+	time: Res<Time>,
+) {
+	if paused.0 {
+		return;
+	}
+	let dt = time.delta_secs();
+
+	for mut shield in shield_query.iter_mut() {
+		// A flash of exactly 1.0 means the shield soaked a hit this frame
+		if shield.flash >= 1.0 && shield.hit_fx == HitFx::ShieldShimmer {
+			sfx_events.send(PlaySfxEvent::simple("sounds/shield_hit.ogg", 0.45, 80, 0.08));
+		}
+
+		shield.since_hit += dt;
+		if shield.since_hit >= shield.regen_delay && shield.current < shield.max {
+			shield.current = (shield.current + shield.regen_per_sec * dt).min(shield.max);
+		}
+
+		let flash = shield.flash;
+		shield.flash = (flash - BUBBLE_FADE_RATE * dt).max(0.0);
+		if shield.hit_fx != HitFx::ShieldShimmer {
+			continue;
+		}
+		let Some(mut sprite) = shield.bubble.and_then(|bubble| sprite_query.get_mut(bubble).ok()) else { continue };
+		sprite.color.set_alpha(flash * BUBBLE_MAX_ALPHA);
+	}
+}
+
+/// Damage armor plates, scaled by their core's resistances; a destroyed plate breaks off and stops covering its core
+#[allow(clippy::type_complexity)]
+pub fn apply_armor_plate_damage(
+	mut commands: Commands,
+	mut hit_events: EventReader<EnemyHitEvent>,
+	mut plate_query: Query<(&ArmorPlate, &mut Health, &GlobalTransform)>,
+	mut core_query: Query<(&mut ArmorPlates, &Transform, Option<&DamageResistances>, Option<&FrontShield>)>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+	asset_server: Res<AssetServer>,
+) {
+	for event in hit_events.read() {
+		let Ok((plate, mut health, transform)) = plate_query.get_mut(event.enemy) else { continue };
+		if health.current <= 0.0 {
+			continue;
+		}
+		let multiplier = core_query.get(plate.core)
+			.map_or(1.0, |(_, core_transform, resistances, shield)| plate_hit_multiplier(event, core_transform, resistances, shield));
+		health.current -= event.damage * multiplier;
+		if health.current > 0.0 {
+			continue;
+		}
+
+		if let Ok((mut armor, ..)) = core_query.get_mut(plate.core) {
+			armor.plates.retain(|&other| other != event.enemy);
+			if armor.is_exposed() {
+				info!("🔓 Armor stripped - core exposed");
+			}
+		}
+		spawn_plate_debris(&mut commands, &asset_server, transform.translation().truncate());
+		sfx_events.send(PlaySfxEvent::simple("sounds/explosion_small.ogg", 0.5, 90, 0.05));
+		commands.entity(event.enemy).despawn_recursive();
+	}
+}
+
+/// When a core dies, its remaining plates break off with it
+pub fn shed_armor_on_death(
+	mut commands: Commands,
+	core_query: Query<&ArmorPlates, Added<Dying>>,
+	plate_query: Query<&GlobalTransform, With<ArmorPlate>>,
+	asset_server: Res<AssetServer>,
+) {
+	for armor in core_query.iter() {
+		for &plate in &armor.plates {
+			let Ok(transform) = plate_query.get(plate) else { continue };
+			spawn_plate_debris(&mut commands, &asset_server, transform.translation().truncate());
+			commands.entity(plate).despawn_recursive();
+		}
+	}
+}
+
+fn spawn_plate_debris(commands: &mut Commands, asset_server: &AssetServer, position: Vec2) {
+	let mut rng = rand::thread_rng();
+	for _ in 0..8 {
+		let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+		let speed = rng.gen_range(90.0..200.0);
+		let size = rng.gen_range(6.0..14.0);
+		commands.spawn((
+			Sprite {
+				image: asset_server.load("particles/debris_metal.png"),
+				custom_size: Some(Vec2::splat(size)),
+				..default()
+			},
+			Transform::from_xyz(position.x, position.y, 1.1)
+				.with_rotation(Quat::from_rotation_z(rng.gen_range(0.0..std::f32::consts::TAU))),
+			Particle {
+				lifetime: Timer::from_seconds(rng.gen_range(0.4..0.8), TimerMode::Once),
+				velocity: Vec2::from_angle(angle) * speed,
+			},
+		));
+	}
+}
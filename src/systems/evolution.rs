@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use crate::components::{Player, PlayerDown, Weapon, EvolutionPickup, EvolutionOfferEvent};
+use crate::materials::{ProjectileMaterial, ProjectileMaterialHandles};
+use crate::systems::audio::PlaySfxEvent;
+use crate::systems::pickups::PICKUP_RADIUS;
+use crate::systems::weapon_tuning::WeaponTuning;
+use crate::systems::world::HALF_WORLD_HEIGHT;
+
+/// How far ahead of the ship an evolution pickup appears
+const OFFER_DISTANCE: f32 = 250.0;
+/// Seconds each branch is shown before the pickup flips to the other
+const BRANCH_CYCLE_SECS: f32 = 1.2;
+const ORB_SIZE: f32 = 44.0;
+
+/// True when one more level-up for this weapon should offer an evolution instead
+pub fn can_evolve(weapon: &Weapon, tuning: &WeaponTuning) -> bool {
+	weapon.evolution.is_none()
+		&& weapon.weapon_type.evolutions().is_some()
+		&& weapon.level >= tuning.weapon(weapon.weapon_type).max_level()
+}
+
+/// Spawn an evolution pickup ahead of the ship, at most one pending per ship
+pub fn spawn_evolution_pickups(
+	mut commands: Commands,
+	mut offer_events: EventReader<EvolutionOfferEvent>,
+	player_query: Query<(&Transform, &Weapon), With<Player>>,
+	pickup_query: Query<&EvolutionPickup>,
+	projectile_materials: Res<ProjectileMaterialHandles>,
+) {
+	let mut offered: Vec<Entity> = pickup_query.iter().map(|pickup| pickup.owner).collect();
+
+	for event in offer_events.read() {
+		if offered.contains(&event.player) {
+			continue;
+		}
+		let Ok((transform, weapon)) = player_query.get(event.player) else { continue };
+		let Some(branches) = weapon.weapon_type.evolutions() else { continue };
+		offered.push(event.player);
+
+		let position = Vec2::new(
+			transform.translation.x,
+			(transform.translation.y + OFFER_DISTANCE).min(HALF_WORLD_HEIGHT - 100.0),
+		);
+		commands.spawn((
+			Transform::from_xyz(position.x, position.y, 5.0),
+			Visibility::default(),
+			EvolutionPickup {
+				owner: event.player,
+				branches,
+				showing: 0,
+				cycle: Timer::from_seconds(BRANCH_CYCLE_SECS, TimerMode::Repeating),
+			},
+		)).with_children(|parent| {
+			parent.spawn((
+				Mesh2d(projectile_materials.quad.clone()),
+				MeshMaterial2d(projectile_materials.evolution(branches[0])),
+				Transform::from_scale(Vec3::new(ORB_SIZE, ORB_SIZE, 1.0)),
+			));
+			parent.spawn((
+				Text2d::new(branches[0].name()),
+				TextFont { font_size: 16.0, ..default() },
+				TextColor(Color::WHITE),
+				Transform::from_xyz(0.0, -ORB_SIZE * 0.8, 0.1),
+			));
+		});
+		info!("🧬 {:?} at max level - evolution offered: {} / {}",
+			weapon.weapon_type, branches[0].name(), branches[1].name());
+	}
+}
+
+/// Flip each pickup between its two branches, updating the orb's glow and label
+pub fn cycle_evolution_pickups(
+	mut pickup_query: Query<(&mut EvolutionPickup, &Children)>,
+	mut orb_query: Query<&mut MeshMaterial2d<ProjectileMaterial>>,
+	mut label_query: Query<&mut Text2d>,
+	projectile_materials: Res<ProjectileMaterialHandles>,
+	time: Res<Time>,
+) {
+	for (mut pickup, children) in pickup_query.iter_mut() {
+		pickup.cycle.tick(time.delta());
+		if !pickup.cycle.just_finished() {
+			continue;
+		}
+		pickup.showing = 1 - pickup.showing;
+		let branch = pickup.branches[pickup.showing];
+
+		for &child in children.iter() {
+			if let Ok(mut material) = orb_query.get_mut(child) {
+				material.0 = projectile_materials.evolution(branch);
+			}
+			if let Ok(mut label) = label_query.get_mut(child) {
+				label.0 = branch.name().to_string();
+			}
+		}
+	}
+}
+
+/// Only the ship the pickup was offered to can take it; it evolves into the branch showing
+#[allow(clippy::type_complexity)]
+pub fn collect_evolution_pickups(
+	mut commands: Commands,
+	mut player_query: Query<(&Transform, &mut Weapon), (With<Player>, Without<PlayerDown>)>,
+	pickup_query: Query<(Entity, &Transform, &EvolutionPickup)>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+	for (entity, pickup_transform, pickup) in pickup_query.iter() {
+		let Ok((player_transform, mut weapon)) = player_query.get_mut(pickup.owner) else { continue };
+		let distance = player_transform.translation.truncate().distance(pickup_transform.translation.truncate());
+		if distance >= PICKUP_RADIUS {
+			continue;
+		}
+
+		commands.entity(entity).despawn_recursive();
+
+		// The ship may have switched weapons since the offer
+		let branch = pickup.branches[pickup.showing];
+		if weapon.weapon_type.evolutions() != Some(pickup.branches) || weapon.evolution.is_some() {
+			continue;
+		}
+		weapon.evolution = Some(branch);
+		sfx_events.send(PlaySfxEvent::simple("sounds/powerup_collect.ogg", 0.75, 85, 0.1));
+		info!("🧬 {:?} evolved into {}", weapon.weapon_type, branch.name());
+	}
+}
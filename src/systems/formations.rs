@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+use bevy::ecs::entity::Entities;
+use std::collections::{HashMap, HashSet};
+use crate::components::{
+	FormationLeader, FormationMember, FormationRegistry, LeaderDeathPolicy, EnemyBehavior, Behavior, BehaviorType,
+	BehaviorFlow, TransitionType, Player, PlayerDown, Dying,
+};
+use super::enemies::nearest_player;
+
+/// Dive speed of kamikaze members
+const KAMIKAZE_SPEED: f32 = 380.0;
+
+/// Keep members on their slot around the leader (or ease them toward it with a `follow_rate`),
+/// applying the formation's rotate/scale maneuvers
+#[allow(clippy::type_complexity)]
+pub fn update_formations(
+	mut formation_registry: ResMut<FormationRegistry>,
+	leader_query: Query<(&Transform, &FormationLeader), Without<Dying>>,
+	mut member_query: Query<(&mut Transform, &FormationMember), Without<FormationLeader>>,
+	time: Res<Time>,
+) {
+	// Build a quick lookup so members can follow leaders even if spawn order was "member first".
+	let mut leader_frames: HashMap<&str, (Vec2, f32, f32, Option<f32>)> = HashMap::new();
+	for (leader_transform, leader) in leader_query.iter() {
+		let (rotation, scale, follow_rate) = match formation_registry.plans.get_mut(&leader.formation_id) {
+			Some(plan) => {
+				plan.elapsed += time.delta_secs();
+				let (rotation, scale) = plan.current_shape();
+				(rotation, scale, plan.follow_rate)
+			}
+			None => (0.0, 1.0, None),
+		};
+		leader_frames.insert(leader.formation_id.as_str(), (leader_transform.translation.truncate(), rotation, scale, follow_rate));
+	}
+
+	for (mut member_transform, member) in member_query.iter_mut() {
+		if let Some(&(leader_pos, rotation, scale, follow_rate)) = leader_frames.get(member.formation_id.as_str()) {
+			let target_pos = leader_pos + Vec2::from_angle(rotation).rotate(member.offset * scale);
+			let position = match follow_rate {
+				Some(rate) => member_transform.translation.truncate().lerp(target_pos, 1.0 - (-rate * time.delta_secs()).exp()),
+				None => target_pos,
+			};
+			member_transform.translation = position.extend(member_transform.translation.z);
+		}
+	}
+}
+
+/// Release members one at a time, outermost first, while a `PeelOff` maneuver is running
+pub fn peel_off_formation_members(
+	mut commands: Commands,
+	mut formation_registry: ResMut<FormationRegistry>,
+	mut member_query: Query<(Entity, &Transform, &FormationMember, &mut EnemyBehavior), Without<Dying>>,
+) {
+	for (formation_id, plan) in formation_registry.plans.iter_mut() {
+		if plan.peel_due() <= plan.peeled {
+			continue;
+		}
+		plan.peeled += 1;
+
+		let outermost = member_query.iter()
+			.filter(|(_, _, member, _)| &member.formation_id == formation_id)
+			.max_by(|a, b| a.2.offset.length_squared().total_cmp(&b.2.offset.length_squared()))
+			.map(|(entity, ..)| entity);
+		let Some(entity) = outermost else { continue };
+		if let Ok((entity, transform, _, mut behavior)) = member_query.get_mut(entity) {
+			release_member(&mut commands, entity, transform, &mut behavior);
+			info!("🛩️  Formation '{}' peeled off a member", formation_id);
+		}
+	}
+}
+
+/// Apply each formation's leader-death policy once its leader dies or is despawned
+#[allow(clippy::type_complexity)]
+pub fn handle_formation_leader_deaths(
+	mut commands: Commands,
+	mut formation_registry: ResMut<FormationRegistry>,
+	entities: &Entities,
+	dying_leader_query: Query<(&Transform, &EnemyBehavior), With<Dying>>,
+	mut member_query: Query<(Entity, &Transform, &mut FormationMember, &mut EnemyBehavior), (Without<Dying>, Without<FormationLeader>)>,
+	player_query: Query<&Transform, (With<Player>, Without<PlayerDown>)>,
+) {
+	// Formations whose leader is gone, with their remaining members
+	let mut orphans: HashMap<String, Vec<Entity>> = HashMap::new();
+	for (entity, _, member, _) in member_query.iter() {
+		// No leader registered yet - members may spawn before it
+		let Some(&leader) = formation_registry.formations.get(&member.formation_id) else { continue };
+		if entities.contains(leader) && !dying_leader_query.contains(leader) {
+			continue;
+		}
+		orphans.entry(member.formation_id.clone()).or_default().push(entity);
+	}
+
+	let players: Vec<Vec2> = player_query.iter().map(|t| t.translation.truncate()).collect();
+
+	for (formation_id, members) in orphans {
+		let policy = formation_registry.plans.get(&formation_id)
+			.map(|plan| plan.on_leader_death)
+			.unwrap_or_default();
+		let old_leader = formation_registry.formations.get(&formation_id)
+			.and_then(|leader| dying_leader_query.get(*leader).ok());
+
+		match policy {
+			LeaderDeathPolicy::Promote => {
+				let Some(heir) = members.iter().copied().min_by(|a, b| {
+					let offset = |entity| member_query.get(entity).map(|(_, _, member, _)| member.offset.length_squared()).unwrap_or(f32::MAX);
+					offset(*a).total_cmp(&offset(*b))
+				}) else { continue };
+				let Ok((_, heir_transform, heir_member, mut heir_behavior)) = member_query.get_mut(heir) else { continue };
+				let heir_offset = heir_member.offset;
+
+				// Carry on from where the old leader was in its behaviors, shifted to the heir's position
+				if let Some((leader_transform, leader_behavior)) = old_leader {
+					let shift = heir_transform.translation.truncate() - leader_transform.translation.truncate();
+					heir_behavior.behaviors = leader_behavior.behaviors.clone();
+					heir_behavior.current_index = leader_behavior.current_index;
+					heir_behavior.behavior_start_time = leader_behavior.behavior_start_time;
+					heir_behavior.total_time_alive = leader_behavior.total_time_alive;
+					heir_behavior.state_roll = leader_behavior.state_roll;
+					heir_behavior.entered_screen = leader_behavior.entered_screen;
+					heir_behavior.spawn_position = leader_behavior.spawn_position + shift;
+				}
+
+				commands.entity(heir).remove::<FormationMember>().insert(FormationLeader {
+					formation_id: formation_id.clone(),
+					member_offsets: Vec::new(),
+				});
+				formation_registry.formations.insert(formation_id.clone(), heir);
+
+				for &entity in &members {
+					if entity == heir {
+						continue;
+					}
+					if let Ok((_, _, mut member, _)) = member_query.get_mut(entity) {
+						member.offset -= heir_offset;
+					}
+				}
+				info!("👑 Formation '{}' lost its leader - promoted a member", formation_id);
+			}
+			LeaderDeathPolicy::Scatter => {
+				for entity in members {
+					let Ok((entity, transform, _, mut behavior)) = member_query.get_mut(entity) else { continue };
+					behavior.leader_lost = true;
+					release_member(&mut commands, entity, transform, &mut behavior);
+				}
+				info!("💔 Formation '{}' lost its leader - members scattering", formation_id);
+			}
+			LeaderDeathPolicy::Kamikaze => {
+				for entity in members {
+					let Ok((entity, transform, _, mut behavior)) = member_query.get_mut(entity) else { continue };
+					let position = transform.translation.truncate();
+					let heading = nearest_player(&players, position)
+						.map(|player| (player - position).normalize_or(Vec2::NEG_Y))
+						.unwrap_or(Vec2::NEG_Y);
+					behavior.behaviors = vec![Behavior {
+						behavior_type: BehaviorType::MoveStraight { velocity: heading * KAMIKAZE_SPEED },
+						duration: None,
+						transition: TransitionType::WaitForCompletion,
+						flow: BehaviorFlow::default(),
+					}];
+					behavior.current_index = 0;
+					behavior.leader_lost = true;
+					release_member(&mut commands, entity, transform, &mut behavior);
+				}
+				info!("💥 Formation '{}' lost its leader - members diving", formation_id);
+			}
+		}
+	}
+
+	// Forget formations with nobody left in them
+	let live_formations: HashSet<&str> = member_query.iter()
+		.map(|(_, _, member, _)| member.formation_id.as_str())
+		.collect();
+	let registry = &mut *formation_registry;
+	registry.formations.retain(|formation_id, leader| {
+		(entities.contains(*leader) && !dying_leader_query.contains(*leader)) || live_formations.contains(formation_id.as_str())
+	});
+	registry.plans.retain(|formation_id, _| registry.formations.contains_key(formation_id));
+}
+
+/// Take a member out of its formation so it runs its own behaviors from where it is
+fn release_member(commands: &mut Commands, entity: Entity, transform: &Transform, behavior: &mut EnemyBehavior) {
+	commands.entity(entity).remove::<FormationMember>();
+	behavior.spawn_position = transform.translation.truncate();
+	let index = behavior.current_index;
+	behavior.enter_state(index);
+}
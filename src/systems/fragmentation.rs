@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::{
+	EnemyDeathEvent, EnemyVelocity, FragmentTable, EnemyBehavior, Behavior, BehaviorType, BehaviorFlow, TransitionType,
+};
+use super::spawn::EnemySpawnAssets;
+
+/// Random spread of each fragment's direction around its even share of the circle (radians)
+const FRAGMENT_ANGLE_JITTER: f32 = 0.4;
+
+/// How many fragments to spawn: anywhere in `min_count..=max_count` (a max below the min counts as the min)
+fn fragment_count(table: &FragmentTable, rng: &mut impl Rng) -> u32 {
+	rng.gen_range(table.min_count..=table.max_count.max(table.min_count))
+}
+
+/// Launch direction of fragment `index` of `count`: an even share of the circle from `base_angle`, plus `jitter`
+fn fragment_direction(index: u32, count: u32, base_angle: f32, jitter: f32) -> Vec2 {
+	Vec2::from_angle(base_angle + index as f32 * std::f32::consts::TAU / count as f32 + jitter)
+}
+
+/// Break destroyed enemies into smaller ones using the split table from their manifest entry
+pub fn fragment_enemies(
+	mut commands: Commands,
+	mut spawn_assets: EnemySpawnAssets,
+	mut death_events: EventReader<EnemyDeathEvent>,
+	velocity_query: Query<&EnemyVelocity>,
+) {
+	let mut rng = rand::thread_rng();
+
+	for event in death_events.read().filter(|event| event.killed_by_player) {
+		let Some(table) = spawn_assets.enemy_assets.get(event.enemy_type)
+			.and_then(|meta| meta.fragments.clone()) else { continue };
+		if !spawn_assets.enemy_assets.entries.contains_key(&table.child) {
+			warn!("⚠️ {:?} fragments into unknown enemy '{}'", event.enemy_type, table.child);
+			continue;
+		}
+		let inherited = velocity_query.get(event.entity).map_or(Vec2::ZERO, |velocity| velocity.0);
+		let count = fragment_count(&table, &mut rng);
+		let base_angle = rng.gen_range(0.0..std::f32::consts::TAU);
+
+		for i in 0..count {
+			let jitter = rng.gen_range(-FRAGMENT_ANGLE_JITTER..FRAGMENT_ANGLE_JITTER);
+			let direction = fragment_direction(i, count, base_angle, jitter);
+			let position = event.position + direction * table.spawn_radius;
+			let velocity = inherited + direction * table.spread_speed;
+
+			spawn_assets.spawn_with_behavior(
+				&mut commands,
+				&table.child,
+				Transform::from_translation(position.extend(0.5)),
+				EnemyBehavior::new(vec![Behavior {
+					behavior_type: BehaviorType::MoveStraight { velocity },
+					duration: None,
+					transition: TransitionType::WaitForCompletion,
+					flow: BehaviorFlow::default(),
+				}], position),
+			);
+		}
+		info!("🪨 {:?} split into {} {}", event.enemy_type, count, table.child);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn table(min_count: u32, max_count: u32) -> FragmentTable {
+		FragmentTable { child: "SmallAsteroid".to_string(), min_count, max_count, spread_speed: 100.0, spawn_radius: 10.0 }
+	}
+
+	#[test]
+	fn fragment_count_stays_within_the_table() {
+		let mut rng = rand::thread_rng();
+		let table = table(2, 3);
+		let counts: Vec<u32> = (0..200).map(|_| fragment_count(&table, &mut rng)).collect();
+		assert!(counts.iter().all(|count| (2..=3).contains(count)));
+		assert!(counts.contains(&2) && counts.contains(&3));
+	}
+
+	#[test]
+	fn fragment_count_treats_a_low_max_as_the_min() {
+		let mut rng = rand::thread_rng();
+		assert_eq!(fragment_count(&table(3, 1), &mut rng), 3);
+	}
+
+	#[test]
+	fn fragments_spread_evenly_around_the_circle() {
+		for count in 1..=5 {
+			let directions: Vec<Vec2> = (0..count).map(|i| fragment_direction(i, count, 0.3, 0.0)).collect();
+			for (i, direction) in directions.iter().enumerate() {
+				assert!((direction.length() - 1.0).abs() < 1e-5);
+				let next = directions[(i + 1) % directions.len()];
+				if count > 1 {
+					let gap = direction.angle_to(next).rem_euclid(std::f32::consts::TAU);
+					assert!((gap - std::f32::consts::TAU / count as f32).abs() < 1e-4);
+				}
+			}
+			if count > 1 {
+				assert!(directions.iter().sum::<Vec2>().length() < 1e-4);
+			}
+		}
+	}
+
+	#[test]
+	fn fragment_jitter_turns_the_direction() {
+		let straight = fragment_direction(0, 2, 0.0, 0.0);
+		let jittered = fragment_direction(0, 2, 0.0, FRAGMENT_ANGLE_JITTER);
+		assert!((straight.angle_to(jittered) - FRAGMENT_ANGLE_JITTER).abs() < 1e-5);
+	}
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::collections::HashMap;
+use crate::components::{Player, PlayerDown, EnemyProjectile, Grazed, Collider, PlayerCoreHitbox, ChargeMeter, Particle, Invincible};
+use crate::resources::Score;
+use crate::systems::audio::PlaySfxEvent;
+use crate::systems::collision::ENEMY_PROJECTILE_RADIUS;
+
+/// Extra distance beyond a hit at which a passing enemy projectile counts as a graze
+pub const GRAZE_RADIUS: f32 = 40.0;
+const GRAZE_SCORE: u64 = 50;
+const GRAZE_CHARGE: f32 = 0.15;
+
+/// Count each enemy projectile once when it passes through a ship's graze ring without hitting;
+/// the graze lands when the shot leaves the ring (or expires inside it), and the charge goes to
+/// the ship that grazed it. Invincible ships don't graze.
+#[allow(clippy::type_complexity)]
+pub fn detect_grazes(
+	mut commands: Commands,
+	mut player_query: Query<(Entity, &Transform, &Collider, Option<&PlayerCoreHitbox>, &mut ChargeMeter, Has<Invincible>), (With<Player>, Without<PlayerDown>)>,
+	projectiles: Query<(Entity, &Transform), (With<EnemyProjectile>, Without<Grazed>)>,
+	mut score: ResMut<Score>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+	// Shots currently inside a graze ring: projectile -> (ship, last position)
+	mut in_ring: Local<HashMap<Entity, (Entity, Vec2)>>,
+) {
+	let mut grazes: Vec<(Entity, Vec2)> = Vec::new();
+
+	// Shots that expired while inside a ring never hit, so they count
+	in_ring.retain(|&projectile, &mut (player, pos)| {
+		if projectiles.contains(projectile) {
+			return true;
+		}
+		grazes.push((player, pos));
+		false
+	});
+
+	for (entity, transform) in projectiles.iter() {
+		let pos = transform.translation.truncate();
+
+		for (player_entity, player_transform, collider, core_hitbox, _, invincible) in player_query.iter() {
+			let tracked = in_ring.get(&entity).map(|&(player, _)| player);
+			if tracked.is_some_and(|player| player != player_entity) {
+				continue;
+			}
+			let player_pos = player_transform.translation.truncate();
+			let hit_distance = core_hitbox.map_or(collider.radius, |core| core.radius) + ENEMY_PROJECTILE_RADIUS;
+			let distance = player_pos.distance(pos);
+
+			if distance < hit_distance {
+				// A hit (collision already handled it) or a pass through an invincible ship: never a graze
+				in_ring.remove(&entity);
+				commands.entity(entity).insert(Grazed);
+				break;
+			}
+			if distance < hit_distance + GRAZE_RADIUS {
+				if tracked.is_some() || !invincible {
+					in_ring.insert(entity, (player_entity, pos));
+				}
+				break;
+			}
+			if tracked.is_some() {
+				// Left the ring without hitting
+				in_ring.remove(&entity);
+				commands.entity(entity).insert(Grazed);
+				grazes.push((player_entity, pos));
+				break;
+			}
+		}
+	}
+
+	let mut rng = rand::thread_rng();
+	for (player, pos) in grazes {
+		let Ok((_, player_transform, _, _, mut charge_meter, invincible)) = player_query.get_mut(player) else { continue };
+		if invincible {
+			continue;
+		}
+		score.grazes += 1;
+		score.points += GRAZE_SCORE;
+		charge_meter.current = (charge_meter.current + GRAZE_CHARGE).min(charge_meter.max);
+
+		// Small spark pair flicking away from the ship
+		let away = (pos - player_transform.translation.truncate()).normalize_or_zero();
+		for _ in 0..2 {
+			let jitter = Vec2::new(rng.gen_range(-40.0..40.0), rng.gen_range(-40.0..40.0));
+			commands.spawn((
+				Sprite {
+					color: Color::srgba(0.8, 0.95, 1.0, 0.9),
+					custom_size: Some(Vec2::splat(4.0)),
+					..default()
+				},
+				Transform::from_xyz(pos.x, pos.y, 2.0),
+				Particle {
+					lifetime: Timer::from_seconds(0.25, TimerMode::Once),
+					velocity: away * 160.0 + jitter,
+				},
+			));
+		}
+		sfx_events.send(PlaySfxEvent::simple("sounds/score_tick.ogg", 0.25, 20, 0.04));
+	}
+}
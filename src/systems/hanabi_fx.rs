@@ -90,6 +90,16 @@ pub fn setup_particle_effects(
         needs_texture: true,
     });
 
+    // === BOMB ===
+
+    // Expanding ring of white/cyan sparks that sweeps the whole play area
+    named_effects.push(NamedEffect {
+        name: "bomb_shockwave",
+        handle: create_shockwave_ring(&mut effects, 600, 900.0, 1100.0, 1.0, 8.0, 14.0),
+        cleanup_time: 1.5,
+        needs_texture: false,
+    });
+
     commands.insert_resource(ParticleEffects {
         effects: named_effects,
         flake_texture,
@@ -164,6 +174,78 @@ fn create_spark_burst(
     effects.add(effect)
 }
 
+/// Create a flat expanding ring (bomb shockwave) - particles start on a circle and fly outward in-plane
+fn create_shockwave_ring(
+    effects: &mut Assets<EffectAsset>,
+    particle_count: u32,
+    speed_min: f32,
+    speed_max: f32,
+    lifetime: f32,
+    size_min: f32,
+    size_max: f32,
+) -> Handle<EffectAsset> {
+    let writer = ExprWriter::new();
+
+    let age = writer.lit(0.0).expr();
+    let init_age = SetAttributeModifier::new(Attribute::AGE, age);
+
+    let lifetime = writer.lit(lifetime * 0.8).uniform(writer.lit(lifetime)).expr();
+    let init_lifetime = SetAttributeModifier::new(Attribute::LIFETIME, lifetime);
+
+    let init_pos = SetPositionCircleModifier {
+        center: writer.lit(Vec3::ZERO).expr(),
+        axis: writer.lit(Vec3::Z).expr(),
+        radius: writer.lit(20.0).expr(),
+        dimension: ShapeDimension::Surface,
+    };
+
+    let speed = writer.lit(speed_min).uniform(writer.lit(speed_max)).expr();
+    let init_vel = SetVelocityCircleModifier {
+        center: writer.lit(Vec3::ZERO).expr(),
+        axis: writer.lit(Vec3::Z).expr(),
+        speed,
+    };
+
+    let size = writer.lit(size_min).uniform(writer.lit(size_max)).expr();
+    let init_size = SetAttributeModifier::new(Attribute::SIZE, size);
+
+    // White-hot core fading through cyan
+    let color_gradient = Gradient::new()
+        .with_key(0.0, Vec4::new(1.0, 1.0, 1.0, 1.0))
+        .with_key(0.3, Vec4::new(0.6, 0.95, 1.0, 1.0))
+        .with_key(0.7, Vec4::new(0.2, 0.6, 1.0, 0.7))
+        .with_key(1.0, Vec4::new(0.1, 0.2, 0.6, 0.0));
+
+    let size_gradient = Gradient::new()
+        .with_key(0.0, Vec3::splat(1.0))
+        .with_key(1.0, Vec3::splat(0.3));
+
+    let drag = writer.lit(0.8).expr();
+
+    let spawner = SpawnerSettings::once((particle_count as f32).into());
+    let module = writer.finish();
+
+    let effect = EffectAsset::new(particle_count, spawner, module)
+        .with_name("shockwave_ring")
+        .init(init_pos)
+        .init(init_vel)
+        .init(init_age)
+        .init(init_lifetime)
+        .init(init_size)
+        .update(LinearDragModifier::new(drag))
+        .render(ColorOverLifetimeModifier {
+            gradient: color_gradient,
+            blend: ColorBlendMode::Overwrite,
+            mask: ColorBlendMask::RGBA,
+        })
+        .render(SizeOverLifetimeModifier {
+            gradient: size_gradient,
+            screen_space_size: false,
+        });
+
+    effects.add(effect)
+}
+
 /// Create burning aluminum flakes - bright white/silver flickering debris that floats down
 /// Uses a texture so particles render as quads (not size-limited points)
 fn create_aluminum_flakes(
@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use crate::components::{Enemy, Collider, Dying, Player, Weapon, SubWeapon};
+use crate::resources::EnemyAssetRegistry;
+use crate::systems::level::{CurrentLevel, LevelSource, parse_level_yaml};
+use crate::systems::level_editor::LevelEditorState;
+use crate::systems::spawn::enemy_manifest_components;
+use crate::systems::weapon_tuning::WeaponTuning;
+
+/// How often the level YAML, enemy manifest and weapon tuning are checked for changes
+const POLL_INTERVAL_SECS: f32 = 0.5;
+
+/// Polls file modification times so level/manifest edits apply without a restart
+#[derive(Resource)]
+pub struct HotReloadState {
+	poll_timer: Timer,
+	level_path: Option<PathBuf>,
+	level_modified: Option<SystemTime>,
+	manifest_modified: Option<SystemTime>,
+	weapon_tuning_modified: Option<SystemTime>,
+}
+
+impl Default for HotReloadState {
+	fn default() -> Self {
+		Self {
+			poll_timer: Timer::from_seconds(POLL_INTERVAL_SECS, TimerMode::Repeating),
+			level_path: None,
+			level_modified: None,
+			manifest_modified: EnemyAssetRegistry::manifest_modified(),
+			weapon_tuning_modified: WeaponTuning::file_modified(),
+		}
+	}
+}
+
+fn modified_time(path: &PathBuf) -> Option<SystemTime> {
+	fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+pub fn hot_reload_level(
+	time: Res<Time>,
+	mut state: ResMut<HotReloadState>,
+	editor: Res<LevelEditorState>,
+	level: Option<ResMut<CurrentLevel>>,
+	source: Option<ResMut<LevelSource>>,
+	mut held_back: Local<bool>,
+) {
+	state.poll_timer.tick(time.delta());
+	if !state.poll_timer.just_finished() {
+		return;
+	}
+	let (Some(mut level), Some(mut source)) = (level, source) else { return };
+
+	// New level loaded (or first poll): just take a baseline
+	if state.level_path.as_ref() != Some(&source.path) {
+		state.level_path = Some(source.path.clone());
+		state.level_modified = modified_time(&source.path);
+		return;
+	}
+
+	let modified = modified_time(&source.path);
+	if modified.is_none() || modified == state.level_modified {
+		return;
+	}
+
+	// Keep the old mtime so the change is picked up once the editor closes or saves
+	if editor.active && editor.dirty {
+		if !*held_back {
+			warn!("🔄 {} changed on disk; reloading once the editor closes or saves", source.path.display());
+			*held_back = true;
+		}
+		return;
+	}
+	*held_back = false;
+	state.level_modified = modified;
+
+	let yaml_str = match fs::read_to_string(&source.path) {
+		Ok(contents) => contents,
+		Err(e) => {
+			error!("Failed to re-read level file {}: {}", source.path.display(), e);
+			return;
+		}
+	};
+	match parse_level_yaml(&yaml_str) {
+		Ok((data, is_v1)) => {
+			for problem in data.path_problems() {
+				warn!("⚠️  {}", problem);
+			}
+			*level = level.reloaded(data.expanded());
+			source.data = data;
+			source.is_v1 = is_v1;
+			info!("🔄 Reloaded level {} at distance {:.0} ({} waves)",
+				source.path.display(), level.distance, level.data.enemy_waves.len());
+		}
+		Err(e) => {
+			// Keep playing the old data; the file is probably mid-edit
+			error!("Failed to parse level YAML {}, keeping previous: {}", source.path.display(), e);
+		}
+	}
+}
+
+pub fn hot_reload_enemy_manifest(
+	mut commands: Commands,
+	mut state: ResMut<HotReloadState>,
+	mut enemy_assets: ResMut<EnemyAssetRegistry>,
+	enemies: Query<(Entity, &Enemy, &Collider), Without<Dying>>,
+) {
+	// Shares the poll timer ticked by hot_reload_level (runs after it)
+	if !state.poll_timer.just_finished() {
+		return;
+	}
+
+	let modified = EnemyAssetRegistry::manifest_modified();
+	if modified.is_none() || modified == state.manifest_modified {
+		return;
+	}
+	state.manifest_modified = modified;
+
+	if !enemy_assets.reload() {
+		return;
+	}
+
+	// Re-apply hitboxes and sockets to live enemies
+	let mut count = 0;
+	for (entity, enemy, collider) in enemies.iter() {
+		let (hitbox, sockets) = enemy_manifest_components(enemy.enemy_type, collider.radius * 2.0, &enemy_assets);
+		commands.entity(entity).insert((hitbox, sockets));
+		count += 1;
+	}
+	info!("🔄 Re-applied manifest hitboxes/sockets to {} live enemies", count);
+}
+
+pub fn hot_reload_weapon_tuning(
+	mut state: ResMut<HotReloadState>,
+	mut tuning: ResMut<WeaponTuning>,
+	mut weapons: Query<(&mut Weapon, Option<&mut SubWeapon>), With<Player>>,
+) {
+	// Shares the poll timer ticked by hot_reload_level (runs after it)
+	if !state.poll_timer.just_finished() {
+		return;
+	}
+
+	let modified = WeaponTuning::file_modified();
+	if modified.is_none() || modified == state.weapon_tuning_modified {
+		return;
+	}
+	state.weapon_tuning_modified = modified;
+
+	if !tuning.reload() {
+		return;
+	}
+
+	// A balance pass may have dropped top levels; cooldowns pick up the new values on the next shot
+	for (mut weapon, sub_weapon) in weapons.iter_mut() {
+		clamp_to_max_level(&mut weapon, &tuning);
+		if let Some(mut sub_weapon) = sub_weapon {
+			clamp_to_max_level(&mut sub_weapon.0, &tuning);
+		}
+	}
+}
+
+fn clamp_to_max_level(weapon: &mut Weapon, tuning: &WeaponTuning) {
+	let max_level = tuning.weapon(weapon.weapon_type).max_level();
+	if weapon.level > max_level {
+		weapon.level = max_level;
+	}
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use crate::systems::level::{CurrentLevel, MusicState, InfoOverlayEnabled};
-use crate::components::{Player, Weapon, PlayerDefenses};
+use crate::components::{PrimaryPlayer, Weapon, PlayerDefenses};
+use crate::systems::rank::Rank;
 
 #[derive(Component)]
 pub struct InfoOverlayContainer;
@@ -67,8 +68,9 @@ pub fn update_info_overlay(
 	level: Option<Res<CurrentLevel>>,
 	music_state: Res<MusicState>,
 	diagnostics: Res<DiagnosticsStore>,
-	player_query: Query<(&PlayerDefenses, &Weapon), With<Player>>,
+	player_query: Query<(&PlayerDefenses, &Weapon), With<PrimaryPlayer>>,
 	selected_level: Res<crate::systems::level::SelectedLevel>,
+	rank: Res<Rank>,
 	mut text_query: Query<&mut Text, With<InfoOverlayText>>,
 ) {
 	if let Some(level) = level {
@@ -110,7 +112,7 @@ pub fn update_info_overlay(
 		let time_str = format!("{:02}:{:02}", minutes, seconds);
 
 		let info_text = format!(
-			"Level: {}\nPhase: {}\nDistance: {} GU\nTime: {}\nMusic: {}\nScroll: {:.1} GU/s\nFPS: {:.0}\n\nDefenses:\n{}\nWeapon: {}\nLevel: {}",
+			"Level: {}\nPhase: {}\nDistance: {} GU\nTime: {}\nMusic: {}\nScroll: {:.1} GU/s\nFPS: {:.0}\nRank: {:.2} (earned {:+.2})\n\nDefenses:\n{}\nWeapon: {}\nLevel: {}",
 			selected_level.level_number,
 			phase_name,
 			distance,
@@ -118,6 +120,8 @@ pub fn update_info_overlay(
 			music_path,
 			scroll_speed,
 			fps,
+			rank.value,
+			rank.earned,
 			armor_info,
 			weapon_name,
 			weapon_level
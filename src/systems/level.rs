@@ -3,8 +3,8 @@ use bevy_kira_audio::prelude::*;
 use std::fs;
 use crate::level::{LevelData, LevelDataV2, LevelDataV3};
 use crate::components::DistanceLocked;
-use crate::resources::EnemyAssetRegistry;
-use super::world::{sizes, doodad_sizes};
+use super::world::doodad_sizes;
+use super::spawn::EnemySpawnAssets;
 
 #[derive(Resource, Default)]
 pub struct SelectedLevel {
@@ -323,6 +323,95 @@ impl CurrentLevel {
 		}
 	}
 
+	/// Rebuild for a runtime timeline jump to `target`. Like `with_start_distance`, but
+	/// structures/doodads recent enough to still be on screen are left unprocessed so
+	/// `process_doodads` respawns them at their distance-correct positions.
+	pub fn jumped_to(&self, target: f32) -> Self {
+		let mut level = Self::with_start_distance(self.data.clone(), target);
+		level.time_elapsed = self.time_elapsed;
+
+		// Same window process_doodads uses to decide what is spawnable
+		let base_threshold = level.get_scroll_speed() * 10.0;
+		let still_visible = |spawn_distance: f32, size: Option<[f32; 2]>| {
+			let size_buffer = size.map(|[w, h]| w.max(h) * 2.0).unwrap_or(0.0);
+			spawn_distance > target - (base_threshold + size_buffer)
+		};
+		level.processed_structures.retain(|&i| {
+			let structure = &level.data.structures[i];
+			!still_visible(structure.spawn_distance, structure.size)
+		});
+		level.processed_doodads.retain(|&i| {
+			let doodad = &level.data.doodads[i];
+			!still_visible(doodad.spawn_distance, doodad.size)
+		});
+
+		level
+	}
+
+	/// Rebuild with new level data at the current distance (hot-reload).
+	/// Content before the current distance is skipped as usual; content already
+	/// processed ahead of it stays processed if it still exists in the new data
+	/// (matched by spawn distance + sprite/enemy list, since indices may shift).
+	pub fn reloaded(&self, data: LevelData) -> Self {
+		let mut level = Self::with_start_distance(data, self.distance);
+		level.time_elapsed = self.time_elapsed;
+		level.last_milestone = self.last_milestone;
+
+		for &old_idx in &self.processed_waves {
+			let Some(old_wave) = self.data.enemy_waves.get(old_idx) else { continue };
+			let matched = level.data.enemy_waves.iter().position(|w| {
+				w.spawn_distance == old_wave.spawn_distance && w.enemies.len() == old_wave.enemies.len()
+			});
+			if let Some(new_idx) = matched {
+				if !level.processed_waves.contains(&new_idx) {
+					level.processed_waves.push(new_idx);
+				}
+			}
+		}
+		// Partially spawned waves: carry over which enemies are already out
+		for &(old_wave_idx, enemy_idx) in &self.spawned_enemies {
+			let Some(old_wave) = self.data.enemy_waves.get(old_wave_idx) else { continue };
+			let matched = level.data.enemy_waves.iter().position(|w| w.spawn_distance == old_wave.spawn_distance);
+			if let Some(new_idx) = matched {
+				if enemy_idx < level.data.enemy_waves[new_idx].enemies.len() {
+					level.spawned_enemies.insert((new_idx, enemy_idx));
+				}
+			}
+		}
+
+		for &old_idx in &self.processed_doodads {
+			let Some(old) = self.data.doodads.get(old_idx) else { continue };
+			let matched = level.data.doodads.iter().enumerate().position(|(i, d)| {
+				d.spawn_distance == old.spawn_distance && d.sprite == old.sprite && !level.processed_doodads.contains(&i)
+			});
+			if let Some(new_idx) = matched {
+				level.processed_doodads.push(new_idx);
+			}
+		}
+
+		for &old_idx in &self.processed_structures {
+			let Some(old) = self.data.structures.get(old_idx) else { continue };
+			let matched = level.data.structures.iter().enumerate().position(|(i, s)| {
+				s.spawn_distance == old.spawn_distance && s.sprite == old.sprite && !level.processed_structures.contains(&i)
+			});
+			if let Some(new_idx) = matched {
+				level.processed_structures.push(new_idx);
+			}
+		}
+
+		for &old_idx in &self.processed_events {
+			let Some(old) = self.data.events.get(old_idx) else { continue };
+			let matched = level.data.events.iter().enumerate().position(|(i, e)| {
+				e.distance == old.distance && !level.processed_events.contains(&i)
+			});
+			if let Some(new_idx) = matched {
+				level.processed_events.push(new_idx);
+			}
+		}
+
+		level
+	}
+
 	pub fn get_current_phase(&self) -> Option<&crate::level::Phase> {
 		self.data.phases.iter().find(|p| {
 			self.distance >= p.start_distance && self.distance < p.end_distance
@@ -336,6 +425,45 @@ impl CurrentLevel {
 	}
 }
 
+/// Parse level YAML: try V3 (geography/sections), then V2 (zones), then V1 (raw doodads).
+/// Returns the level converted to V1 `LevelData` and whether the file was V1 already.
+pub fn parse_level_yaml(yaml_str: &str) -> Result<(LevelData, bool), serde_yaml::Error> {
+	if let Ok(v3_level) = serde_yaml::from_str::<LevelDataV3>(yaml_str) {
+		if !v3_level.sections.is_empty() {
+			info!("✓ Loaded V3 geography-based level with {} sections", v3_level.sections.len());
+			return Ok((v3_level.to_level_data(), false));
+		}
+	}
+	if let Ok(v2_level) = serde_yaml::from_str::<LevelDataV2>(yaml_str) {
+		if !v2_level.zones.is_empty() {
+			info!("✓ Loaded V2 zone-based level with {} zones", v2_level.zones.len());
+			return Ok((v2_level.to_level_data(), false));
+		}
+	}
+	serde_yaml::from_str::<LevelData>(yaml_str).map(|level| (level, true))
+}
+
+/// Authored (unexpanded) level data plus the file it came from.
+/// `CurrentLevel::data` is derived from this via `LevelData::expanded()`.
+#[derive(Resource)]
+pub struct LevelSource {
+	pub path: std::path::PathBuf,
+	pub data: LevelData,
+	pub is_v1: bool,
+}
+
+impl LevelSource {
+	/// Where edits get written: V1 files in place, V2/V3 files next to the original
+	/// (so converted output never clobbers zone/section authoring)
+	pub fn save_path(&self) -> std::path::PathBuf {
+		if self.is_v1 {
+			self.path.clone()
+		} else {
+			self.path.with_extension("v1.yaml")
+		}
+	}
+}
+
 pub fn load_level(
 	mut commands: Commands,
 	asset_server: Res<AssetServer>,
@@ -347,53 +475,30 @@ pub fn load_level(
 	let yaml_path = format!("assets/level-defs/level{}.yaml", level_num);
 
 	// Try working directory first (for cargo run), then exe directory (for distribution)
+	let mut resolved_path = std::path::PathBuf::from(&yaml_path);
 	let yaml_content = fs::read_to_string(&yaml_path).or_else(|_| {
 		let exe_dir = std::env::current_exe()
 			.ok()
 			.and_then(|p| p.parent().map(|p| p.to_path_buf()))
 			.unwrap_or_default();
-		fs::read_to_string(exe_dir.join(&yaml_path))
+		resolved_path = exe_dir.join(&yaml_path);
+		fs::read_to_string(&resolved_path)
 	});
 
 	match yaml_content {
 		Ok(yaml_str) => {
-			// Try V3 (geography/sections), then V2 (zones), then V1 (raw doodads)
-			let level = match serde_yaml::from_str::<LevelDataV3>(&yaml_str) {
-				Ok(v3_level) if !v3_level.sections.is_empty() => {
-					info!("✓ Loaded V3 geography-based level with {} sections", v3_level.sections.len());
-					v3_level.to_level_data()
-				}
-				_ => {
-					match serde_yaml::from_str::<LevelDataV2>(&yaml_str) {
-						Ok(v2_level) if !v2_level.zones.is_empty() => {
-							info!("✓ Loaded V2 zone-based level with {} zones", v2_level.zones.len());
-							v2_level.to_level_data()
-						}
-						_ => serde_yaml::from_str::<LevelData>(&yaml_str).expect("Failed to parse level YAML")
-					}
-				}
-			};
-
-			// Expand geography into doodads (tiles are doodads)
-			let mut expanded_level = level.clone();
-			let mut geo_doodads = Vec::new();
-			for geo in &expanded_level.geography {
-				geo_doodads.extend(geo.expand_to_doodads());
-			}
-			expanded_level.doodads.extend(geo_doodads);
-			info!("✓ Expanded {} geography elements into doodads", expanded_level.geography.len());
-
-			// Expand structure_grids into structures
-			let grid_count = expanded_level.structure_grids.len();
-			let mut grid_structures = Vec::new();
-			for grid in &expanded_level.structure_grids {
-				grid_structures.extend(grid.expand_to_structures());
+			let (level, is_v1) = parse_level_yaml(&yaml_str).expect("Failed to parse level YAML");
+			for problem in level.path_problems() {
+				warn!("⚠️  {}", problem);
 			}
-			let grid_structure_count = grid_structures.len();
-			expanded_level.structures.extend(grid_structures);
-			if grid_count > 0 {
+
+			// Expand geography into doodads (tiles are doodads) and structure_grids into structures
+			let expanded_level = level.expanded();
+			info!("✓ Expanded {} geography elements into doodads", level.geography.len());
+			let grid_structure_count = expanded_level.structures.len() - level.structures.len();
+			if !level.structure_grids.is_empty() {
 				info!("✓ Expanded {} structure grids into {} structures",
-					grid_count, grid_structure_count);
+					level.structure_grids.len(), grid_structure_count);
 			}
 
 			// Keep structures separate - don't merge into doodads
@@ -422,6 +527,11 @@ pub fn load_level(
 			}
 
 			commands.insert_resource(CurrentLevel::with_start_distance(expanded_level, start_distance));
+			commands.insert_resource(LevelSource {
+				path: resolved_path,
+				data: level,
+				is_v1,
+			});
 		}
 		Err(e) => {
 			error!("Failed to read level file {}: {}", yaml_path, e);
@@ -455,16 +565,13 @@ pub fn update_level_timer(
 pub fn process_enemy_waves(
 	mut level: ResMut<CurrentLevel>,
 	mut commands: Commands,
-	asset_server: Res<AssetServer>,
-	mut meshes: ResMut<Assets<Mesh>>,
-	mut effects_materials: ResMut<Assets<crate::materials::EffectsMaterial>>,
-	noise_texture: Res<crate::materials::noise::EffectsNoiseTexture>,
+	mut spawn_assets: EnemySpawnAssets,
 	mut formation_registry: ResMut<crate::components::FormationRegistry>,
-	enemy_assets: Res<EnemyAssetRegistry>,
+	rank: Res<super::rank::Rank>,
 ) {
-	use crate::components::{EnemyType, EnemyMovement, MovementPattern, EnemyBehavior, FormationLeader, FormationMember, EnemyFireOverride};
+	use crate::components::{EnemyType, EnemyMovement, MovementPattern, EnemyBehavior, FormationLeader, FormationMember, FormationPlan, EnemyFireOverride, EnemySpawner};
 	use crate::level::{FormationRole, EnemySpawn};
-	use crate::systems::spawn::{spawn_enemy_with_behavior, spawn_enemy_with_movement};
+	use crate::systems::spawn::enemy_sprite_for;
 
 	let current_distance = level.distance;
 	let scroll_speed = level.get_scroll_speed();
@@ -522,24 +629,22 @@ pub fn process_enemy_waves(
 				continue;
 			}
 
-			let (sprite_path, size, enemy_type) = match enemy.enemy_type.as_str() {
-				"Scout" => ("enemies/scout.png", sizes::SCOUT, EnemyType::Scout),
-				"ScoutSting" => ("enemies/scout_sting.png", sizes::SCOUT_STING, EnemyType::ScoutSting),
-				"Fighter" => ("enemies/fighter.png", sizes::FIGHTER, EnemyType::Fighter),
-				"HeavyGunship" => ("enemies/heavy_gunship.png", sizes::HEAVY_GUNSHIP, EnemyType::HeavyGunship),
-				"Boss" => ("enemies/boss.png", sizes::BOSS, EnemyType::Boss),
-				"Interceptor" => ("enemies/interceptor.png", sizes::INTERCEPTOR, EnemyType::Interceptor),
-				"Drone" => ("enemies/drone.png", sizes::DRONE, EnemyType::Drone),
-				"Bomber" => ("enemies/bomber.png", sizes::BOMBER, EnemyType::Bomber),
-				"Corvette" => ("enemies/corvette.png", sizes::CORVETTE, EnemyType::Corvette),
-				"Drill" => ("enemies/drill/drill_0.png", sizes::DRILL, EnemyType::Drill),
-				"SmallAsteroid" => ("enemies/small_asteroid.png", sizes::SMALL_ASTEROID, EnemyType::SmallAsteroid),
-				"MediumAsteroid" => ("enemies/medium_asteroid.png", sizes::MEDIUM_ASTEROID, EnemyType::MediumAsteroid),
-				"LargeAsteroid" => ("enemies/large_asteroid.png", sizes::LARGE_ASTEROID, EnemyType::LargeAsteroid),
-				"StationDebris" => ("enemies/station_debris.png", sizes::STATION_DEBRIS, EnemyType::StationDebris),
-				"AsteroidTurret" => ("enemies/asteroid_turret.png", sizes::ASTEROID_TURRET, EnemyType::AsteroidTurret),
-				_ => ("enemies/scout.png", sizes::SCOUT, EnemyType::Scout),
-			};
+			// Extra spawns for harder difficulties count as handled when they're skipped.
+			// Ones meant for the next difficulty up can still come in at high rank.
+			if let Some(min_difficulty) = enemy.min_difficulty {
+				let difficulty = spawn_assets.difficulty.difficulty;
+				if difficulty < min_difficulty {
+					let rank_extra = difficulty.next() == Some(min_difficulty)
+						&& rand::random::<f32>() < rank.extra_spawn_chance();
+					if !rank_extra {
+						level.spawned_enemies.insert((wave_idx, enemy_idx));
+						continue;
+					}
+					info!("📈 Rank {:.2} brought in an extra {}", rank.value, enemy.enemy_type);
+				}
+			}
+
+			let (_, _, enemy_type) = enemy_sprite_for(&enemy.enemy_type);
 
 			let mut behaviors = enemy.get_behaviors();
 			if behaviors.is_empty() {
@@ -551,29 +656,19 @@ pub fn process_enemy_waves(
 				// YAML Y position is ignored - enemies enter from top of screen
 				let spawn_y = 600.0;  // Above viewport top edge (Y=500)
 
-				let entity_id = spawn_enemy_with_behavior(
+				let entity_id = spawn_assets.spawn_with_behavior(
 					&mut commands,
-					&asset_server,
-					&mut meshes,
-					&mut effects_materials,
-					&noise_texture.0,
-					&enemy_assets,
-					enemy_type,
-					sprite_path,
-					size,
+					&enemy.enemy_type,
 					Transform::from_xyz(enemy.position[0], spawn_y, 0.5),
-					EnemyBehavior {
-						behaviors: behaviors.clone(),
-						current_index: 0,
-						behavior_start_time: 0.0,
-						total_time_alive: 0.0,
-						spawn_position: Vec2::new(enemy.position[0], spawn_y),
-					},
+					EnemyBehavior::new(behaviors.clone(), Vec2::new(enemy.position[0], spawn_y)),
 				);
 
 				if let Some(firing) = enemy.firing.clone() {
 					commands.entity(entity_id).insert(EnemyFireOverride { overrides: firing });
 				}
+				if let Some(spawner) = enemy.spawner.clone().or_else(|| enemy_type.default_spawner()) {
+					commands.entity(entity_id).insert(EnemySpawner::new(spawner));
+				}
 
 				if let Some(ref formation_id) = enemy.formation_id {
 					match enemy.formation_role {
@@ -583,6 +678,11 @@ pub fn process_enemy_waves(
 								member_offsets: Vec::new(),
 							});
 							formation_registry.formations.insert(formation_id.clone(), entity_id);
+							formation_registry.plans.insert(formation_id.clone(), FormationPlan::new(
+								enemy.formation_maneuvers.clone(),
+								enemy.on_leader_death.unwrap_or_default(),
+								enemy.formation_follow_rate,
+							));
 						}
 						Some(FormationRole::Member) => {
 							let offset = enemy.formation_offset
@@ -622,16 +722,9 @@ pub fn process_enemy_waves(
 					MovementPattern::Straight { speed: 100.0 }
 				};
 
-				let entity_id = spawn_enemy_with_movement(
+				let entity_id = spawn_assets.spawn_with_movement(
 					&mut commands,
-					&asset_server,
-					&mut meshes,
-					&mut effects_materials,
-					&noise_texture.0,
-					&enemy_assets,
-					enemy_type,
-					sprite_path,
-					size,
+					&enemy.enemy_type,
 					Transform::from_xyz(enemy.position[0], enemy.position[1], 0.5),
 					EnemyMovement {
 						pattern: movement_pattern,
@@ -643,6 +736,9 @@ pub fn process_enemy_waves(
 				if let Some(firing) = enemy.firing.clone() {
 					commands.entity(entity_id).insert(EnemyFireOverride { overrides: firing });
 				}
+				if let Some(spawner) = enemy.spawner.clone().or_else(|| enemy_type.default_spawner()) {
+					commands.entity(entity_id).insert(EnemySpawner::new(spawner));
+				}
 
 				// Mark this enemy as spawned
 				level.spawned_enemies.insert((wave_idx, enemy_idx));
@@ -753,17 +849,7 @@ pub fn process_doodads(
 		let sprite_path = doodad.sprite.clone();
 
 		// Get layer speed multiplier first (needed for spawn Y calculation)
-		let speed_multiplier = match doodad.layer {
-			DoodadLayer::DeepSpace => ParallaxLayer::DeepSpace.speed_multiplier(),
-			DoodadLayer::FarField => ParallaxLayer::FarField.speed_multiplier(),
-			DoodadLayer::DeepStructures => ParallaxLayer::DeepStructures.speed_multiplier(),
-			DoodadLayer::MegaStructures => ParallaxLayer::MegaStructures.speed_multiplier(),
-			DoodadLayer::MidDistance => ParallaxLayer::MidDistance.speed_multiplier(),
-			DoodadLayer::StructureDetails => ParallaxLayer::StructureDetails.speed_multiplier(),
-			DoodadLayer::NearBackground => ParallaxLayer::NearBackground.speed_multiplier(),
-			DoodadLayer::Gameplay => 1.0,
-			DoodadLayer::Foreground => ParallaxLayer::Foreground.speed_multiplier(),
-		};
+		let speed_multiplier = doodad.layer.speed_multiplier();
 
 		// Calculate depth-based scale (farthest 2 layers stay 1.0x, others scale by speed)
 		let depth_scale = if speed_multiplier <= 0.1 {
@@ -856,6 +942,15 @@ pub fn process_doodads(
 			}
 		};
 
+		// Late-entry doodads (after a timeline jump) start part-way down their path.
+		// Normal spawning happens before spawn_distance is reached, so this is a no-op there.
+		let spawn_y = if !is_structure && doodad.spawn_distance > 1000.0 {
+			let seconds_past = (current_distance - doodad.spawn_distance).max(0.0) / scroll_speed.max(1.0);
+			spawn_y - layer_speed * seconds_past
+		} else {
+			spawn_y
+		};
+
 		// Calculate z-depth: explicit z_depth > z_order > auto-calculation
 		let z_order_offset = doodad.z_order as f32 * 0.001;  // Each z_order step = 0.001 z-depth
 		let z_depth = if let Some(explicit_z) = doodad.z_depth {
@@ -0,0 +1,827 @@
+use bevy::prelude::*;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::window::PrimaryWindow;
+use std::fs;
+use crate::components::{Behavior, BehaviorType, EasingType, SineAxis, TransitionType, BehaviorFlow, FlightPath, PathCurve, PathRef};
+use crate::level::{DoodadLayer, DoodadSpawn, EnemySpawn, EnemyWave, Position, Structure};
+use crate::systems::level::{CurrentLevel, GamePaused, LevelSource};
+use crate::systems::timeline::TimelineJumpEvent;
+
+/// Enemy type names accepted by `process_enemy_waves`
+const ENEMY_TYPE_NAMES: [&str; 15] = [
+	"Scout", "ScoutSting", "Fighter", "HeavyGunship", "Boss", "Interceptor", "Drone", "Bomber",
+	"Corvette", "Drill", "SmallAsteroid", "MediumAsteroid", "LargeAsteroid", "StationDebris",
+	"AsteroidTurret",
+];
+
+const PICK_RADIUS: f32 = 40.0;
+const SCRUB_STEP_LINE: f32 = 100.0;   // GU per mouse wheel notch
+const SCRUB_STEP_PAGE: f32 = 1000.0;  // GU per PageUp/PageDown
+/// Wave grouping tolerance: placing an enemy within this distance of a wave joins that wave
+const WAVE_MERGE_DISTANCE: f32 = 1.0;
+/// Enemies always enter at Y=600 and scroll down at 100 GU/s (see `process_enemy_waves`)
+const ENEMY_ENTRY_VELOCITY: f32 = 100.0;
+/// Items further than this off-screen are not drawn
+const VIEW_MARGIN_Y: f32 = 650.0;
+
+const ENEMY_COLOR: Color = Color::srgb(1.0, 0.3, 0.3);
+const FORMATION_COLOR: Color = Color::srgb(1.0, 0.6, 0.2);
+const DOODAD_COLOR: Color = Color::srgb(0.3, 1.0, 0.5);
+const STRUCTURE_COLOR: Color = Color::srgb(0.4, 0.6, 1.0);
+const SELECTED_COLOR: Color = Color::srgb(1.0, 1.0, 0.2);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EditorLayer {
+	#[default]
+	Enemies,
+	Doodads,
+	Structures,
+}
+
+impl EditorLayer {
+	fn next(self) -> Self {
+		match self {
+			EditorLayer::Enemies => EditorLayer::Doodads,
+			EditorLayer::Doodads => EditorLayer::Structures,
+			EditorLayer::Structures => EditorLayer::Enemies,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditorSelection {
+	Enemy { wave: usize, enemy: usize },
+	/// A formation template in a wave's `formations` (moved/removed as a whole)
+	Formation { wave: usize, formation: usize },
+	Doodad(usize),
+	Structure(usize),
+}
+
+/// In-game level editor (F5). Edits `LevelSource::data` (authored V1 data);
+/// `CurrentLevel` is rebuilt from it when leaving the editor or saving.
+#[derive(Resource, Default)]
+pub struct LevelEditorState {
+	pub active: bool,
+	pub layer: EditorLayer,
+	pub selected: Option<EditorSelection>,
+	pub dragging: bool,
+	pub palette_index: usize,
+	pub behavior_index: usize,
+	pub preset_index: usize,
+	pub dirty: bool,
+}
+
+#[derive(Component)]
+pub struct LevelEditorPanel;
+
+#[derive(Component)]
+pub struct LevelEditorPanelText;
+
+/// Run condition: gameplay spawning/simulation is frozen while the editor is open
+pub fn editor_inactive(editor: Res<LevelEditorState>) -> bool {
+	!editor.active
+}
+
+// === View mapping ===
+// Editor shows authored content where it would be on screen at `distance`.
+
+fn enemy_screen_y(spawn_distance: f32, position: [f32; 2], distance: f32, scroll_speed: f32) -> f32 {
+	// Enemy reaches its target Y exactly when the player reaches the wave's spawn_distance
+	position[1] + (spawn_distance - distance) * ENEMY_ENTRY_VELOCITY / scroll_speed
+}
+
+fn structure_screen_y(structure: &Structure, distance: f32) -> f32 {
+	let distance_past = distance - structure.spawn_distance;
+	800.0 + structure.position.y() - distance_past * structure.layer.speed_multiplier()
+}
+
+fn doodad_start_y(doodad: &DoodadSpawn) -> f32 {
+	// Mirrors process_doodads: late doodads always enter at Y=800
+	if doodad.spawn_distance > 1000.0 { 800.0 } else { doodad.position.y() }
+}
+
+fn doodad_screen_y(doodad: &DoodadSpawn, distance: f32, scroll_speed: f32) -> f32 {
+	let seconds_past = (distance - doodad.spawn_distance) / scroll_speed;
+	doodad_start_y(doodad) + doodad.velocity[1] * seconds_past
+}
+
+fn set_enemy_screen_pos(spawn_distance: f32, position: &mut [f32; 2], distance: f32, scroll_speed: f32, pos: Vec2) {
+	*position = [
+		pos.x,
+		pos.y - (spawn_distance - distance) * ENEMY_ENTRY_VELOCITY / scroll_speed,
+	];
+}
+
+fn set_structure_screen_pos(structure: &mut Structure, distance: f32, pos: Vec2) {
+	let distance_past = distance - structure.spawn_distance;
+	let y_offset = pos.y - 800.0 + distance_past * structure.layer.speed_multiplier();
+	structure.position = Position::XY([pos.x, y_offset]);
+}
+
+fn set_doodad_screen_pos(doodad: &mut DoodadSpawn, distance: f32, scroll_speed: f32, pos: Vec2) {
+	let vy = doodad.velocity[1];
+	if doodad.spawn_distance > 1000.0 && vy != 0.0 {
+		// Entry Y is fixed, so vertical placement moves the spawn distance instead
+		doodad.spawn_distance = (distance - (pos.y - 800.0) * scroll_speed / vy).max(1000.1);
+		doodad.position = Position::XY([pos.x, doodad.position.y()]);
+	} else {
+		let seconds_past = (distance - doodad.spawn_distance) / scroll_speed;
+		doodad.position = Position::XY([pos.x, pos.y - vy * seconds_past]);
+	}
+}
+
+fn scroll_speed_at(level: &CurrentLevel) -> f32 {
+	level.get_scroll_speed().max(1.0)
+}
+
+fn behavior_presets() -> Vec<(&'static str, Behavior)> {
+	let behavior = |behavior_type, duration| Behavior {
+		behavior_type,
+		duration,
+		transition: TransitionType::WaitForCompletion,
+		flow: BehaviorFlow::default(),
+	};
+	vec![
+		("MoveStraight", behavior(BehaviorType::MoveStraight { velocity: Vec2::new(0.0, -120.0) }, None)),
+		("MoveSineWave", behavior(BehaviorType::MoveSineWave {
+			base_velocity: Vec2::new(0.0, -100.0),
+			amplitude: 100.0,
+			frequency: 2.0,
+			axis: SineAxis::Horizontal,
+		}, None)),
+		("MoveCircular", behavior(BehaviorType::MoveCircular {
+			center_offset: Vec2::ZERO,
+			radius: 80.0,
+			angular_speed: 1.5,
+			clockwise: true,
+		}, Some(3.0))),
+		("MoveToPosition", behavior(BehaviorType::MoveToPosition {
+			target: Vec2::new(0.0, 200.0),
+			speed: 200.0,
+			easing: EasingType::EaseOut,
+			until_arrived: false,
+		}, Some(2.0))),
+		("Wait", behavior(BehaviorType::Wait { maintain_velocity: false }, Some(1.0))),
+		("FacePlayer", behavior(BehaviorType::FacePlayer { rotation_speed: 3.0 }, Some(1.0))),
+		("FollowPlayer", behavior(BehaviorType::FollowPlayer {
+			speed: 150.0,
+			max_distance: None,
+			offset: Vec2::new(0.0, 200.0),
+		}, Some(3.0))),
+		("FollowPath", behavior(BehaviorType::FollowPath {
+			path: PathRef::Inline(FlightPath::new(
+				PathCurve::CatmullRom,
+				vec![Vec2::new(-700.0, 450.0), Vec2::new(-200.0, 150.0), Vec2::new(200.0, 150.0), Vec2::new(700.0, 450.0)],
+			)),
+			speed: 300.0,
+			easing: EasingType::Linear,
+			mirror: false,
+		}, None)),
+		("Charge", behavior(BehaviorType::Charge {
+			windup: 0.8,
+			speed: 650.0,
+			dash_time: Some(1.5),
+			flash_color: [1.0, 0.3, 0.2, 1.0],
+			explode: None,
+		}, None)),
+	]
+}
+
+/// Unique sprites already used by a layer - the palette for placing new items
+fn doodad_palette(source: &LevelSource) -> Vec<(String, DoodadLayer)> {
+	let mut palette: Vec<(String, DoodadLayer)> = Vec::new();
+	for doodad in &source.data.doodads {
+		if !palette.iter().any(|(sprite, _)| sprite == &doodad.sprite) {
+			palette.push((doodad.sprite.clone(), doodad.layer.clone()));
+		}
+	}
+	palette
+}
+
+fn structure_palette(source: &LevelSource) -> Vec<(String, DoodadLayer)> {
+	let mut palette: Vec<(String, DoodadLayer)> = Vec::new();
+	for structure in &source.data.structures {
+		if !palette.iter().any(|(sprite, _)| sprite == &structure.sprite) {
+			palette.push((structure.sprite.clone(), structure.layer.clone()));
+		}
+	}
+	palette
+}
+
+fn selected_enemy<'a>(editor: &LevelEditorState, source: &'a mut LevelSource) -> Option<&'a mut EnemySpawn> {
+	match editor.selected {
+		Some(EditorSelection::Enemy { wave, enemy }) => source.data.enemy_waves
+			.get_mut(wave)
+			.and_then(|w| w.enemies.get_mut(enemy)),
+		_ => None,
+	}
+}
+
+/// Rebuild runtime level state from the edited source, keeping distance and time
+fn resync_current_level(level: &mut CurrentLevel, source: &LevelSource) {
+	let time_elapsed = level.time_elapsed;
+	*level = CurrentLevel::with_start_distance(source.data.expanded(), level.distance);
+	level.time_elapsed = time_elapsed;
+}
+
+/// Write edits back to the file the level was loaded from, so the next load picks them up
+fn save_level_source(source: &LevelSource) {
+	let path = source.save_path();
+	match serde_yaml::to_string(&source.data) {
+		Ok(yaml) => match fs::write(&path, yaml) {
+			Ok(()) => {
+				if !source.is_v1 {
+					info!("💾 {} is a V2/V3 level - saved the flattened V1 copy next to it", source.path.display());
+				}
+				info!("💾 Saved level to {}", path.display());
+			}
+			Err(e) => error!("Failed to write level file {}: {}", path.display(), e),
+		},
+		Err(e) => error!("Failed to serialize level: {}", e),
+	}
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn toggle_level_editor(
+	mut commands: Commands,
+	keyboard: Res<ButtonInput<KeyCode>>,
+	mut editor: ResMut<LevelEditorState>,
+	mut paused: ResMut<GamePaused>,
+	level: Option<ResMut<CurrentLevel>>,
+	source: Option<Res<LevelSource>>,
+	panel_query: Query<Entity, With<LevelEditorPanel>>,
+	mut jump_events: EventWriter<TimelineJumpEvent>,
+) {
+	if !keyboard.just_pressed(KeyCode::F5) {
+		return;
+	}
+	let (Some(mut level), Some(source)) = (level, source) else {
+		warn!("Level editor unavailable: no level loaded");
+		return;
+	};
+
+	editor.active = !editor.active;
+	editor.selected = None;
+	editor.dragging = false;
+
+	if editor.active {
+		paused.0 = true;
+		info!("✏ LEVEL EDITOR - F5 to exit, Ctrl+S to save ({})", source.path.display());
+		spawn_editor_panel(&mut commands);
+	} else {
+		paused.0 = false;
+		resync_current_level(&mut level, &source);
+		// Respawn the scene for wherever we scrubbed to
+		jump_events.send(TimelineJumpEvent { distance: level.distance });
+		for entity in panel_query.iter() {
+			commands.entity(entity).despawn_recursive();
+		}
+		if editor.dirty {
+			info!("✏ Level editor closed with unsaved changes");
+		} else {
+			info!("✏ Level editor closed");
+		}
+	}
+}
+
+fn spawn_editor_panel(commands: &mut Commands) {
+	commands.spawn((
+		Node {
+			position_type: PositionType::Absolute,
+			right: Val::Px(10.0),
+			top: Val::Px(110.0),
+			width: Val::Px(360.0),
+			padding: UiRect::all(Val::Px(10.0)),
+			flex_direction: FlexDirection::Column,
+			..default()
+		},
+		BackgroundColor(Color::srgba(0.0, 0.0, 0.05, 0.8)),
+		LevelEditorPanel,
+	)).with_children(|parent| {
+		parent.spawn((
+			Text::new(""),
+			TextFont {
+				font_size: 12.0,
+				..default()
+			},
+			TextColor(Color::srgb(0.9, 0.9, 0.9)),
+			LevelEditorPanelText,
+		));
+	});
+}
+
+/// Scrub `CurrentLevel::distance` with the mouse wheel / PageUp / PageDown / Home
+pub fn editor_scrub_distance(
+	editor: Res<LevelEditorState>,
+	keyboard: Res<ButtonInput<KeyCode>>,
+	mut wheel_events: EventReader<MouseWheel>,
+	level: Option<ResMut<CurrentLevel>>,
+) {
+	if !editor.active {
+		wheel_events.clear();
+		return;
+	}
+	let Some(mut level) = level else { return };
+
+	let mut delta = 0.0;
+	for event in wheel_events.read() {
+		delta += match event.unit {
+			MouseScrollUnit::Line => event.y * SCRUB_STEP_LINE,
+			MouseScrollUnit::Pixel => event.y * 2.0,
+		};
+	}
+	if keyboard.just_pressed(KeyCode::PageUp) {
+		delta += SCRUB_STEP_PAGE;
+	}
+	if keyboard.just_pressed(KeyCode::PageDown) {
+		delta -= SCRUB_STEP_PAGE;
+	}
+
+	if keyboard.just_pressed(KeyCode::Home) {
+		level.distance = 0.0;
+	} else if delta != 0.0 {
+		let total = level.data.total_distance;
+		level.distance = (level.distance + delta).clamp(0.0, total);
+	}
+}
+
+/// Left click: select + drag. Right click: place palette item. Delete: remove selection.
+pub fn editor_mouse_input(
+	mut editor: ResMut<LevelEditorState>,
+	mouse: Res<ButtonInput<MouseButton>>,
+	keyboard: Res<ButtonInput<KeyCode>>,
+	window_query: Query<&Window, With<PrimaryWindow>>,
+	camera_query: Query<(&Camera, &GlobalTransform)>,
+	level: Option<Res<CurrentLevel>>,
+	source: Option<ResMut<LevelSource>>,
+) {
+	if !editor.active {
+		return;
+	}
+	let (Some(level), Some(mut source)) = (level, source) else { return };
+
+	let distance = level.distance;
+	let scroll_speed = scroll_speed_at(&level);
+
+	if keyboard.just_pressed(KeyCode::Delete) || keyboard.just_pressed(KeyCode::Backspace) {
+		if let Some(selection) = editor.selected.take() {
+			match selection {
+				EditorSelection::Enemy { wave, enemy } => {
+					if let Some(w) = source.data.enemy_waves.get_mut(wave) {
+						if enemy < w.enemies.len() {
+							w.enemies.remove(enemy);
+						}
+						if w.enemies.is_empty() && w.formations.is_empty() {
+							source.data.enemy_waves.remove(wave);
+						}
+					}
+				}
+				EditorSelection::Formation { wave, formation } => {
+					if let Some(w) = source.data.enemy_waves.get_mut(wave) {
+						if formation < w.formations.len() {
+							w.formations.remove(formation);
+						}
+						if w.enemies.is_empty() && w.formations.is_empty() {
+							source.data.enemy_waves.remove(wave);
+						}
+					}
+				}
+				EditorSelection::Doodad(idx) => {
+					if idx < source.data.doodads.len() {
+						source.data.doodads.remove(idx);
+					}
+				}
+				EditorSelection::Structure(idx) => {
+					if idx < source.data.structures.len() {
+						source.data.structures.remove(idx);
+					}
+				}
+			}
+			editor.dirty = true;
+		}
+	}
+
+	let Ok(window) = window_query.get_single() else { return };
+	let Some(cursor) = window.cursor_position() else { return };
+	let Some((camera, camera_transform)) = camera_query.iter().next() else { return };
+	let Ok(cursor_pos) = camera.viewport_to_world_2d(camera_transform, cursor) else { return };
+
+	if mouse.just_pressed(MouseButton::Left) {
+		editor.selected = pick_item(&editor, &source, distance, scroll_speed, cursor_pos);
+		editor.dragging = editor.selected.is_some();
+		editor.behavior_index = 0;
+	}
+	if mouse.just_released(MouseButton::Left) {
+		editor.dragging = false;
+	}
+
+	if editor.dragging && mouse.pressed(MouseButton::Left) {
+		match editor.selected {
+			Some(EditorSelection::Enemy { wave, enemy }) => {
+				if let Some(w) = source.data.enemy_waves.get_mut(wave) {
+					let spawn_distance = w.spawn_distance;
+					if let Some(e) = w.enemies.get_mut(enemy) {
+						set_enemy_screen_pos(spawn_distance, &mut e.position, distance, scroll_speed, cursor_pos);
+					}
+				}
+			}
+			Some(EditorSelection::Formation { wave, formation }) => {
+				if let Some(w) = source.data.enemy_waves.get_mut(wave) {
+					let spawn_distance = w.spawn_distance;
+					if let Some(f) = w.formations.get_mut(formation) {
+						set_enemy_screen_pos(spawn_distance, &mut f.position, distance, scroll_speed, cursor_pos);
+					}
+				}
+			}
+			Some(EditorSelection::Doodad(idx)) => {
+				if let Some(d) = source.data.doodads.get_mut(idx) {
+					set_doodad_screen_pos(d, distance, scroll_speed, cursor_pos);
+				}
+			}
+			Some(EditorSelection::Structure(idx)) => {
+				if let Some(s) = source.data.structures.get_mut(idx) {
+					set_structure_screen_pos(s, distance, cursor_pos);
+				}
+			}
+			None => {}
+		}
+		editor.dirty = true;
+	}
+
+	if mouse.just_pressed(MouseButton::Right) {
+		editor.selected = place_item(&editor, &mut source, distance, scroll_speed, cursor_pos);
+		editor.behavior_index = 0;
+		if editor.selected.is_some() {
+			editor.dirty = true;
+		}
+	}
+}
+
+fn pick_item(
+	editor: &LevelEditorState,
+	source: &LevelSource,
+	distance: f32,
+	scroll_speed: f32,
+	cursor: Vec2,
+) -> Option<EditorSelection> {
+	let mut best: Option<(f32, EditorSelection)> = None;
+	let mut consider = |pos: Vec2, selection: EditorSelection| {
+		let dist = pos.distance(cursor);
+		if dist <= PICK_RADIUS && best.is_none_or(|(d, _)| dist < d) {
+			best = Some((dist, selection));
+		}
+	};
+
+	match editor.layer {
+		EditorLayer::Enemies => {
+			for (wave_idx, wave) in source.data.enemy_waves.iter().enumerate() {
+				for (enemy_idx, enemy) in wave.enemies.iter().enumerate() {
+					let pos = Vec2::new(enemy.position[0], enemy_screen_y(wave.spawn_distance, enemy.position, distance, scroll_speed));
+					consider(pos, EditorSelection::Enemy { wave: wave_idx, enemy: enemy_idx });
+				}
+				for (formation_idx, formation) in wave.formations.iter().enumerate() {
+					let pos = Vec2::new(formation.position[0], enemy_screen_y(wave.spawn_distance, formation.position, distance, scroll_speed));
+					consider(pos, EditorSelection::Formation { wave: wave_idx, formation: formation_idx });
+				}
+			}
+		}
+		EditorLayer::Doodads => {
+			for (idx, doodad) in source.data.doodads.iter().enumerate() {
+				let pos = Vec2::new(doodad.position.x(), doodad_screen_y(doodad, distance, scroll_speed));
+				consider(pos, EditorSelection::Doodad(idx));
+			}
+		}
+		EditorLayer::Structures => {
+			for (idx, structure) in source.data.structures.iter().enumerate() {
+				let pos = Vec2::new(structure.position.x(), structure_screen_y(structure, distance));
+				consider(pos, EditorSelection::Structure(idx));
+			}
+		}
+	}
+
+	best.map(|(_, selection)| selection)
+}
+
+fn place_item(
+	editor: &LevelEditorState,
+	source: &mut LevelSource,
+	distance: f32,
+	scroll_speed: f32,
+	cursor: Vec2,
+) -> Option<EditorSelection> {
+	match editor.layer {
+		EditorLayer::Enemies => {
+			let enemy_type = ENEMY_TYPE_NAMES[editor.palette_index % ENEMY_TYPE_NAMES.len()];
+			// Spawning at the current distance puts the enemy's target Y right under the cursor
+			let enemy = EnemySpawn {
+				enemy_type: enemy_type.to_string(),
+				position: [cursor.x, cursor.y],
+				movement: None,
+				behaviors: EnemySpawn::get_default_behavior_for_type(enemy_type),
+				firing: None,
+				formation_id: None,
+				formation_role: None,
+				formation_offset: None,
+				formation_maneuvers: Vec::new(),
+				on_leader_death: None,
+				formation_follow_rate: None,
+				spawner: None,
+				min_difficulty: None,
+			};
+
+			let waves = &mut source.data.enemy_waves;
+			let wave_idx = match waves.iter().position(|w| (w.spawn_distance - distance).abs() < WAVE_MERGE_DISTANCE) {
+				Some(idx) => idx,
+				None => {
+					// Keep waves sorted by distance
+					let idx = waves.iter().position(|w| w.spawn_distance > distance).unwrap_or(waves.len());
+					waves.insert(idx, EnemyWave { spawn_distance: distance, enemies: Vec::new(), formations: Vec::new() });
+					idx
+				}
+			};
+			waves[wave_idx].enemies.push(enemy);
+			let enemy_idx = waves[wave_idx].enemies.len() - 1;
+			info!("✏ Placed {} at {:.0} (wave {})", enemy_type, distance, wave_idx);
+			Some(EditorSelection::Enemy { wave: wave_idx, enemy: enemy_idx })
+		}
+		EditorLayer::Doodads => {
+			let palette = doodad_palette(source);
+			let Some((sprite, layer)) = palette.get(editor.palette_index % palette.len().max(1)).cloned() else {
+				warn!("✏ No doodad sprites in this level to place");
+				return None;
+			};
+			let mut doodad = DoodadSpawn {
+				spawn_distance: distance,
+				sprite: sprite.clone(),
+				position: Position::XY([cursor.x, 800.0]),
+				velocity: [0.0, -100.0],
+				rotation: 0.0,
+				rotation_speed: 0.0,
+				layer,
+				size: None,
+				z_depth: None,
+				z_order: 0,
+			};
+			set_doodad_screen_pos(&mut doodad, distance, scroll_speed, cursor);
+			source.data.doodads.push(doodad);
+			info!("✏ Placed doodad {} at {:.0}", sprite, distance);
+			Some(EditorSelection::Doodad(source.data.doodads.len() - 1))
+		}
+		EditorLayer::Structures => {
+			let palette = structure_palette(source);
+			let Some((sprite, layer)) = palette.get(editor.palette_index % palette.len().max(1)).cloned() else {
+				warn!("✏ No structure sprites in this level to place");
+				return None;
+			};
+			let mut structure = Structure {
+				sprite: sprite.clone(),
+				layer,
+				position: Position::XY([cursor.x, 0.0]),
+				spawn_distance: distance,
+				velocity: None,
+				rotation: 0.0,
+				size: None,
+				z_depth: None,
+				z_order: 0,
+			};
+			set_structure_screen_pos(&mut structure, distance, cursor);
+			source.data.structures.push(structure);
+			info!("✏ Placed structure {} at {:.0}", sprite, distance);
+			Some(EditorSelection::Structure(source.data.structures.len() - 1))
+		}
+	}
+}
+
+/// Layer/palette/behavior editing and save
+pub fn editor_keyboard_input(
+	mut editor: ResMut<LevelEditorState>,
+	keyboard: Res<ButtonInput<KeyCode>>,
+	level: Option<ResMut<CurrentLevel>>,
+	source: Option<ResMut<LevelSource>>,
+) {
+	if !editor.active {
+		return;
+	}
+	let (Some(mut level), Some(mut source)) = (level, source) else { return };
+
+	let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+	if ctrl && keyboard.just_pressed(KeyCode::KeyS) {
+		save_level_source(&source);
+		resync_current_level(&mut level, &source);
+		editor.dirty = false;
+		return;
+	}
+
+	if keyboard.just_pressed(KeyCode::Tab) {
+		editor.layer = editor.layer.next();
+		editor.selected = None;
+		editor.palette_index = 0;
+	}
+	if keyboard.just_pressed(KeyCode::KeyC) {
+		editor.palette_index += 1;
+	}
+
+	// Behavior list editing for the selected enemy
+	let presets = behavior_presets();
+	if keyboard.just_pressed(KeyCode::KeyO) {
+		editor.preset_index = (editor.preset_index + 1) % presets.len();
+	}
+
+	let behavior_index = editor.behavior_index;
+	let preset = presets[editor.preset_index % presets.len()].1.clone();
+	let Some(enemy) = selected_enemy(&editor, &mut source) else { return };
+	let mut changed = false;
+
+	if enemy.behaviors.is_empty() && enemy.movement.is_some() {
+		// Editing converts legacy `movement:` into an explicit behavior list
+		enemy.behaviors = enemy.get_behaviors();
+		enemy.movement = None;
+	}
+
+	let mut new_index = behavior_index.min(enemy.behaviors.len().saturating_sub(1));
+	if keyboard.just_pressed(KeyCode::KeyJ) {
+		new_index = (new_index + 1).min(enemy.behaviors.len().saturating_sub(1));
+	}
+	if keyboard.just_pressed(KeyCode::KeyK) {
+		new_index = new_index.saturating_sub(1);
+	}
+	if keyboard.just_pressed(KeyCode::KeyB) {
+		enemy.behaviors.push(preset);
+		new_index = enemy.behaviors.len() - 1;
+		changed = true;
+	}
+	if keyboard.just_pressed(KeyCode::KeyX) && new_index < enemy.behaviors.len() {
+		enemy.behaviors.remove(new_index);
+		new_index = new_index.min(enemy.behaviors.len().saturating_sub(1));
+		changed = true;
+	}
+	if let Some(behavior) = enemy.behaviors.get_mut(new_index) {
+		let step = if keyboard.just_pressed(KeyCode::KeyI) {
+			0.5
+		} else if keyboard.just_pressed(KeyCode::KeyU) {
+			-0.5
+		} else {
+			0.0
+		};
+		if step != 0.0 {
+			// Dropping below 0.5s clears the duration (run until completion)
+			let duration = behavior.duration.unwrap_or(0.0) + step;
+			behavior.duration = if duration < 0.5 { None } else { Some(duration) };
+			changed = true;
+		}
+	}
+
+	editor.behavior_index = new_index;
+	if changed {
+		editor.dirty = true;
+	}
+}
+
+/// Draw authored content in the active layer (other layers dimmed) at the scrubbed distance
+pub fn render_level_editor(
+	editor: Res<LevelEditorState>,
+	mut gizmos: Gizmos,
+	level: Option<Res<CurrentLevel>>,
+	source: Option<Res<LevelSource>>,
+) {
+	if !editor.active {
+		return;
+	}
+	let (Some(level), Some(source)) = (level, source) else { return };
+
+	let distance = level.distance;
+	let scroll_speed = scroll_speed_at(&level);
+	let layer_alpha = |layer: EditorLayer| if editor.layer == layer { 1.0 } else { 0.2 };
+
+	for (wave_idx, wave) in source.data.enemy_waves.iter().enumerate() {
+		for (enemy_idx, enemy) in wave.enemies.iter().enumerate() {
+			let y = enemy_screen_y(wave.spawn_distance, enemy.position, distance, scroll_speed);
+			if y.abs() > VIEW_MARGIN_Y {
+				continue;
+			}
+			let pos = Vec2::new(enemy.position[0], y);
+			let selected = editor.selected == Some(EditorSelection::Enemy { wave: wave_idx, enemy: enemy_idx });
+			let color = if selected { SELECTED_COLOR } else { ENEMY_COLOR.with_alpha(layer_alpha(EditorLayer::Enemies)) };
+			gizmos.rect_2d(pos, Vec2::splat(24.0), color);
+			gizmos.cross_2d(pos, 6.0, color);
+		}
+		// Formation templates: leader marker plus the slot layout around it
+		for (formation_idx, formation) in wave.formations.iter().enumerate() {
+			let y = enemy_screen_y(wave.spawn_distance, formation.position, distance, scroll_speed);
+			if y.abs() > VIEW_MARGIN_Y {
+				continue;
+			}
+			let pos = Vec2::new(formation.position[0], y);
+			let selected = editor.selected == Some(EditorSelection::Formation { wave: wave_idx, formation: formation_idx });
+			let color = if selected { SELECTED_COLOR } else { FORMATION_COLOR.with_alpha(layer_alpha(EditorLayer::Enemies)) };
+			gizmos.rect_2d(pos, Vec2::splat(28.0), color);
+			for offset in formation.shape.slots(formation.count, formation.spacing).into_iter().skip(1) {
+				gizmos.circle_2d(pos + offset, 8.0, color);
+				gizmos.line_2d(pos, pos + offset, color.with_alpha(0.3 * color.alpha()));
+			}
+		}
+	}
+
+	for (idx, doodad) in source.data.doodads.iter().enumerate() {
+		let y = doodad_screen_y(doodad, distance, scroll_speed);
+		if y.abs() > VIEW_MARGIN_Y {
+			continue;
+		}
+		let pos = Vec2::new(doodad.position.x(), y);
+		let selected = editor.selected == Some(EditorSelection::Doodad(idx));
+		let color = if selected { SELECTED_COLOR } else { DOODAD_COLOR.with_alpha(layer_alpha(EditorLayer::Doodads)) };
+		gizmos.circle_2d(pos, 14.0, color);
+	}
+
+	for (idx, structure) in source.data.structures.iter().enumerate() {
+		let y = structure_screen_y(structure, distance);
+		if y.abs() > VIEW_MARGIN_Y {
+			continue;
+		}
+		let pos = Vec2::new(structure.position.x(), y);
+		let size = structure.size.map(|[w, h]| Vec2::new(w, h)).unwrap_or(Vec2::splat(40.0));
+		let selected = editor.selected == Some(EditorSelection::Structure(idx));
+		let color = if selected { SELECTED_COLOR } else { STRUCTURE_COLOR.with_alpha(layer_alpha(EditorLayer::Structures)) };
+		gizmos.rect_2d(pos, size, color);
+	}
+}
+
+pub fn update_level_editor_panel(
+	editor: Res<LevelEditorState>,
+	level: Option<Res<CurrentLevel>>,
+	source: Option<Res<LevelSource>>,
+	mut text_query: Query<&mut Text, With<LevelEditorPanelText>>,
+) {
+	if !editor.active {
+		return;
+	}
+	let (Some(level), Some(source)) = (level, source) else { return };
+	let Ok(mut text) = text_query.get_single_mut() else { return };
+
+	let palette_item = match editor.layer {
+		EditorLayer::Enemies => ENEMY_TYPE_NAMES[editor.palette_index % ENEMY_TYPE_NAMES.len()].to_string(),
+		EditorLayer::Doodads => {
+			let palette = doodad_palette(&source);
+			palette.get(editor.palette_index % palette.len().max(1))
+				.map(|(sprite, _)| sprite.clone())
+				.unwrap_or_else(|| "-".to_string())
+		}
+		EditorLayer::Structures => {
+			let palette = structure_palette(&source);
+			palette.get(editor.palette_index % palette.len().max(1))
+				.map(|(sprite, _)| sprite.clone())
+				.unwrap_or_else(|| "-".to_string())
+		}
+	};
+	let presets = behavior_presets();
+
+	let selection = match editor.selected {
+		Some(EditorSelection::Enemy { wave, enemy }) => source.data.enemy_waves.get(wave)
+			.and_then(|w| w.enemies.get(enemy).map(|e| (w, e)))
+			.map(|(w, e)| {
+				let behaviors = e.get_behaviors();
+				let mut lines = format!(
+					"Enemy: {} (wave {} @ {:.0})\nPos: [{:.0}, {:.0}]\nBehaviors (J/K select, B add, X remove, U/I duration):\n",
+					e.enemy_type, wave, w.spawn_distance, e.position[0], e.position[1],
+				);
+				for (i, behavior) in behaviors.iter().enumerate() {
+					let marker = if i == editor.behavior_index { ">" } else { " " };
+					let yaml = serde_yaml::to_string(behavior).unwrap_or_default();
+					lines.push_str(&format!("{} {}: {}\n", marker, i, yaml.trim_end().replace('\n', ", ")));
+				}
+				lines
+			}),
+		Some(EditorSelection::Formation { wave, formation }) => source.data.enemy_waves.get(wave)
+			.and_then(|w| w.formations.get(formation).map(|f| (w, f)))
+			.map(|(w, f)| format!(
+				"Formation: {} {:?} x{} (wave {} @ {:.0})\nPos: [{:.0}, {:.0}]\nBehaviors: edit in the level YAML\n",
+				f.enemy_type, f.shape, f.count, wave, w.spawn_distance, f.position[0], f.position[1],
+			)),
+		Some(EditorSelection::Doodad(idx)) => source.data.doodads.get(idx).map(|d| format!(
+			"Doodad: {}\nDistance: {:.0}\nPos: [{:.0}, {:.0}]\nLayer: {:?}\n",
+			d.sprite, d.spawn_distance, d.position.x(), d.position.y(), d.layer,
+		)),
+		Some(EditorSelection::Structure(idx)) => source.data.structures.get(idx).map(|s| format!(
+			"Structure: {}\nDistance: {:.0}\nPos: [{:.0}, {:.0}]\nLayer: {:?}\n",
+			s.sprite, s.spawn_distance, s.position.x(), s.position.y(), s.layer,
+		)),
+		None => None,
+	};
+
+	**text = format!(
+		"LEVEL EDITOR{}\n\
+		Distance: {:.0} / {:.0}  (wheel/PgUp/PgDn/Home)\n\
+		Layer: {:?}  (Tab)\n\
+		Palette: {}  (C, right-click to place)\n\
+		Preset: {}  (O)\n\
+		Save: Ctrl+S -> {}\n\n\
+		{}",
+		if editor.dirty { " *" } else { "" },
+		level.distance, level.data.total_distance,
+		editor.layer,
+		palette_item,
+		presets[editor.preset_index % presets.len()].0,
+		source.path.display(),
+		selection.unwrap_or_else(|| "Left-click to select/drag, Del to remove".to_string()),
+	);
+}
@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+use crate::resources::{Score, SelectedDifficulty, HighScores};
+use crate::systems::audio::PlaySfxEvent;
+use crate::systems::level::CurrentLevel;
+
+/// Tracks whether the end-of-level tally has been shown for this run
+#[derive(Resource, Default)]
+pub struct LevelTallyState {
+	pub shown: bool,
+}
+
+#[derive(Component)]
+pub struct LevelTallyPanel;
+
+/// Reset tally state when entering Playing - call this on OnEnter(Playing)
+pub fn reset_level_tally(mut commands: Commands) {
+	commands.insert_resource(LevelTallyState::default());
+}
+
+/// Show score, grazes and time once the level's total distance is reached
+pub fn show_level_tally(
+	mut commands: Commands,
+	level: Option<Res<CurrentLevel>>,
+	score: Res<Score>,
+	mut state: ResMut<LevelTallyState>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+	difficulty: Res<SelectedDifficulty>,
+	mut high_scores: ResMut<HighScores>,
+) {
+	let Some(level) = level else { return };
+	if state.shown || level.data.total_distance <= 0.0 || level.distance < level.data.total_distance {
+		return;
+	}
+	state.shown = true;
+
+	let minutes = (level.time_elapsed / 60.0) as u32;
+	let seconds = (level.time_elapsed % 60.0) as u32;
+	let difficulty = difficulty.difficulty;
+	let best_line = if high_scores.record(difficulty, score.points) {
+		"NEW HIGH SCORE!".to_string()
+	} else {
+		format!("Best: {}", high_scores.best(difficulty))
+	};
+	let tally = format!(
+		"LEVEL COMPLETE ({})\n\nScore: {}\n{}\nGrazes: {}\nTime: {:02}:{:02}",
+		difficulty.name(), score.points, best_line, score.grazes, minutes, seconds
+	);
+
+	commands.spawn((
+		Node {
+			position_type: PositionType::Absolute,
+			left: Val::Percent(50.0),
+			top: Val::Percent(35.0),
+			margin: UiRect::left(Val::Px(-150.0)),
+			width: Val::Px(300.0),
+			padding: UiRect::all(Val::Px(20.0)),
+			justify_content: JustifyContent::Center,
+			..default()
+		},
+		BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+		LevelTallyPanel,
+	)).with_children(|parent| {
+		parent.spawn((
+			Text::new(tally),
+			TextFont {
+				font_size: 20.0,
+				..default()
+			},
+			TextColor(Color::srgb(1.0, 1.0, 1.0)),
+			TextLayout::new_with_justify(JustifyText::Center),
+		));
+	});
+	sfx_events.send(PlaySfxEvent::simple("sounds/level_complete.ogg", 0.8, 200, 0.0));
+
+	info!("🏁 Level complete - score {}, grazes {}, time {:02}:{:02}", score.points, score.grazes, minutes, seconds);
+}
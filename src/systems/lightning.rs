@@ -1,12 +1,14 @@
 use bevy::prelude::*;
 use rand::Rng;
+use serde::Deserialize;
 use std::collections::{HashSet, HashMap};
 use crate::components::{
-	Weapon, WeaponType, Enemy, Health, Player,
-	EnemyHitEvent, ChargeMeter, Collider,
+	Weapon, WeaponType, Enemy, Health, Player, DamageType,
+	EnemyHitEvent, ChargeMeter, Collider, PlayerInput, PlayerDown,
 	LightningBolt, LightningImpact, LightningAoeEffect, PendingBabyWhip, LightningArc,
 	LightningGlitter, PendingSound,
 };
+use crate::systems::weapon_tuning::{WeaponTuning, check_levels};
 
 /// Tracks last time each enemy was hit by defensive field (for sound throttling)
 #[derive(Resource, Default)]
@@ -33,38 +35,15 @@ impl DefensiveFieldHitTracker {
 const VIEWPORT_HEIGHT: f32 = 1000.0;
 const VIEWPORT_TOP: f32 = VIEWPORT_HEIGHT / 2.0; // 500.0
 
-// Defensive field parameters per level (8, 9, 10)
-// (radius, base_damage_per_sec, visual_intensity)
-const FIELD_PARAMS: [(f32, f32, f32); 3] = [
-	(92.0,  15.0, 0.7),  // Level 8: +73% area vs original
-	(99.0,  25.0, 0.85), // Level 9: +21% area vs original
-	(115.0, 40.0, 1.0),  // Level 10: +9% area vs original
-];
-
-// Whip curve tuning
-const BOW_OFFSET_MIN: f32 = 25.0;  // Min perpendicular bow on "straight" section
-const BOW_OFFSET_MAX: f32 = 45.0;  // Max perpendicular bow
-const DRIFT_ANGLE_MIN: f32 = 8.0;  // Early drift phase (degrees)
-const DRIFT_ANGLE_MAX: f32 = 12.0;
-const DRIFT_DISTANCE: f32 = 60.0;  // Drift before commit
-const COMMIT_ANGLE_MIN: f32 = 28.0; // Firm commit phase (degrees)
-const COMMIT_ANGLE_MAX: f32 = 45.0;
-const BOOM_ZONE_BELOW_TOP: f32 = 75.0; // Target Y: this many units below viewport top
-const COMMIT_DISTANCE_MIN: f32 = 60.0;  // Minimum commit travel (visual consistency)
-const COMMIT_DISTANCE_MAX: f32 = 180.0; // Maximum commit travel
-
-// Glitter effect tuning (burning micro-sparks)
-const GLITTER_COUNT_MIN: u32 = 270;
-const GLITTER_COUNT_MAX: u32 = 430;
-const GLITTER_SPEED_MIN: f32 = 8.0;    // Drift outward
-const GLITTER_SPEED_MAX: f32 = 35.0;
-const GLITTER_LIFETIME_MIN: f32 = 0.25;
-const GLITTER_LIFETIME_MAX: f32 = 0.55;
-const GLITTER_SIZE_MIN: f32 = 0.4;     // Tiny spark arm length
-const GLITTER_SIZE_MAX: f32 = 1.2;
+/// First level where lightning switches to charge-and-release with a defensive field
+pub const ENHANCED_MODE_LEVEL: u8 = 8;
+/// Charge tiers 0, 0.4, 0.8, 1.2, 1.6, 2.0
+pub const CHARGE_TIER_COUNT: usize = 6;
+/// Widest fan `generate_whip_angles` knows
+const MAX_WHIPS: u8 = 8;
 
 /// Parameters for a charge tier at a specific level
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Deserialize)]
 pub struct TierParams {
 	pub whips: u8,
 	pub max_chains: u8,
@@ -75,48 +54,132 @@ pub struct TierParams {
 	pub baby_chance: f32,
 }
 
-/// Tier parameter tables for levels 8, 9, 10
-/// Index by (level - 8, tier_index) where tier_index = (tier / 0.4) as usize
-const TIER_PARAMS: [[[TierParams; 6]; 3]; 1] = [[
-	// Level 8: tiers 0, 0.4, 0.8, 1.2, 1.6, 2.0
-	[
-		TierParams { whips: 2, max_chains: 3, range: 280.0, aoe_radius: 112.0, damage_mult: 0.75, damage_falloff: 0.10, baby_chance: 0.15 },
-		TierParams { whips: 3, max_chains: 4, range: 300.0, aoe_radius: 140.0, damage_mult: 1.0, damage_falloff: 0.05, baby_chance: 0.30 },
-		TierParams { whips: 3, max_chains: 5, range: 325.0, aoe_radius: 161.0, damage_mult: 1.15, damage_falloff: 0.035, baby_chance: 0.38 },
-		TierParams { whips: 3, max_chains: 6, range: 350.0, aoe_radius: 182.0, damage_mult: 1.375, damage_falloff: 0.02, baby_chance: 0.45 },
-		TierParams { whips: 4, max_chains: 7, range: 385.0, aoe_radius: 210.0, damage_mult: 1.55, damage_falloff: 0.01, baby_chance: 0.55 },
-		TierParams { whips: 5, max_chains: 9, range: 420.0, aoe_radius: 245.0, damage_mult: 1.8, damage_falloff: 0.0, baby_chance: 0.65 },
-	],
-	// Level 9: tiers 0, 0.4, 0.8, 1.2, 1.6, 2.0
-	[
-		TierParams { whips: 3, max_chains: 4, range: 300.0, aoe_radius: 126.0, damage_mult: 0.80, damage_falloff: 0.08, baby_chance: 0.20 },
-		TierParams { whips: 4, max_chains: 5, range: 350.0, aoe_radius: 168.0, damage_mult: 1.0, damage_falloff: 0.03, baby_chance: 0.40 },
-		TierParams { whips: 4, max_chains: 6, range: 400.0, aoe_radius: 196.0, damage_mult: 1.25, damage_falloff: 0.015, baby_chance: 0.50 },
-		TierParams { whips: 5, max_chains: 8, range: 450.0, aoe_radius: 224.0, damage_mult: 1.556, damage_falloff: 0.0, baby_chance: 0.60 },
-		TierParams { whips: 5, max_chains: 10, range: 500.0, aoe_radius: 260.0, damage_mult: 1.75, damage_falloff: 0.0, baby_chance: 0.70 },
-		TierParams { whips: 6, max_chains: 12, range: 560.0, aoe_radius: 300.0, damage_mult: 2.0, damage_falloff: 0.0, baby_chance: 0.80 },
-	],
-	// Level 10: tiers 0, 0.4, 0.8, 1.2, 1.6, 2.0
-	[
-		TierParams { whips: 3, max_chains: 4, range: 300.0, aoe_radius: 140.0, damage_mult: 0.85, damage_falloff: 0.05, baby_chance: 0.30 },
-		TierParams { whips: 5, max_chains: 6, range: 400.0, aoe_radius: 196.0, damage_mult: 1.0, damage_falloff: 0.01, baby_chance: 0.50 },
-		TierParams { whips: 5, max_chains: 8, range: 475.0, aoe_radius: 252.0, damage_mult: 1.35, damage_falloff: 0.005, baby_chance: 0.60 },
-		TierParams { whips: 6, max_chains: 12, range: 550.0, aoe_radius: 308.0, damage_mult: 1.8, damage_falloff: 0.0, baby_chance: 0.70 },
-		TierParams { whips: 7, max_chains: 14, range: 600.0, aoe_radius: 360.0, damage_mult: 2.1, damage_falloff: 0.0, baby_chance: 0.80 },
-		TierParams { whips: 8, max_chains: 18, range: 680.0, aoe_radius: 420.0, damage_mult: 2.5, damage_falloff: 0.0, baby_chance: 0.90 },
-	],
-]];
-
-/// Get parameters for a given level and charge tier
-/// For levels < 8, returns level 8 tier 0.4 params (basic shot)
-fn get_tier_params(level: u8, charge_tier: f32) -> TierParams {
-	// Clamp level to 8-10 range
-	let level_idx = ((level.max(8).min(10) - 8) as usize).min(2);
-
-	// Convert tier to index: 0=0, 0.4=1, 0.8=2, 1.2=3, 1.6=4, 2.0=5
-	let tier_idx = ((charge_tier / 0.4).round() as usize).min(5);
-
-	TIER_PARAMS[0][level_idx][tier_idx]
+/// A level below enhanced mode: one fixed set of whip parameters
+#[derive(Clone, Deserialize)]
+pub struct LightningLevel {
+	pub level: u8,
+	#[serde(flatten)]
+	pub params: TierParams,
+}
+
+/// Defensive field around the ship in enhanced mode
+#[derive(Clone, Copy, Deserialize)]
+pub struct FieldParams {
+	pub radius: f32,
+	pub damage_per_sec: f32,
+	pub visual_intensity: f32,
+}
+
+/// An enhanced-mode level: one row per charge tier plus the defensive field
+#[derive(Clone, Deserialize)]
+pub struct EnhancedLevel {
+	pub level: u8,
+	pub field: FieldParams,
+	pub tiers: Vec<TierParams>,
+}
+
+/// Whip curve tuning (angles in degrees)
+#[derive(Clone, Deserialize)]
+pub struct WhipTuning {
+	pub bow_offset_min: f32,
+	pub bow_offset_max: f32,
+	pub drift_angle_min: f32,
+	pub drift_angle_max: f32,
+	pub drift_distance: f32,
+	pub commit_angle_min: f32,
+	pub commit_angle_max: f32,
+	pub boom_zone_below_top: f32,
+	pub commit_distance_min: f32,
+	pub commit_distance_max: f32,
+}
+
+/// Glitter effect tuning (burning micro-sparks)
+#[derive(Clone, Deserialize)]
+pub struct GlitterTuning {
+	pub count_min: u32,
+	pub count_max: u32,
+	pub speed_min: f32,
+	pub speed_max: f32,
+	pub lifetime_min: f32,
+	pub lifetime_max: f32,
+	pub size_min: f32,
+	pub size_max: f32,
+}
+
+/// The `lightning` section of the weapon tuning file
+#[derive(Clone, Deserialize)]
+pub struct LightningTuning {
+	pub levels: Vec<LightningLevel>,
+	pub enhanced: Vec<EnhancedLevel>,
+	pub whip: WhipTuning,
+	pub glitter: GlitterTuning,
+}
+
+impl LightningTuning {
+	/// Enhanced-mode row for `level`, or None below enhanced mode
+	pub fn enhanced(&self, level: u8) -> Option<&EnhancedLevel> {
+		if level < ENHANCED_MODE_LEVEL {
+			return None;
+		}
+		self.enhanced.iter().rev().find(|row| row.level <= level)
+	}
+
+	/// Parameters for a level and charge tier (the tier only matters in enhanced mode)
+	pub fn tier_params(&self, level: u8, charge_tier: f32) -> TierParams {
+		if let Some(row) = self.enhanced(level) {
+			// Convert tier to index: 0=0, 0.4=1, 0.8=2, 1.2=3, 1.6=4, 2.0=5
+			let tier_idx = ((charge_tier / 0.4).round() as usize).min(CHARGE_TIER_COUNT - 1);
+			return row.tiers[tier_idx];
+		}
+		self.levels.iter().rev()
+			.find(|row| row.level <= level)
+			.unwrap_or(&self.levels[0])
+			.params
+	}
+
+	/// Check level coverage up to `max_level` and that every random range is non-empty
+	pub fn validate(&self, max_level: u8, errors: &mut Vec<String>) {
+		let levels: Vec<u8> = self.levels.iter().map(|row| row.level).collect();
+		check_levels("lightning.levels", &levels, 1..=(ENHANCED_MODE_LEVEL - 1).min(max_level), errors);
+		let enhanced: Vec<u8> = self.enhanced.iter().map(|row| row.level).collect();
+		check_levels("lightning.enhanced", &enhanced, ENHANCED_MODE_LEVEL..=max_level, errors);
+
+		for row in &self.enhanced {
+			if row.tiers.len() != CHARGE_TIER_COUNT {
+				errors.push(format!("lightning.enhanced level {}: needs {} tiers, found {}",
+					row.level, CHARGE_TIER_COUNT, row.tiers.len()));
+			}
+		}
+		let all_params = self.levels.iter().map(|row| (row.level, &row.params))
+			.chain(self.enhanced.iter().flat_map(|row| row.tiers.iter().map(move |tier| (row.level, tier))));
+		for (level, params) in all_params {
+			if params.whips == 0 || params.whips > MAX_WHIPS {
+				errors.push(format!("lightning level {}: whips must be 1-{}", level, MAX_WHIPS));
+			}
+		}
+
+		let whip = &self.whip;
+		let glitter = &self.glitter;
+		let ranges = [
+			("whip.bow_offset", whip.bow_offset_min, whip.bow_offset_max),
+			("whip.drift_angle", whip.drift_angle_min, whip.drift_angle_max),
+			("whip.commit_angle", whip.commit_angle_min, whip.commit_angle_max),
+			("glitter.speed", glitter.speed_min, glitter.speed_max),
+			("glitter.lifetime", glitter.lifetime_min, glitter.lifetime_max),
+			("glitter.size", glitter.size_min, glitter.size_max),
+		];
+		for (name, min, max) in ranges {
+			if min >= max {
+				errors.push(format!("lightning.{}: min must be below max", name));
+			}
+		}
+		if whip.commit_distance_min > whip.commit_distance_max {
+			errors.push("lightning.whip.commit_distance: min must not exceed max".to_string());
+		}
+		if glitter.count_min > glitter.count_max {
+			errors.push("lightning.glitter.count: min must not exceed max".to_string());
+		}
+	}
 }
 
 struct RaycastResult {
@@ -139,6 +202,7 @@ fn perform_hitscan_ray(
 	start_pos: Vec2,
 	direction: Vec2,
 	enemies: &Query<(Entity, &Transform, &Health, &Collider), With<Enemy>>,
+	whip: &WhipTuning,
 ) -> RaycastResult {
 	let mut rng = rand::thread_rng();
 
@@ -147,26 +211,26 @@ fn perform_hitscan_ray(
 	let perpendicular = Vec2::new(-direction.y, direction.x) * curve_side;
 
 	// Target Y for boom zone (consistent position near top)
-	let target_boom_y = VIEWPORT_TOP - BOOM_ZONE_BELOW_TOP;
+	let target_boom_y = VIEWPORT_TOP - whip.boom_zone_below_top;
 
 	// Calculate straight distance to get most of the way there
 	// Leave room for drift + commit to finish the journey
 	let distance_to_target = target_boom_y - start_pos.y;
-	let straight_distance = (distance_to_target - DRIFT_DISTANCE - 50.0).max(100.0);
+	let straight_distance = (distance_to_target - whip.drift_distance - 50.0).max(100.0);
 
 	// === PHASE 1: Bowed "straight" section ===
 	// Endpoints are the same, but path bows out via bezier control point
-	let bow_offset = rng.gen_range(BOW_OFFSET_MIN..BOW_OFFSET_MAX);
+	let bow_offset = rng.gen_range(whip.bow_offset_min..whip.bow_offset_max);
 	let straight_end = start_pos + direction * straight_distance;
 	let bow_control = start_pos.lerp(straight_end, 0.5) + perpendicular * bow_offset;
 
 	// === PHASE 2: Drift section (gentle angle, same direction as bow) ===
-	let drift_angle = rng.gen_range(DRIFT_ANGLE_MIN..DRIFT_ANGLE_MAX).to_radians() * curve_side;
+	let drift_angle = rng.gen_range(whip.drift_angle_min..whip.drift_angle_max).to_radians() * curve_side;
 	let drift_dir = rotate_direction(direction, drift_angle);
-	let drift_end = straight_end + drift_dir * DRIFT_DISTANCE;
+	let drift_end = straight_end + drift_dir * whip.drift_distance;
 
 	// === PHASE 3: Commit section - calculate distance to land at target Y ===
-	let commit_angle = rng.gen_range(COMMIT_ANGLE_MIN..COMMIT_ANGLE_MAX).to_radians() * curve_side;
+	let commit_angle = rng.gen_range(whip.commit_angle_min..whip.commit_angle_max).to_radians() * curve_side;
 	let commit_dir = rotate_direction(direction, commit_angle);
 
 	// How much Y do we need to travel to reach target?
@@ -176,10 +240,10 @@ fn perform_hitscan_ray(
 	// commit_dir.y is how much Y we gain per unit of travel
 	let commit_distance = if commit_dir.y.abs() > 0.01 && y_remaining > 0.0 {
 		// Solve: drift_end.y + commit_dir.y * distance = target_boom_y
-		(y_remaining / commit_dir.y).clamp(COMMIT_DISTANCE_MIN, COMMIT_DISTANCE_MAX)
+		(y_remaining / commit_dir.y).clamp(whip.commit_distance_min, whip.commit_distance_max)
 	} else {
 		// Fallback if commit is mostly horizontal or we're past target
-		COMMIT_DISTANCE_MIN
+		whip.commit_distance_min
 	};
 
 	let ray_end_visual = drift_end + commit_dir * commit_distance;
@@ -255,24 +319,6 @@ fn perform_hitscan_ray(
 	}
 }
 
-fn calculate_whip_directions(level: u8, charge_tier: f32) -> Vec<f32> {
-	// Returns angle offsets in radians
-	// For levels 8+, use tier params
-	if level >= 8 {
-		let params = get_tier_params(level, charge_tier);
-		let num_whips = params.whips as usize;
-		generate_whip_angles(num_whips)
-	} else {
-		// Levels 1-7: old hardcoded behavior
-		match level {
-			1..=3 => vec![0.0],
-			4..=6 => vec![-0.087, 0.087], // ±5°
-			7 => vec![-0.174, 0.0, 0.174], // -10°, 0°, +10°
-			_ => vec![0.0],
-		}
-	}
-}
-
 /// Generate evenly-spaced whip angles for a given count
 fn generate_whip_angles(num_whips: usize) -> Vec<f32> {
 	match num_whips {
@@ -340,9 +386,9 @@ fn spawn_impact_buzz(commands: &mut Commands, position: Vec2) {
 	});
 }
 
-fn spawn_glitter_burst(commands: &mut Commands, center: Vec2, radius: f32, incoming_direction: Option<Vec2>) {
+fn spawn_glitter_burst(commands: &mut Commands, center: Vec2, radius: f32, incoming_direction: Option<Vec2>, glitter: &GlitterTuning) {
 	let mut rng = rand::thread_rng();
-	let count = rng.gen_range(GLITTER_COUNT_MIN..=GLITTER_COUNT_MAX);
+	let count = rng.gen_range(glitter.count_min..=glitter.count_max);
 
 	// Oval stretch: longer along incoming direction, narrower perpendicular
 	let forward = incoming_direction.unwrap_or(Vec2::Y).normalize();
@@ -353,7 +399,7 @@ fn spawn_glitter_burst(commands: &mut Commands, center: Vec2, radius: f32, incom
 	for _ in 0..count {
 		// Very slow random drift (firefly wander)
 		let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-		let speed = rng.gen_range(GLITTER_SPEED_MIN..GLITTER_SPEED_MAX);
+		let speed = rng.gen_range(glitter.speed_min..glitter.speed_max);
 		let velocity = Vec2::new(angle.cos(), angle.sin()) * speed;
 
 		// Spread in OVAL matching AoE shape
@@ -371,8 +417,8 @@ fn spawn_glitter_burst(commands: &mut Commands, center: Vec2, radius: f32, incom
 		let oval_offset = (forward * forward_component + perpendicular * perp_component) * spawn_dist;
 		let position = center + oval_offset;
 
-		let lifetime = rng.gen_range(GLITTER_LIFETIME_MIN..GLITTER_LIFETIME_MAX);
-		let size = rng.gen_range(GLITTER_SIZE_MIN..GLITTER_SIZE_MAX);
+		let lifetime = rng.gen_range(glitter.lifetime_min..glitter.lifetime_max);
+		let size = rng.gen_range(glitter.size_min..glitter.size_max);
 		let color_temp = rng.gen_range(0.0..1.0);
 
 		// Random phase and speed for sine wave twinkle
@@ -401,6 +447,7 @@ fn execute_aoe_explosion(
 	commands: &mut Commands,
 	incoming_direction: Option<Vec2>,
 	is_final_zone: bool,
+	glitter: &GlitterTuning,
 ) {
 	for (entity, transform, _, _) in enemies.iter() {
 		let enemy_pos = transform.translation.truncate();
@@ -421,6 +468,10 @@ fn execute_aoe_explosion(
 				enemy: entity,
 				damage: base_damage * 0.5,
 				hit_sound: Some("sounds/lightning/lightning_wave_light.ogg"),
+				dot: None,
+				knockback: Vec2::ZERO,
+				damage_type: DamageType::Electric,
+				source: None,
 			});
 		}
 	}
@@ -437,7 +488,7 @@ fn execute_aoe_explosion(
 
 	// Spawn glitter burst for final zones (incandescent firework dots)
 	if is_final_zone {
-		spawn_glitter_burst(commands, center, radius, incoming_direction);
+		spawn_glitter_burst(commands, center, radius, incoming_direction, glitter);
 	}
 }
 
@@ -502,6 +553,7 @@ fn execute_chain_sequence(
 	aoe_radius: f32,
 	baby_spawn_chance: f32,
 	recursion_depth: u8,
+	glitter: &GlitterTuning,
 ) {
 	let mut current_pos = start_pos;
 
@@ -546,6 +598,10 @@ fn execute_chain_sequence(
 				enemy: target_entity,
 				damage: chain_damage,
 				hit_sound: Some("sounds/lightning/lightning_wave_light.ogg"),
+				dot: None,
+				knockback: Vec2::ZERO,
+				damage_type: DamageType::Electric,
+				source: None,
 			});
 
 			already_hit.insert(target_entity);
@@ -581,7 +637,7 @@ fn execute_chain_sequence(
 	}
 
 	// Small AoE at final chain position (intermediate, not the main finale)
-	execute_aoe_explosion(current_pos, aoe_radius * 0.3, damage * 0.3, &enemies, hit_events, commands, None, false);
+	execute_aoe_explosion(current_pos, aoe_radius * 0.3, damage * 0.3, enemies, hit_events, commands, None, false, glitter);
 }
 
 pub fn fire_lightning_weapon(
@@ -592,49 +648,28 @@ pub fn fire_lightning_weapon(
 	charge_tier: f32, // 0.0, 0.4, 0.8, 1.2, 1.6, or 2.0
 	enemies: &Query<(Entity, &Transform, &Health, &Collider), With<Enemy>>,
 	hit_events: &mut EventWriter<EnemyHitEvent>,
+	tuning: &LightningTuning,
 ) {
 	let level = weapon.level;
 
-	// Get parameters based on level and tier
-	let (max_chains, chain_range, aoe_radius, damage_mult, damage_falloff, baby_spawn_chance): (u8, f32, f32, f32, f32, f32) = if level >= 8 {
-		let params = get_tier_params(level, charge_tier);
-		(
-			params.max_chains,
-			params.range,
-			params.aoe_radius,
-			params.damage_mult,
-			params.damage_falloff,
-			params.baby_chance,
-		)
-	} else {
-		// Levels 1-7: old hardcoded behavior
-		let (mc, cr, ar, dm, df): (u8, f32, f32, f32, f32) = match level {
-			1 => (0, 0.0, 56.0, 1.0, 0.0),
-			2 => (1, 150.0, 70.0, 1.0, 0.2),
-			3 => (2, 180.0, 84.0, 1.0, 0.2),
-			4 => (2, 200.0, 98.0, 1.0, 0.15),
-			5 => (3, 250.0, 112.0, 1.0, 0.10),
-			6 => (4, 280.0, 126.0, 1.0, 0.08),
-			7 => (4, 300.0, 140.0, 1.0, 0.05),
-			_ => (4, 300.0, 140.0, 1.0, 0.05),
-		};
-		let baby: f32 = match level {
-			0..=4 => 0.0,
-			5 => 0.15,
-			6 => 0.25,
-			7 => 0.30,
-			_ => 0.0,
-		};
-		(mc, cr, ar, dm, df, baby)
-	};
+	let params = tuning.tier_params(level, charge_tier);
+	let TierParams {
+		max_chains,
+		range: chain_range,
+		aoe_radius,
+		damage_mult,
+		damage_falloff,
+		baby_chance: baby_spawn_chance,
+		..
+	} = params;
 
 	let actual_damage = damage * damage_mult;
 
 	// Visual feedback for higher tiers
 	let bolt_intensity = if charge_tier >= 1.2 { 1.0 } else { 0.9 };
 
-	// Calculate whip directions (uses tier-based count for levels 8+)
-	let whip_angles = calculate_whip_directions(level, charge_tier);
+	// Whip count comes from the level (and tier, in enhanced mode)
+	let whip_angles = generate_whip_angles(params.whips as usize);
 	let num_whips = whip_angles.len();
 	let base_direction = Vec2::Y; // Fire upward
 
@@ -643,7 +678,7 @@ pub fn fire_lightning_weapon(
 		let whip_direction = rotate_direction(base_direction, *angle_offset);
 
 		// Perform hitscan
-		let ray_result = perform_hitscan_ray(spawn_pos.truncate(), whip_direction, enemies);
+		let ray_result = perform_hitscan_ray(spawn_pos.truncate(), whip_direction, enemies, &tuning.whip);
 
 		// Spawn main bolt visual
 		commands.spawn(LightningBolt {
@@ -668,6 +703,10 @@ pub fn fire_lightning_weapon(
 				enemy: hit_entity,
 				damage: actual_damage,
 				hit_sound: Some("sounds/lightning/lightning_wave_light.ogg"),
+				dot: None,
+				knockback: Vec2::ZERO,
+				damage_type: DamageType::Electric,
+				source: None,
 			});
 
 			// Small discharge at initial hit point (intermediate)
@@ -680,6 +719,7 @@ pub fn fire_lightning_weapon(
 				commands,
 				None,
 				false,
+				&tuning.glitter,
 			);
 
 			let mut already_hit = HashSet::new();
@@ -711,6 +751,7 @@ pub fn fire_lightning_weapon(
 				aoe_radius,
 				baby_spawn_chance,
 				0, // recursion_depth
+				&tuning.glitter,
 			);
 
 			// Main finale discharge at bolt's visual end
@@ -725,6 +766,7 @@ pub fn fire_lightning_weapon(
 				commands,
 				Some(final_direction),
 				true, // Final zone - splitting bolt visual
+				&tuning.glitter,
 			);
 		} else {
 			// No hit - full finale AoE at end of bolt
@@ -738,6 +780,7 @@ pub fn fire_lightning_weapon(
 				commands,
 				Some(final_direction),
 				true, // Final zone - splitting bolt visual
+				&tuning.glitter,
 			);
 		}
 	}
@@ -787,70 +830,69 @@ pub fn fire_lightning_weapon(
 }
 
 /// Quantize charge to floor tier: 0.4-0.79→0.4, 0.8-1.19→0.8, 1.2-1.59→1.2, etc.
-fn quantize_charge_tier(charge: f32) -> f32 {
+pub fn quantize_charge_tier(charge: f32) -> f32 {
 	((charge / 0.4).floor() * 0.4).clamp(0.0, 2.0)
 }
 
+/// Each ship has its own meter, driven by its own fire button
 pub fn update_charge_meter(
-	mut charge_meter: ResMut<ChargeMeter>,
-	keyboard: Res<ButtonInput<KeyCode>>,
-	weapon_query: Query<&Weapon, With<Player>>,
+	mut player_query: Query<(&mut ChargeMeter, &Weapon, &PlayerInput), With<Player>>,
 	time: Res<Time>,
 ) {
-	let Ok(weapon) = weapon_query.get_single() else { return };
-
-	// Reset frame flags
-	charge_meter.charge_consumed_this_frame = false;
-	charge_meter.pending_fire_tier = None;
-
-	// Only active for LightningChain at level 8+
-	if weapon.weapon_type != WeaponType::LightningChain || weapon.level < 8 {
-		charge_meter.current = charge_meter.max;
-		charge_meter.is_charging = false;
-		charge_meter.charge_building = 0.0;
-		return;
-	}
-
-	let dt = time.delta_secs();
-	let just_pressed = keyboard.just_pressed(KeyCode::Space);
-	let pressed = keyboard.pressed(KeyCode::Space);
-	let just_released = keyboard.just_released(KeyCode::Space);
-
-	// Start building charge on press
-	if just_pressed {
-		charge_meter.is_charging = true;
-		charge_meter.charge_building = 0.4; // Base charge for tap
-	}
+	for (mut charge_meter, weapon, input) in player_query.iter_mut() {
+		// Reset frame flags
+		charge_meter.charge_consumed_this_frame = false;
+		charge_meter.pending_fire_tier = None;
+
+		// Only active for LightningChain in enhanced mode
+		if weapon.weapon_type != WeaponType::LightningChain || weapon.level < ENHANCED_MODE_LEVEL {
+			charge_meter.current = charge_meter.max;
+			charge_meter.is_charging = false;
+			charge_meter.charge_building = 0.0;
+			continue;
+		}
 
-	// Continue building while held (1.0 charge/sec, max 2.0 per shot)
-	if pressed && charge_meter.is_charging {
-		charge_meter.charge_building = (charge_meter.charge_building + dt).min(2.0);
-	}
+		let dt = time.delta_secs();
+		let just_pressed = input.fire_just_pressed;
+		let pressed = input.fire;
+		let just_released = input.fire_just_released;
 
-	// Fire on release
-	if just_released && charge_meter.is_charging {
-		// Can only fire with charge we actually have
-		let affordable = charge_meter.charge_building.min(charge_meter.current);
-		let tier = quantize_charge_tier(affordable);
+		// Start building charge on press
+		if just_pressed {
+			charge_meter.is_charging = true;
+			charge_meter.charge_building = 0.4; // Base charge for tap
+		}
 
-		// Consume charge (if tier is 0, we still "fire" but consume nothing)
-		if tier > 0.0 {
-			charge_meter.current = (charge_meter.current - tier).max(0.0);
-			charge_meter.charge_consumed_this_frame = true;
+		// Continue building while held (1.0 charge/sec, max 2.0 per shot)
+		if pressed && charge_meter.is_charging {
+			charge_meter.charge_building = (charge_meter.charge_building + dt).min(2.0);
 		}
 
-		// Signal to fire with this tier (tier 0 = empty shot)
-		charge_meter.pending_fire_tier = Some(tier);
+		// Fire on release
+		if just_released && charge_meter.is_charging {
+			// Can only fire with charge we actually have
+			let affordable = charge_meter.charge_building.min(charge_meter.current);
+			let tier = quantize_charge_tier(affordable);
 
-		// Reset building state
-		charge_meter.is_charging = false;
-		charge_meter.charge_building = 0.0;
-	}
+			// Consume charge (if tier is 0, we still "fire" but consume nothing)
+			if tier > 0.0 {
+				charge_meter.current = (charge_meter.current - tier).max(0.0);
+				charge_meter.charge_consumed_this_frame = true;
+			}
 
-	// Passive recharge when not charging (1.0/sec)
-	if !charge_meter.is_charging {
-		charge_meter.current = (charge_meter.current + charge_meter.recharge_rate * dt)
-			.min(charge_meter.max);
+			// Signal to fire with this tier (tier 0 = empty shot)
+			charge_meter.pending_fire_tier = Some(tier);
+
+			// Reset building state
+			charge_meter.is_charging = false;
+			charge_meter.charge_building = 0.0;
+		}
+
+		// Passive recharge when not charging (1.0/sec)
+		if !charge_meter.is_charging {
+			charge_meter.current = (charge_meter.current + charge_meter.recharge_rate * dt)
+				.min(charge_meter.max);
+		}
 	}
 }
 
@@ -1188,6 +1230,7 @@ pub fn spawn_pending_baby_whips(
 	mut pending: Query<(Entity, &mut PendingBabyWhip)>,
 	enemies: Query<(Entity, &Transform, &Health, &Collider), With<Enemy>>,
 	mut hit_events: EventWriter<EnemyHitEvent>,
+	tuning: Res<WeaponTuning>,
 ) {
 	for (entity, mut pending_whip) in pending.iter_mut() {
 		pending_whip.delay_timer.tick(time.delta());
@@ -1198,6 +1241,7 @@ pub fn spawn_pending_baby_whips(
 				pending_whip.spawn_from,
 				pending_whip.direction,
 				&enemies,
+				&tuning.lightning.whip,
 			);
 
 			// Spawn bolt visual
@@ -1256,6 +1300,10 @@ pub fn spawn_pending_baby_whips(
 					enemy: hit_entity,
 					damage: pending_whip.parent_damage,
 					hit_sound: Some("sounds/lightning/lightning_wave_light.ogg"),
+					dot: None,
+					knockback: Vec2::ZERO,
+					damage_type: DamageType::Electric,
+					source: None,
 				});
 
 				// Baby discharge at hit point (25% damage)
@@ -1268,6 +1316,7 @@ pub fn spawn_pending_baby_whips(
 					&mut commands,
 					None,
 					false,
+					&tuning.lightning.glitter,
 				);
 
 				let mut already_hit = HashSet::new();
@@ -1290,6 +1339,7 @@ pub fn spawn_pending_baby_whips(
 					baby_aoe_radius,
 					pending_whip.baby_spawn_chance,
 					pending_whip.recursion_depth,
+					&tuning.lightning.glitter,
 				);
 
 				// Baby discharge at bolt visual end (15% damage)
@@ -1302,6 +1352,7 @@ pub fn spawn_pending_baby_whips(
 					&mut commands,
 					None,
 					false,
+					&tuning.lightning.glitter,
 				);
 			} else {
 				// No hit - discharge at end of baby bolt
@@ -1314,6 +1365,7 @@ pub fn spawn_pending_baby_whips(
 					&mut commands,
 					None,
 					false,
+					&tuning.lightning.glitter,
 				);
 			}
 
@@ -1525,96 +1577,106 @@ pub fn render_lightning_glitter(
 	}
 }
 
-/// Deal damage to enemies inside the defensive field
+/// Deal damage to enemies inside each ship's defensive field
+#[allow(clippy::type_complexity)]
 pub fn update_defensive_field_damage(
 	time: Res<Time>,
-	charge_meter: Res<ChargeMeter>,
-	player_query: Query<(&Transform, &Weapon), With<Player>>,
+	player_query: Query<(&Transform, &Weapon, &ChargeMeter), (With<Player>, Without<PlayerDown>)>,
 	enemies: Query<(Entity, &Transform, &Collider), With<Enemy>>,
 	mut hit_events: EventWriter<EnemyHitEvent>,
 	mut hit_tracker: ResMut<DefensiveFieldHitTracker>,
+	tuning: Res<WeaponTuning>,
 ) {
-	let Ok((transform, weapon)) = player_query.get_single() else { return };
-
-	// Only active for Lightning weapon level 8+
-	if weapon.weapon_type != WeaponType::LightningChain || weapon.level < 8 {
-		return;
-	}
-
-	let player_pos = transform.translation.truncate();
 	let dt = time.delta_secs();
 	let current_time = time.elapsed_secs_f64();
 
-	// Get level-based parameters (level 8 = index 0, level 9 = index 1, level 10 = index 2)
-	let level_idx = ((weapon.level - 8) as usize).min(2);
-	let (field_radius, base_dps, _) = FIELD_PARAMS[level_idx];
-
-	// Field strength: inversely proportional to charge (empty = 1.0, full = 0.25)
-	// Cap at 2.0 for calculation - having 4.0 stock shouldn't weaken field beyond 2.0 level
-	let charge_ratio = charge_meter.current.min(2.0) / 2.0;
-	let field_strength = 1.0 - (charge_ratio * 0.75);
-
-	// Actual damage per second scales with field strength
-	let actual_dps = base_dps * field_strength;
-	let damage_this_frame = actual_dps * dt;
-
-	// Track valid enemies for cleanup
-	let mut valid_enemies = HashSet::new();
-
-	// Check enemies in range
-	let hull_radius = 35.0;
-	for (entity, enemy_transform, collider) in enemies.iter() {
-		valid_enemies.insert(entity);
-		let enemy_pos = enemy_transform.translation.truncate();
-		let dist = player_pos.distance(enemy_pos);
-
-		// Enemy is inside field if within radius (accounting for enemy collider)
-		if dist < field_radius + collider.radius && dist > hull_radius {
-			// Damage falls off toward the edge
-			let normalized_dist = (dist - hull_radius) / (field_radius - hull_radius);
-			let falloff = 1.0 - normalized_dist.clamp(0.0, 1.0) * 0.5; // 100% at hull, 50% at edge
-
-			// Check if enough time has passed to play sound again (0.15 second cooldown per enemy)
-			let hit_sound = if hit_tracker.should_play_sound(entity, current_time, 0.15) {
-				Some("sounds/enemy_hit.ogg")
-			} else {
-				None
-			};
+	for (transform, weapon, charge_meter) in player_query.iter() {
+		// Only active for Lightning weapon in enhanced mode
+		if weapon.weapon_type != WeaponType::LightningChain {
+			continue;
+		}
+		let Some(enhanced) = tuning.lightning.enhanced(weapon.level) else { continue };
+
+		let player_pos = transform.translation.truncate();
+		let field_radius = enhanced.field.radius;
+		let base_dps = enhanced.field.damage_per_sec;
+
+		// Field strength: inversely proportional to charge (empty = 1.0, full = 0.25)
+		// Cap at 2.0 for calculation - having 4.0 stock shouldn't weaken field beyond 2.0 level
+		let charge_ratio = charge_meter.current.min(2.0) / 2.0;
+		let field_strength = 1.0 - (charge_ratio * 0.75);
+
+		// Actual damage per second scales with field strength
+		let actual_dps = base_dps * field_strength;
+		let damage_this_frame = actual_dps * dt;
+
+		// Check enemies in range
+		let hull_radius = 35.0;
+		for (entity, enemy_transform, collider) in enemies.iter() {
+			let enemy_pos = enemy_transform.translation.truncate();
+			let dist = player_pos.distance(enemy_pos);
+
+			// Enemy is inside field if within radius (accounting for enemy collider)
+			if dist < field_radius + collider.radius && dist > hull_radius {
+				// Damage falls off toward the edge
+				let normalized_dist = (dist - hull_radius) / (field_radius - hull_radius);
+				let falloff = 1.0 - normalized_dist.clamp(0.0, 1.0) * 0.5; // 100% at hull, 50% at edge
+
+				// Check if enough time has passed to play sound again (0.15 second cooldown per enemy)
+				let hit_sound = if hit_tracker.should_play_sound(entity, current_time, 0.15) {
+					Some("sounds/enemy_hit.ogg")
+				} else {
+					None
+				};
 
-			hit_events.send(EnemyHitEvent {
-				enemy: entity,
-				damage: damage_this_frame * falloff,
-				hit_sound,
-			});
+				hit_events.send(EnemyHitEvent {
+					enemy: entity,
+					damage: damage_this_frame * falloff,
+					hit_sound,
+					dot: None,
+					knockback: Vec2::ZERO,
+					damage_type: DamageType::Electric,
+					source: None,
+				});
+			}
 		}
 	}
 
 	// Cleanup dead enemies from tracker
+	let valid_enemies: HashSet<Entity> = enemies.iter().map(|(entity, _, _)| entity).collect();
 	hit_tracker.cleanup(&valid_enemies);
 }
 
 /// Render defensive electric field around player - stronger when charge is depleted
 /// Plasma globe style: glowing tendrils with ethereal shell
+#[allow(clippy::type_complexity)]
 pub fn render_defensive_field(
 	mut gizmos: Gizmos,
 	time: Res<Time>,
-	charge_meter: Res<ChargeMeter>,
-	player_query: Query<(&Transform, &Weapon), With<Player>>,
+	player_query: Query<(&Transform, &Weapon, &ChargeMeter), (With<Player>, Without<PlayerDown>)>,
+	tuning: Res<WeaponTuning>,
 ) {
-	let Ok((transform, weapon)) = player_query.get_single() else { return };
-
-	// Only show for Lightning weapon level 8+
-	if weapon.weapon_type != WeaponType::LightningChain || weapon.level < 8 {
-		return;
+	for (transform, weapon, charge_meter) in player_query.iter() {
+		// Only show for Lightning weapon in enhanced mode
+		if weapon.weapon_type != WeaponType::LightningChain {
+			continue;
+		}
+		let Some(enhanced) = tuning.lightning.enhanced(weapon.level) else { continue };
+		draw_defensive_field(&mut gizmos, time.elapsed_secs(), transform.translation.truncate(), &enhanced.field, charge_meter);
 	}
+}
 
-	let player_pos = transform.translation.truncate();
+fn draw_defensive_field(
+	gizmos: &mut Gizmos,
+	time_secs: f32,
+	player_pos: Vec2,
+	field: &FieldParams,
+	charge_meter: &ChargeMeter,
+) {
 	let mut rng = rand::thread_rng();
-	let time_secs = time.elapsed_secs();
 
-	// Get level-based parameters
-	let level_idx = ((weapon.level - 8) as usize).min(2);
-	let (globe_radius, _, visual_intensity) = FIELD_PARAMS[level_idx];
+	let globe_radius = field.radius;
+	let visual_intensity = field.visual_intensity;
 
 	// Field strength: inversely proportional to charge (empty = 1.0, full = 0.25)
 	// Cap at 2.0 for calculation - having 4.0 stock shouldn't weaken field beyond 2.0 level
@@ -0,0 +1,241 @@
+use bevy::prelude::*;
+use crate::components::{Player, PlayerDown, PlayerDefenses, PlayerHitEvent, DamageSink, Invincible};
+use crate::resources::{Score, CoopEnabled, SelectedDifficulty, HighScores};
+use crate::systems::audio::PlaySfxEvent;
+use crate::systems::hanabi_fx::{ParticleEffects, ExplosionSize, spawn_explosion_effect};
+use crate::systems::level::CurrentLevel;
+use crate::systems::timeline::TimelineJumpEvent;
+use crate::systems::player::spawn_x_for_slot;
+use crate::systems::world::player_bounds;
+
+const STARTING_LIVES: u8 = 3;
+const MAX_LIVES: u8 = 9;
+const RESPAWN_INVINCIBILITY_SECS: f32 = 3.0;
+/// Score thresholds for extra lives; after the last one, one more every `EXTRA_LIFE_INTERVAL`
+const EXTRA_LIFE_THRESHOLDS: [u64; 3] = [5_000, 15_000, 30_000];
+const EXTRA_LIFE_INTERVAL: u64 = 20_000;
+
+/// Lives stock (shared in co-op), extra-life progress and the current checkpoint (a phase start distance)
+#[derive(Resource)]
+pub struct Lives {
+	pub stock: u8,
+	/// How many extra lives have been awarded (index into the threshold schedule)
+	pub extra_lives_awarded: usize,
+	pub continues_used: u32,
+	pub checkpoint: f32,
+	/// Every ship is down; gameplay stops until the player continues
+	pub game_over: bool,
+}
+
+impl Default for Lives {
+	fn default() -> Self {
+		Self {
+			stock: STARTING_LIVES,
+			extra_lives_awarded: 0,
+			continues_used: 0,
+			checkpoint: 0.0,
+			game_over: false,
+		}
+	}
+}
+
+impl Lives {
+	/// Score needed for the next extra life
+	pub fn next_extra_life_at(&self) -> u64 {
+		match EXTRA_LIFE_THRESHOLDS.get(self.extra_lives_awarded) {
+			Some(&threshold) => threshold,
+			None => {
+				let beyond = (self.extra_lives_awarded - EXTRA_LIFE_THRESHOLDS.len()) as u64 + 1;
+				EXTRA_LIFE_THRESHOLDS[EXTRA_LIFE_THRESHOLDS.len() - 1] + beyond * EXTRA_LIFE_INTERVAL
+			}
+		}
+	}
+}
+
+#[derive(Component)]
+pub struct GameOverPanel;
+
+/// Run condition: gameplay systems only run while the game isn't over
+pub fn game_running(lives: Res<Lives>) -> bool {
+	!lives.game_over
+}
+
+/// Reset lives when a run starts - call this on OnEnter(Playing)
+pub fn reset_lives(mut commands: Commands) {
+	commands.insert_resource(Lives::default());
+}
+
+/// Advance the checkpoint whenever a new phase starts
+pub fn update_checkpoint(
+	level: Option<Res<CurrentLevel>>,
+	mut lives: ResMut<Lives>,
+) {
+	let Some(level) = level else { return };
+	let Some(phase) = level.get_current_phase() else { return };
+	if phase.start_distance > lives.checkpoint {
+		lives.checkpoint = phase.start_distance;
+		info!("🚩 Checkpoint: {} ({:.0} GU)", phase.name, phase.start_distance);
+	}
+}
+
+pub fn award_extra_lives(
+	score: Res<Score>,
+	mut lives: ResMut<Lives>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+	while score.points >= lives.next_extra_life_at() {
+		lives.extra_lives_awarded += 1;
+		lives.stock = (lives.stock + 1).min(MAX_LIVES);
+		sfx_events.send(PlaySfxEvent::simple("sounds/extra_life.ogg", 0.8, 150, 0.0));
+		info!("❤️ Extra life! ({} lives)", lives.stock);
+	}
+}
+
+/// On a fatal hit: spend a life to respawn the ship, or put it out of the game.
+/// Solo deaths restart from the checkpoint; in co-op the ship respawns in place and
+/// it's only game over once every ship is down.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn handle_player_death(
+	mut commands: Commands,
+	mut hit_events: EventReader<PlayerHitEvent>,
+	mut lives: ResMut<Lives>,
+	coop: Res<CoopEnabled>,
+	mut player_query: Query<(Entity, &mut Transform, &mut Visibility, &mut PlayerDefenses, &Player, Option<&PlayerDown>)>,
+	mut jump_events: EventWriter<TimelineJumpEvent>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+	particle_effects: Option<Res<ParticleEffects>>,
+	score: Res<Score>,
+	difficulty: Res<SelectedDifficulty>,
+	mut high_scores: ResMut<HighScores>,
+) {
+	// Several sources can report a fatal hit on the same ship in one frame; one death each is enough
+	let mut dead: Vec<Entity> = Vec::new();
+	for event in hit_events.read() {
+		if event.sink == DamageSink::Dead && !dead.contains(&event.player) {
+			dead.push(event.player);
+		}
+	}
+	if dead.is_empty() || lives.game_over {
+		return;
+	}
+
+	let mut knocked_out: Vec<Entity> = Vec::new();
+	for &entity in &dead {
+		let Ok((_, mut transform, mut visibility, mut defenses, player, down)) = player_query.get_mut(entity) else { continue };
+		if down.is_some() {
+			continue;
+		}
+
+		if let Some(effects) = particle_effects.as_ref() {
+			spawn_explosion_effect(&mut commands, effects, transform.translation, ExplosionSize::Large);
+		}
+		sfx_events.send(PlaySfxEvent::simple("sounds/explosion_large.ogg", 0.9, 200, 0.0));
+
+		if lives.stock == 0 {
+			*visibility = Visibility::Hidden;
+			// PlayerDown keeps the wreck out of collisions while it's out of the game
+			commands.entity(entity).insert(PlayerDown);
+			knocked_out.push(entity);
+			info!("💀 Player {} is out of lives", player.slot + 1);
+			continue;
+		}
+
+		lives.stock -= 1;
+		*defenses = PlayerDefenses::from_profile(&player.ship_type.get_stats().defenses);
+		transform.translation.x = spawn_x_for_slot(player.slot, coop.0);
+		transform.translation.y = player_bounds::SPAWN_Y;
+		commands.entity(entity).insert(Invincible::new(RESPAWN_INVINCIBILITY_SECS));
+		if coop.0 {
+			info!("💥 Player {} lost a life - {} left", player.slot + 1, lives.stock);
+		} else {
+			jump_events.send(TimelineJumpEvent { distance: lives.checkpoint });
+			info!("💥 Life lost - {} left, back to checkpoint {:.0} GU", lives.stock, lives.checkpoint);
+		}
+	}
+
+	// Commands haven't applied yet, so count this frame's knockouts by hand
+	let all_down = player_query.iter()
+		.all(|(entity, _, _, _, _, down)| down.is_some() || knocked_out.contains(&entity));
+	if all_down {
+		lives.game_over = true;
+		// Continuing resets the score, so the run ends here as far as high scores go
+		let new_best = high_scores.record(difficulty.difficulty, score.points);
+		spawn_game_over_panel(&mut commands, difficulty.difficulty.name(), high_scores.best(difficulty.difficulty), new_best);
+		sfx_events.send(PlaySfxEvent::simple("sounds/game_over.ogg", 0.8, 200, 0.0));
+		info!("💀 Game over (continues used: {})", lives.continues_used);
+	}
+}
+
+fn spawn_game_over_panel(commands: &mut Commands, difficulty: &str, best: u64, new_best: bool) {
+	let best_line = if new_best {
+		format!("NEW {} HIGH SCORE: {}", difficulty.to_uppercase(), best)
+	} else {
+		format!("{} best: {}", difficulty, best)
+	};
+	commands.spawn((
+		Node {
+			position_type: PositionType::Absolute,
+			left: Val::Percent(50.0),
+			top: Val::Percent(35.0),
+			margin: UiRect::left(Val::Px(-170.0)),
+			width: Val::Px(340.0),
+			padding: UiRect::all(Val::Px(20.0)),
+			justify_content: JustifyContent::Center,
+			..default()
+		},
+		BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+		GameOverPanel,
+	)).with_children(|parent| {
+		parent.spawn((
+			Text::new(format!("GAME OVER\n\n{}\n\nPress Enter to continue\n(score resets)", best_line)),
+			TextFont {
+				font_size: 20.0,
+				..default()
+			},
+			TextColor(Color::srgb(1.0, 0.4, 0.4)),
+			TextLayout::new_with_justify(JustifyText::Center),
+		));
+	});
+}
+
+/// Enter on the game over screen: reset lives and score, bring every ship back at the checkpoint
+#[allow(clippy::too_many_arguments)]
+pub fn handle_continue(
+	mut commands: Commands,
+	keyboard: Res<ButtonInput<KeyCode>>,
+	mut lives: ResMut<Lives>,
+	mut score: ResMut<Score>,
+	coop: Res<CoopEnabled>,
+	mut player_query: Query<(Entity, &mut Transform, &mut Visibility, &mut PlayerDefenses, &Player)>,
+	panel_query: Query<Entity, With<GameOverPanel>>,
+	mut jump_events: EventWriter<TimelineJumpEvent>,
+) {
+	if !lives.game_over || !keyboard.just_pressed(KeyCode::Enter) {
+		return;
+	}
+
+	for panel in panel_query.iter() {
+		commands.entity(panel).despawn_recursive();
+	}
+
+	let checkpoint = lives.checkpoint;
+	let continues_used = lives.continues_used + 1;
+	*lives = Lives {
+		checkpoint,
+		continues_used,
+		..default()
+	};
+	*score = Score::default();
+
+	for (entity, mut transform, mut visibility, mut defenses, player) in player_query.iter_mut() {
+		*visibility = Visibility::Inherited;
+		*defenses = PlayerDefenses::from_profile(&player.ship_type.get_stats().defenses);
+		transform.translation.x = spawn_x_for_slot(player.slot, coop.0);
+		transform.translation.y = player_bounds::SPAWN_Y;
+		commands.entity(entity)
+			.remove::<PlayerDown>()
+			.insert(Invincible::new(RESPAWN_INVINCIBILITY_SECS));
+	}
+	jump_events.send(TimelineJumpEvent { distance: checkpoint });
+	info!("🔁 Continue #{} from checkpoint {:.0} GU", continues_used, checkpoint);
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
-use crate::components::{ShipType, WeaponType};
-use crate::resources::{SelectedShip, SelectedWeapon, GameState};
+use crate::components::{ShipType, WeaponType, Difficulty};
+use crate::resources::{SelectedShip, SelectedWeapon, SelectedDifficulty, HighScores, CoopEnabled, GameState};
+use crate::systems::weapon_tuning::WeaponTuning;
 
 #[derive(Component)]
 pub struct MenuUI;
@@ -15,6 +16,23 @@ pub struct WeaponButton {
 	pub weapon_type: WeaponType,
 }
 
+/// Sub-weapon choice; `None` leaves the sub slot empty
+#[derive(Component)]
+pub struct SubWeaponButton {
+	pub weapon_type: Option<WeaponType>,
+}
+
+#[derive(Component)]
+pub struct DifficultyButton {
+	pub difficulty: Difficulty,
+}
+
+/// One or two players (local co-op)
+#[derive(Component)]
+pub struct CoopButton {
+	pub enabled: bool,
+}
+
 #[derive(Component)]
 pub struct StartGameButton;
 
@@ -22,10 +40,21 @@ const BUTTON_WIDTH: f32 = 180.0;
 const BUTTON_HEIGHT: f32 = 240.0;
 const BUTTON_SPACING: f32 = 15.0;
 const SHIP_PREVIEW_SIZE: f32 = 120.0;
+const SUB_BUTTON_WIDTH: f32 = 130.0;
+const SUB_BUTTON_HEIGHT: f32 = 44.0;
+
+const BUTTON_BG: Color = Color::srgba(0.15, 0.15, 0.2, 0.9);
+const BUTTON_BORDER: Color = Color::srgb(0.3, 0.3, 0.4);
+const SELECTED_BG: Color = Color::srgba(0.3, 0.4, 0.6, 0.9);
+const SELECTED_BORDER: Color = Color::srgb(0.5, 0.7, 1.0);
 
 pub fn setup_ship_selection_menu(
 	mut commands: Commands,
 	asset_server: Res<AssetServer>,
+	tuning: Res<WeaponTuning>,
+	selected_difficulty: Res<SelectedDifficulty>,
+	high_scores: Res<HighScores>,
+	coop: Res<CoopEnabled>,
 ) {
 	let font = asset_server.load("fonts/Orbitron-Variable.ttf");
 	// Root scrollable container
@@ -109,10 +138,10 @@ pub fn setup_ship_selection_menu(
 					..default()
 				})
 				.with_children(|row| {
-					spawn_weapon_button(row, WeaponType::BasicBlaster, &asset_server, &font);
-					spawn_weapon_button(row, WeaponType::PlasmaCannon, &asset_server, &font);
-					spawn_weapon_button(row, WeaponType::WaveGun, &asset_server, &font);
-					spawn_weapon_button(row, WeaponType::SpreadShot, &asset_server, &font);
+					spawn_weapon_button(row, WeaponType::BasicBlaster, &asset_server, &font, &tuning);
+					spawn_weapon_button(row, WeaponType::PlasmaCannon, &asset_server, &font, &tuning);
+					spawn_weapon_button(row, WeaponType::WaveGun, &asset_server, &font, &tuning);
+					spawn_weapon_button(row, WeaponType::SpreadShot, &asset_server, &font, &tuning);
 				});
 
 			// Weapon selection container (bottom row)
@@ -127,10 +156,111 @@ pub fn setup_ship_selection_menu(
 					..default()
 				})
 				.with_children(|row| {
-					spawn_weapon_button(row, WeaponType::MissilePods, &asset_server, &font);
-					spawn_weapon_button(row, WeaponType::LaserArray, &asset_server, &font);
-					spawn_weapon_button(row, WeaponType::OrbitalDefense, &asset_server, &font);
-					spawn_weapon_button(row, WeaponType::LightningChain, &asset_server, &font);
+					spawn_weapon_button(row, WeaponType::MissilePods, &asset_server, &font, &tuning);
+					spawn_weapon_button(row, WeaponType::LaserArray, &asset_server, &font, &tuning);
+					spawn_weapon_button(row, WeaponType::OrbitalDefense, &asset_server, &font, &tuning);
+					spawn_weapon_button(row, WeaponType::LightningChain, &asset_server, &font, &tuning);
+				});
+
+			// Sub-weapon selection title
+			parent.spawn((
+				Text::new("SELECT SUB-WEAPON"),
+				TextFont {
+					font: font.clone(),
+					font_size: 22.0,
+					..default()
+				},
+				TextColor(Color::srgb(0.9, 0.9, 0.9)),
+				Node {
+					margin: UiRect::new(Val::Px(10.0), Val::Px(10.0), Val::Px(20.0), Val::Px(10.0)),
+					..default()
+				},
+			));
+
+			// Sub-weapon row (compact buttons, "NONE" selected by default)
+			parent
+				.spawn(Node {
+					width: Val::Auto,
+					height: Val::Auto,
+					justify_content: JustifyContent::Center,
+					align_items: AlignItems::Center,
+					column_gap: Val::Px(BUTTON_SPACING),
+					..default()
+				})
+				.with_children(|row| {
+					spawn_sub_weapon_button(row, None, &font);
+					for weapon_type in [
+						WeaponType::PlasmaCannon,
+						WeaponType::WaveGun,
+						WeaponType::SpreadShot,
+						WeaponType::MissilePods,
+						WeaponType::LaserArray,
+						WeaponType::OrbitalDefense,
+					] {
+						spawn_sub_weapon_button(row, Some(weapon_type), &font);
+					}
+				});
+
+			// Difficulty selection title
+			parent.spawn((
+				Text::new("SELECT DIFFICULTY"),
+				TextFont {
+					font: font.clone(),
+					font_size: 22.0,
+					..default()
+				},
+				TextColor(Color::srgb(0.9, 0.9, 0.9)),
+				Node {
+					margin: UiRect::new(Val::Px(10.0), Val::Px(10.0), Val::Px(20.0), Val::Px(10.0)),
+					..default()
+				},
+			));
+
+			// Difficulty row, each with its best score
+			parent
+				.spawn(Node {
+					width: Val::Auto,
+					height: Val::Auto,
+					justify_content: JustifyContent::Center,
+					align_items: AlignItems::Center,
+					column_gap: Val::Px(BUTTON_SPACING),
+					..default()
+				})
+				.with_children(|row| {
+					for difficulty in Difficulty::all() {
+						let selected = difficulty == selected_difficulty.difficulty;
+						spawn_difficulty_button(row, difficulty, selected, high_scores.best(difficulty), &font);
+					}
+				});
+
+			// Players title
+			parent.spawn((
+				Text::new("PLAYERS"),
+				TextFont {
+					font: font.clone(),
+					font_size: 22.0,
+					..default()
+				},
+				TextColor(Color::srgb(0.9, 0.9, 0.9)),
+				Node {
+					margin: UiRect::new(Val::Px(10.0), Val::Px(10.0), Val::Px(20.0), Val::Px(10.0)),
+					..default()
+				},
+			));
+
+			// Solo / co-op row
+			parent
+				.spawn(Node {
+					width: Val::Auto,
+					height: Val::Auto,
+					justify_content: JustifyContent::Center,
+					align_items: AlignItems::Center,
+					column_gap: Val::Px(BUTTON_SPACING),
+					..default()
+				})
+				.with_children(|row| {
+					spawn_coop_button(row, false, !coop.0, &font);
+					spawn_coop_button(row, true, coop.0, &font);
 				});
 
 			// Start button (initially hidden until selection made)
@@ -220,9 +350,11 @@ fn spawn_ship_button(
 			));
 
 			// Stats display at bottom
+			let defenses = &stats.defenses;
 			let stats_text = format!(
-				"Speed: {:.0}\nFire: {:.2}s\nSize: {:.0}\n{}",
-				stats.speed, stats.fire_cooldown, stats.size, stats.description
+				"Speed: {:.0}\nShield: {:.0}/{:.0}  Armor: {:.0}\nAbility: {}\n{}",
+				stats.speed, defenses.shield2_max, defenses.shield1_max, defenses.armor_max,
+				stats.ability.name(), stats.description
 			);
 			button.spawn((
 				Text::new(stats_text),
@@ -246,19 +378,27 @@ fn spawn_weapon_button(
 	weapon_type: WeaponType,
 	asset_server: &Res<AssetServer>,
 	font: &Handle<Font>,
+	tuning: &WeaponTuning,
 ) {
-	let config = weapon_type.config();
+	let config = tuning.weapon(weapon_type);
 	let weapon_name = format!("{:?}", weapon_type);
-	let (description, levels, icon_path) = match weapon_type {
-		WeaponType::BasicBlaster => ("Unupgradeable default weapon", "None", "sprites/weapons/basic_blaster.png"),
-		WeaponType::PlasmaCannon => ("Focused power, high damage", "1-6", "sprites/weapons/plasma_cannon.png"),
-		WeaponType::WaveGun => ("Sine wave pattern, wide coverage", "1-6", "sprites/weapons/wave_gun.png"),
-		WeaponType::SpreadShot => ("Multi-directional fan, crowd control", "1-6", "sprites/weapons/spread_shot.png"),
-		WeaponType::MissilePods => ("Homing missiles, auto-targeting", "1-6", "sprites/weapons/missile_pods.png"),
-		WeaponType::LaserArray => ("Rapid-fire beams, continuous DPS", "1-6", "sprites/weapons/laser_array.png"),
-		WeaponType::OrbitalDefense => ("Rotating orbs, offense + defense", "1-6", "sprites/weapons/orbital_defense.png"),
-		WeaponType::LightningChain => ("Chain lightning whips, recursive chaos", "1-10", "sprites/weapons/lightning_chain.png"),
+	let (description, icon_path) = match weapon_type {
+		WeaponType::BasicBlaster => ("Unupgradeable default weapon", "sprites/weapons/basic_blaster.png"),
+		WeaponType::PlasmaCannon => ("Focused power, high damage", "sprites/weapons/plasma_cannon.png"),
+		WeaponType::WaveGun => ("Sine wave pattern, wide coverage", "sprites/weapons/wave_gun.png"),
+		WeaponType::SpreadShot => ("Multi-directional fan, crowd control", "sprites/weapons/spread_shot.png"),
+		WeaponType::MissilePods => ("Homing missiles, auto-targeting", "sprites/weapons/missile_pods.png"),
+		WeaponType::LaserArray => ("Rapid-fire beams, continuous DPS", "sprites/weapons/laser_array.png"),
+		WeaponType::OrbitalDefense => ("Rotating orbs, offense + defense", "sprites/weapons/orbital_defense.png"),
+		WeaponType::LightningChain => ("Chain lightning whips, recursive chaos", "sprites/weapons/lightning_chain.png"),
+	};
+	let levels = match config.max_level() {
+		0 => "None".to_string(),
+		max_level => format!("1-{}", max_level),
 	};
+	let evolutions = weapon_type.evolutions().map_or(String::new(), |[a, b]| {
+		format!("\nEvolves: {} / {}", a.name(), b.name())
+	});
 
 	parent
 		.spawn((
@@ -309,8 +449,8 @@ fn spawn_weapon_button(
 
 			// Stats display at bottom
 			let stats_text = format!(
-				"DMG: {:.0} | SPD: {:.0}\nLevels: {}\n{}",
-				config.base_damage, config.projectile_speed, levels, description
+				"DMG: {:.0} | SPD: {:.0}\nLevels: {}{}\n{}",
+				config.levels[0].damage, config.projectile_speed, levels, evolutions, description
 			);
 			button.spawn((
 				Text::new(stats_text),
@@ -329,6 +469,178 @@ fn spawn_weapon_button(
 		});
 }
 
+fn spawn_sub_weapon_button(
+	parent: &mut ChildBuilder,
+	weapon_type: Option<WeaponType>,
+	font: &Handle<Font>,
+) {
+	let label = weapon_type.map_or("NONE".to_string(), |w| format!("{:?}", w).to_uppercase());
+	let selected = weapon_type.is_none();
+
+	parent
+		.spawn((
+			Node {
+				width: Val::Px(SUB_BUTTON_WIDTH),
+				height: Val::Px(SUB_BUTTON_HEIGHT),
+				justify_content: JustifyContent::Center,
+				align_items: AlignItems::Center,
+				border: UiRect::all(Val::Px(2.0)),
+				..default()
+			},
+			BackgroundColor(if selected { SELECTED_BG } else { BUTTON_BG }),
+			BorderColor(if selected { SELECTED_BORDER } else { BUTTON_BORDER }),
+			Button,
+			SubWeaponButton { weapon_type },
+		))
+		.with_children(|button| {
+			button.spawn((
+				Text::new(label),
+				TextFont {
+					font: font.clone(),
+					font_size: 12.0,
+					..default()
+				},
+				TextColor(Color::srgb(0.9, 0.9, 0.9)),
+			));
+		});
+}
+
+fn spawn_difficulty_button(
+	parent: &mut ChildBuilder,
+	difficulty: Difficulty,
+	selected: bool,
+	best: u64,
+	font: &Handle<Font>,
+) {
+	parent
+		.spawn((
+			Node {
+				width: Val::Px(SUB_BUTTON_WIDTH),
+				height: Val::Px(SUB_BUTTON_HEIGHT),
+				flex_direction: FlexDirection::Column,
+				justify_content: JustifyContent::Center,
+				align_items: AlignItems::Center,
+				border: UiRect::all(Val::Px(2.0)),
+				..default()
+			},
+			BackgroundColor(if selected { SELECTED_BG } else { BUTTON_BG }),
+			BorderColor(if selected { SELECTED_BORDER } else { BUTTON_BORDER }),
+			Button,
+			DifficultyButton { difficulty },
+		))
+		.with_children(|button| {
+			button.spawn((
+				Text::new(difficulty.name().to_uppercase()),
+				TextFont {
+					font: font.clone(),
+					font_size: 12.0,
+					..default()
+				},
+				TextColor(Color::srgb(0.9, 0.9, 0.9)),
+			));
+			button.spawn((
+				Text::new(format!("BEST {}", best)),
+				TextFont {
+					font: font.clone(),
+					font_size: 10.0,
+					..default()
+				},
+				TextColor(Color::srgb(0.7, 0.7, 0.8)),
+			));
+		});
+}
+
+fn spawn_coop_button(
+	parent: &mut ChildBuilder,
+	enabled: bool,
+	selected: bool,
+	font: &Handle<Font>,
+) {
+	parent
+		.spawn((
+			Node {
+				width: Val::Px(SUB_BUTTON_WIDTH),
+				height: Val::Px(SUB_BUTTON_HEIGHT),
+				justify_content: JustifyContent::Center,
+				align_items: AlignItems::Center,
+				border: UiRect::all(Val::Px(2.0)),
+				..default()
+			},
+			BackgroundColor(if selected { SELECTED_BG } else { BUTTON_BG }),
+			BorderColor(if selected { SELECTED_BORDER } else { BUTTON_BORDER }),
+			Button,
+			CoopButton { enabled },
+		))
+		.with_children(|button| {
+			button.spawn((
+				Text::new(if enabled { "2P CO-OP" } else { "1 PLAYER" }),
+				TextFont {
+					font: font.clone(),
+					font_size: 12.0,
+					..default()
+				},
+				TextColor(Color::srgb(0.9, 0.9, 0.9)),
+			));
+		});
+}
+
+pub fn handle_coop_selection(
+	interaction_query: Query<(&Interaction, &CoopButton), Changed<Interaction>>,
+	mut all_buttons: Query<(&CoopButton, &mut BackgroundColor, &mut BorderColor)>,
+	mut coop: ResMut<CoopEnabled>,
+) {
+	for (interaction, coop_button) in &interaction_query {
+		if *interaction == Interaction::Pressed {
+			coop.0 = coop_button.enabled;
+			info!("Co-op: {}", if coop.0 { "on" } else { "off" });
+
+			for (button, mut bg, mut border) in all_buttons.iter_mut() {
+				let selected = button.enabled == coop_button.enabled;
+				*bg = BackgroundColor(if selected { SELECTED_BG } else { BUTTON_BG });
+				*border = BorderColor(if selected { SELECTED_BORDER } else { BUTTON_BORDER });
+			}
+		}
+	}
+}
+
+pub fn handle_difficulty_selection(
+	interaction_query: Query<(&Interaction, &DifficultyButton), Changed<Interaction>>,
+	mut all_buttons: Query<(&DifficultyButton, &mut BackgroundColor, &mut BorderColor)>,
+	mut selected_difficulty: ResMut<SelectedDifficulty>,
+) {
+	for (interaction, difficulty_button) in &interaction_query {
+		if *interaction == Interaction::Pressed {
+			selected_difficulty.difficulty = difficulty_button.difficulty;
+			info!("Selected difficulty: {}", difficulty_button.difficulty.name());
+
+			for (button, mut bg, mut border) in all_buttons.iter_mut() {
+				let selected = button.difficulty == difficulty_button.difficulty;
+				*bg = BackgroundColor(if selected { SELECTED_BG } else { BUTTON_BG });
+				*border = BorderColor(if selected { SELECTED_BORDER } else { BUTTON_BORDER });
+			}
+		}
+	}
+}
+
+pub fn handle_sub_weapon_selection(
+	interaction_query: Query<(&Interaction, &SubWeaponButton), Changed<Interaction>>,
+	mut all_buttons: Query<(&SubWeaponButton, &mut BackgroundColor, &mut BorderColor)>,
+	mut selected_weapon: ResMut<SelectedWeapon>,
+) {
+	for (interaction, sub_button) in &interaction_query {
+		if *interaction == Interaction::Pressed {
+			selected_weapon.sub_weapon_type = sub_button.weapon_type;
+			info!("Selected sub-weapon: {:?}", sub_button.weapon_type);
+
+			for (button, mut bg, mut border) in all_buttons.iter_mut() {
+				let selected = button.weapon_type == sub_button.weapon_type;
+				*bg = BackgroundColor(if selected { SELECTED_BG } else { BUTTON_BG });
+				*border = BorderColor(if selected { SELECTED_BORDER } else { BUTTON_BORDER });
+			}
+		}
+	}
+}
+
 pub fn handle_weapon_selection(
 	interaction_query: Query<(&Interaction, &WeaponButton), Changed<Interaction>>,
 	mut all_weapons: Query<(&WeaponButton, &mut BackgroundColor, &mut BorderColor)>,
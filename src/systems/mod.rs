@@ -3,11 +3,20 @@ pub mod player;
 pub mod weapons;
 pub mod lightning;
 pub mod weapon_upgrade;
+pub mod weapon_tuning;
+pub mod evolution;
+pub mod status_effects;
 pub mod pickups;
 pub mod level;
 pub mod world;
 pub mod parallax;
 pub mod enemies;
+pub mod formations;
+pub mod carriers;
+pub mod enemy_defenses;
+pub mod fragmentation;
+pub mod charge;
+pub mod offscreen_warnings;
 pub mod particles;
 pub mod visual;
 pub mod menu;
@@ -20,4 +29,14 @@ pub mod spawn;
 pub mod death_fx;
 pub mod sprite_animation;
 pub mod thruster_fx;
-pub mod hanabi_fx;
\ No newline at end of file
+pub mod hanabi_fx;
+pub mod debug_overlay;
+pub mod level_editor;
+pub mod hot_reload;
+pub mod timeline;
+pub mod bomb;
+pub mod abilities;
+pub mod graze;
+pub mod level_tally;
+pub mod lives;
+pub mod rank;
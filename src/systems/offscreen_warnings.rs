@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+use bevy::render::mesh::Mesh2d;
+use bevy::sprite::MeshMaterial2d;
+use std::collections::HashMap;
+use crate::components::{Enemy, EnemyVelocity, Dying, ThreatClass};
+use crate::systems::audio::PlaySfxEvent;
+use crate::systems::world::{HALF_PLAY_WIDTH, HALF_WORLD_HEIGHT};
+
+/// How far outside the play area an incoming enemy gets an arrow
+const LOOKAHEAD: f32 = 400.0;
+/// Gap between an arrow and the edge of the play area
+const EDGE_INSET: f32 = 22.0;
+/// Arrow size for an enemy right at the edge / at the far end of the lookahead band
+const ARROW_SIZE_NEAR: f32 = 22.0;
+const ARROW_SIZE_FAR: f32 = 10.0;
+/// Above enemies, below the bomb flash
+const ARROW_Z: f32 = 15.0;
+const BANNER_SECS: f32 = 3.0;
+const BANNER_BLINK_HZ: f32 = 2.5;
+
+/// Edge arrow pointing at an off-screen enemy
+#[derive(Component)]
+pub struct OffscreenIndicator {
+	pub enemy: Entity,
+}
+
+/// Marker: this boss already triggered its warning banner
+#[derive(Component)]
+pub struct BossWarned;
+
+/// Blinking "WARNING" banner shown when a boss is on its way
+#[derive(Component)]
+pub struct WarningBanner {
+	pub timer: Timer,
+}
+
+/// Shared arrow mesh, pointing along +X
+#[derive(Resource)]
+pub struct IndicatorMesh(pub Handle<Mesh>);
+
+impl FromWorld for IndicatorMesh {
+	fn from_world(world: &mut World) -> Self {
+		let mut meshes = world.resource_mut::<Assets<Mesh>>();
+		Self(meshes.add(Triangle2d::new(
+			Vec2::new(1.0, 0.0),
+			Vec2::new(-0.6, 0.7),
+			Vec2::new(-0.6, -0.7),
+		)))
+	}
+}
+
+/// Keep one arrow at the play area's edge for each enemy heading in from the lookahead band;
+/// arrows grow as their enemy gets closer
+#[allow(clippy::type_complexity)]
+pub fn update_offscreen_indicators(
+	mut commands: Commands,
+	mesh: Res<IndicatorMesh>,
+	mut materials: ResMut<Assets<ColorMaterial>>,
+	enemies: Query<(Entity, &Transform, &Enemy, Option<&EnemyVelocity>), Without<Dying>>,
+	mut indicators: Query<(Entity, &OffscreenIndicator, &mut Transform, &MeshMaterial2d<ColorMaterial>), Without<Enemy>>,
+) {
+	let mut existing: HashMap<Entity, Entity> = indicators.iter()
+		.map(|(entity, indicator, _, _)| (indicator.enemy, entity))
+		.collect();
+	let edge_min = Vec2::new(-HALF_PLAY_WIDTH + EDGE_INSET, -HALF_WORLD_HEIGHT + EDGE_INSET);
+	let edge_max = Vec2::new(HALF_PLAY_WIDTH - EDGE_INSET, HALF_WORLD_HEIGHT - EDGE_INSET);
+
+	for (enemy_entity, transform, enemy, velocity) in enemies.iter() {
+		let pos = transform.translation.truncate();
+		let outside = Vec2::new(
+			(pos.x.abs() - HALF_PLAY_WIDTH).max(0.0),
+			(pos.y.abs() - HALF_WORLD_HEIGHT).max(0.0),
+		).length();
+		if outside <= 0.0 || outside > LOOKAHEAD {
+			continue;
+		}
+		let edge = pos.clamp(edge_min, edge_max);
+		let to_enemy = pos - edge;
+		// Only warn about enemies coming in, not ones leaving (a fresh spawn hasn't moved yet)
+		let velocity = velocity.map_or(Vec2::ZERO, |velocity| velocity.0);
+		if velocity != Vec2::ZERO && velocity.dot(to_enemy) >= 0.0 {
+			continue;
+		}
+
+		let closeness = 1.0 - outside / LOOKAHEAD;
+		let size = ARROW_SIZE_FAR + (ARROW_SIZE_NEAR - ARROW_SIZE_FAR) * closeness;
+		let color = enemy.enemy_type.threat_class().color().with_alpha(0.45 + 0.55 * closeness);
+		let arrow = Transform::from_translation(edge.extend(ARROW_Z))
+			.with_rotation(Quat::from_rotation_z(to_enemy.y.atan2(to_enemy.x)))
+			.with_scale(Vec3::splat(size));
+
+		match existing.remove(&enemy_entity).and_then(|entity| indicators.get_mut(entity).ok()) {
+			Some((_, _, mut transform, material)) => {
+				*transform = arrow;
+				if let Some(material) = materials.get_mut(&material.0) {
+					material.color = color;
+				}
+			}
+			None => {
+				commands.spawn((
+					Mesh2d(mesh.0.clone()),
+					MeshMaterial2d(materials.add(ColorMaterial::from_color(color))),
+					arrow,
+					OffscreenIndicator { enemy: enemy_entity },
+				));
+			}
+		}
+	}
+
+	// Whatever is left points at an enemy that arrived, left or died
+	for (_, indicator) in existing {
+		commands.entity(indicator).despawn();
+	}
+}
+
+/// Sound the alarm and show the WARNING banner the first time a boss is seen
+#[allow(clippy::type_complexity)]
+pub fn warn_incoming_bosses(
+	mut commands: Commands,
+	bosses: Query<(Entity, &Enemy), (Without<BossWarned>, Without<Dying>)>,
+	banners: Query<Entity, With<WarningBanner>>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+	for (entity, enemy) in bosses.iter() {
+		if enemy.enemy_type.threat_class() != ThreatClass::Boss {
+			continue;
+		}
+		commands.entity(entity).insert(BossWarned);
+		// Two bosses arriving together share one banner
+		if !banners.is_empty() {
+			continue;
+		}
+
+		commands.spawn((
+			Node {
+				position_type: PositionType::Absolute,
+				left: Val::Percent(0.0),
+				right: Val::Percent(0.0),
+				top: Val::Percent(22.0),
+				padding: UiRect::axes(Val::Px(0.0), Val::Px(14.0)),
+				justify_content: JustifyContent::Center,
+				..default()
+			},
+			BackgroundColor(Color::srgba(0.5, 0.0, 0.05, 0.6)),
+			WarningBanner {
+				timer: Timer::from_seconds(BANNER_SECS, TimerMode::Once),
+			},
+		)).with_children(|parent| {
+			parent.spawn((
+				Text::new("WARNING\nHUGE ENEMY APPROACHING"),
+				TextFont {
+					font_size: 32.0,
+					..default()
+				},
+				TextColor(Color::srgb(1.0, 0.25, 0.25)),
+				TextLayout::new_with_justify(JustifyText::Center),
+			));
+		});
+		sfx_events.send(PlaySfxEvent::simple("sounds/boss_warning.ogg", 1.0, 220, 1.0));
+		info!("🚨 WARNING: {:?} approaching", enemy.enemy_type);
+	}
+}
+
+/// Blink the banner, then take it down
+pub fn update_warning_banner(
+	mut commands: Commands,
+	time: Res<Time>,
+	mut banners: Query<(Entity, &mut WarningBanner, &mut Visibility)>,
+) {
+	for (entity, mut banner, mut visibility) in banners.iter_mut() {
+		banner.timer.tick(time.delta());
+		if banner.timer.finished() {
+			commands.entity(entity).despawn_recursive();
+			continue;
+		}
+		let shown = (banner.timer.elapsed_secs() * BANNER_BLINK_HZ).fract() < 0.7;
+		*visibility = if shown { Visibility::Inherited } else { Visibility::Hidden };
+	}
+}
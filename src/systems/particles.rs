@@ -1,12 +1,14 @@
 use bevy::prelude::*;
 use bevy::text::{Text2d, TextColor, TextFont};
 use rand::Rng;
-use crate::components::{Particle, ParticleEmitter, Player, EnemyDeathEvent, EnemyType, PlayerHitEvent, EnemyHitEvent, Enemy, FloatingDamageNumber};
+use crate::components::{Particle, ParticleEmitter, Player, PlayerDown, EnemyDeathEvent, EnemyType, PlayerHitEvent, EnemyHitEvent, Enemy, FloatingDamageNumber, DamageResistances, FrontShield, ArmorPlate, ArmorPlates};
+use crate::systems::collision::hit_multiplier;
 use crate::resources::DamageNumbersEnabled;
 
+#[allow(clippy::type_complexity)]
 pub fn spawn_engine_particles(
 	mut commands: Commands,
-	mut emitters: Query<(&Transform, &mut ParticleEmitter), With<Player>>,
+	mut emitters: Query<(&Transform, &mut ParticleEmitter), (With<Player>, Without<PlayerDown>)>,
 	asset_server: Res<AssetServer>,
 	time: Res<Time>,
 ) {
@@ -247,8 +249,8 @@ pub fn spawn_player_hit_particles(
 ) {
 	let mut rng = rand::thread_rng();
 
-	for _event in hit_events.read() {
-		let Ok(player_transform) = player_query.get_single() else { continue };
+	for event in hit_events.read() {
+		let Ok(player_transform) = player_query.get(event.player) else { continue };
 		let pos = player_transform.translation.truncate();
 
 		// Shield/spark burst effect
@@ -285,20 +287,31 @@ pub fn spawn_player_hit_particles(
 
 // === Floating Damage Numbers ===
 
-// Damage number color palette for visual distinction
+// Damage number colors by effectiveness (see `damage_color_index`)
 const DAMAGE_COLORS: &[(f32, f32, f32)] = &[
-	(1.0, 0.9, 0.2),   // Yellow/gold
-	(1.0, 0.5, 0.1),   // Orange
-	(0.2, 1.0, 0.4),   // Green
-	(0.4, 0.9, 1.0),   // Cyan
-	(1.0, 0.4, 0.6),   // Pink
-	(0.8, 0.6, 1.0),   // Lavender
+	(0.4, 0.9, 1.0),   // Cyan - blocked by a front shield
+	(0.6, 0.6, 0.7),   // Grey - resisted
+	(1.0, 0.9, 0.2),   // Yellow/gold - normal
+	(1.0, 0.5, 0.1),   // Orange - effective
+	(1.0, 0.25, 0.3),  // Red - very effective
 ];
 
+/// Index into `DAMAGE_COLORS` for a hit's damage multiplier
+fn damage_color_index(multiplier: f32) -> usize {
+	match multiplier {
+		m if m <= 0.3 => 0,
+		m if m < 0.95 => 1,
+		m if m <= 1.05 => 2,
+		m if m < 1.4 => 3,
+		_ => 4,
+	}
+}
+
+#[allow(clippy::type_complexity)]
 pub fn spawn_floating_damage_numbers(
 	mut commands: Commands,
 	mut hit_events: EventReader<EnemyHitEvent>,
-	enemy_query: Query<&Transform, With<Enemy>>,
+	enemy_query: Query<(&GlobalTransform, Option<&DamageResistances>, Option<&FrontShield>, Option<&ArmorPlates>), Or<(With<Enemy>, With<ArmorPlate>)>>,
 	asset_server: Res<AssetServer>,
 	damage_numbers_enabled: Res<DamageNumbersEnabled>,
 ) {
@@ -311,8 +324,11 @@ pub fn spawn_floating_damage_numbers(
 	let mut rng = rand::thread_rng();
 
 	for event in hit_events.read() {
-		let Ok(enemy_transform) = enemy_query.get(event.enemy) else { continue };
+		// Plates are children of their enemy, so go by world position
+		let Ok((global_transform, resistances, shield, armor)) = enemy_query.get(event.enemy) else { continue };
+		let enemy_transform = global_transform.compute_transform();
 		let pos = enemy_transform.translation.truncate();
+		let multiplier = hit_multiplier(event, &enemy_transform, resistances, shield, armor);
 
 		// Random angle between -45 and 45 degrees from vertical (in radians)
 		let angle_offset: f32 = rng.gen_range(-0.785..0.785); // ~45 degrees
@@ -322,15 +338,16 @@ pub fn spawn_floating_damage_numbers(
 			angle_offset.cos() * base_speed,
 		);
 
-		// Pick random color from palette
-		let color_idx = rng.gen_range(0..DAMAGE_COLORS.len());
+		// Color by how well the hit landed; effective hits also read bigger
+		let color_idx = damage_color_index(multiplier);
 		let (r, g, b) = DAMAGE_COLORS[color_idx];
+		let font_size = if color_idx >= 3 { 22.0 } else { 18.0 };
 
 		commands.spawn((
-			Text2d::new(format!("{:.0}", event.damage)),
+			Text2d::new(format!("{:.0}", event.damage * multiplier)),
 			TextFont {
 				font: asset_server.load("fonts/Orbitron-Variable.ttf"),
-				font_size: 18.0,
+				font_size,
 				..default()
 			},
 			TextColor(Color::srgb(r, g, b)),
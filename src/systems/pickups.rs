@@ -1,26 +1,26 @@
 use bevy::prelude::*;
-use crate::components::{Player, WeaponPickup, PowerUp, WeaponSwitchEvent, WeaponUpgradeEvent};
+use crate::components::{Player, PlayerDown, WeaponPickup, PowerUp, BombPickup, EvolutionPickup, WeaponSwitchEvent, WeaponUpgradeEvent};
 use crate::systems::audio::PlaySfxEvent;
 
-const PICKUP_RADIUS: f32 = 50.0;
+pub const PICKUP_RADIUS: f32 = 50.0;
 const PICKUP_DRIFT_SPEED: f32 = 50.0;
 
+/// Pickups go to whichever ship touches them first
+#[allow(clippy::type_complexity)]
 pub fn collect_pickups(
 	mut commands: Commands,
-	player_query: Query<&Transform, With<Player>>,
+	player_query: Query<(Entity, &Transform), (With<Player>, Without<PlayerDown>)>,
 	weapon_pickup_query: Query<(Entity, &Transform, &WeaponPickup)>,
 	power_up_query: Query<(Entity, &Transform, &PowerUp)>,
 	mut weapon_switch_events: EventWriter<WeaponSwitchEvent>,
 	mut weapon_upgrade_events: EventWriter<WeaponUpgradeEvent>,
 	mut sfx_events: EventWriter<PlaySfxEvent>,
 ) {
-	let Ok(player_transform) = player_query.get_single() else { return };
-	let player_pos = player_transform.translation.truncate();
-
 	for (entity, pickup_transform, weapon_pickup) in weapon_pickup_query.iter() {
 		let pickup_pos = pickup_transform.translation.truncate();
-		if player_pos.distance(pickup_pos) < PICKUP_RADIUS {
+		if let Some(player) = touching_player(&player_query, pickup_pos) {
 			weapon_switch_events.send(WeaponSwitchEvent {
+				player,
 				new_weapon: weapon_pickup.weapon_type,
 			});
 			commands.entity(entity).despawn();
@@ -30,8 +30,9 @@ pub fn collect_pickups(
 
 	for (entity, pickup_transform, power_up) in power_up_query.iter() {
 		let pickup_pos = pickup_transform.translation.truncate();
-		if player_pos.distance(pickup_pos) < PICKUP_RADIUS {
+		if let Some(player) = touching_player(&player_query, pickup_pos) {
 			weapon_upgrade_events.send(WeaponUpgradeEvent {
+				player,
 				level_change: power_up.upgrade_amount,
 			});
 			commands.entity(entity).despawn();
@@ -40,8 +41,20 @@ pub fn collect_pickups(
 	}
 }
 
+/// First ship within pickup range of `pickup_pos`
+#[allow(clippy::type_complexity)]
+pub fn touching_player(
+	player_query: &Query<(Entity, &Transform), (With<Player>, Without<PlayerDown>)>,
+	pickup_pos: Vec2,
+) -> Option<Entity> {
+	player_query.iter()
+		.find(|(_, transform)| transform.translation.truncate().distance(pickup_pos) < PICKUP_RADIUS)
+		.map(|(entity, _)| entity)
+}
+
+#[allow(clippy::type_complexity)]
 pub fn move_pickups(
-	mut query: Query<&mut Transform, Or<(With<WeaponPickup>, With<PowerUp>)>>,
+	mut query: Query<&mut Transform, Or<(With<WeaponPickup>, With<PowerUp>, With<BombPickup>, With<EvolutionPickup>)>>,
 	time: Res<Time>,
 ) {
 	for mut transform in query.iter_mut() {
@@ -49,13 +62,14 @@ pub fn move_pickups(
 	}
 }
 
+#[allow(clippy::type_complexity)]
 pub fn cleanup_pickups(
 	mut commands: Commands,
-	query: Query<(Entity, &Transform), Or<(With<WeaponPickup>, With<PowerUp>)>>,
+	query: Query<(Entity, &Transform), Or<(With<WeaponPickup>, With<PowerUp>, With<BombPickup>, With<EvolutionPickup>)>>,
 ) {
 	for (entity, transform) in query.iter() {
 		if transform.translation.y < -600.0 {
-			commands.entity(entity).despawn();
+			commands.entity(entity).despawn_recursive();
 		}
 	}
 }
@@ -1,52 +1,107 @@
 use bevy::prelude::*;
 use rand::Rng;
-use crate::components::{Player, ParticleEmitter, ShipType, Weapon, WeaponType, PlayerDefenses, Collider, PlayerVelocity};
-use crate::resources::{SelectedShip, SelectedWeapon};
+use bevy::input::gamepad::{Gamepad, GamepadButton};
+use crate::components::{Player, PrimaryPlayer, SubWeapon, PlayerInput, PlayerDown, ChargeMeter, ParticleEmitter, ShipType, Weapon, WeaponType, PlayerDefenses, Collider, PlayerVelocity, ShipAbility, ShipAbilityState, PlayerCoreHitbox, FocusState};
+use crate::resources::{SelectedShip, SelectedWeapon, CoopEnabled};
 use super::world::player_bounds;
+use super::weapon_tuning::WeaponTuning;
 use crate::components::ThrusterFx;
 
 const TILT_ANGLE: f32 = 0.15;  // ~8.5 degrees, subtle bank
 const TILT_SPEED: f32 = 10.0;  // How fast to tilt
+const AFTERBURNER_SPEED_MULT: f32 = 1.7;
+const FOCUS_SPEED_MULT: f32 = 0.45;
+/// Core hitbox radius as a fraction of the contact collider
+const CORE_HITBOX_FRACTION: f32 = 0.2;
+/// Horizontal spawn offset for each ship in co-op
+const COOP_SPAWN_OFFSET_X: f32 = 120.0;
+/// Player two's ship tint so the two are easy to tell apart
+const PLAYER_TWO_TINT: Color = Color::srgb(1.0, 0.75, 0.55);
+const STICK_DEADZONE: f32 = 0.2;
+
+/// Marker for the core hitbox dot shown while focused (child of the player)
+#[derive(Component)]
+pub struct FocusHitboxDot;
 
 pub fn spawn_player(
 	mut commands: Commands,
 	asset_server: Res<AssetServer>,
 	selected_ship: Res<SelectedShip>,
 	selected_weapon: Res<SelectedWeapon>,
+	coop: Res<CoopEnabled>,
+	tuning: Res<WeaponTuning>,
 ) {
 	let ship_type = selected_ship.ship_type.unwrap_or(ShipType::Tempest);
+	let weapon_type = selected_weapon.weapon_type;
+	let sub_weapon_type = selected_weapon.sub_weapon_type.filter(|w| w.can_be_sub_weapon() && *w != weapon_type);
+
+	let weapon_level = if weapon_type == WeaponType::BasicBlaster { 0 } else { 1 };
+	let weapon = || tuning.new_weapon(weapon_type, weapon_level);
+	let sub_weapon = || sub_weapon_type.map(|sub_type| SubWeapon(tuning.new_weapon(sub_type, 1)));
+
+	if coop.0 {
+		spawn_player_ship(&mut commands, &asset_server, ship_type, weapon(), sub_weapon(), 0, -COOP_SPAWN_OFFSET_X);
+		spawn_player_ship(&mut commands, &asset_server, ship_type, weapon(), sub_weapon(), 1, COOP_SPAWN_OFFSET_X);
+	} else {
+		spawn_player_ship(&mut commands, &asset_server, ship_type, weapon(), sub_weapon(), 0, 0.0);
+	}
+}
+
+/// X position a ship respawns at (ships sit side by side in co-op)
+pub fn spawn_x_for_slot(slot: u8, coop: bool) -> f32 {
+	match (coop, slot) {
+		(false, _) => 0.0,
+		(true, 0) => -COOP_SPAWN_OFFSET_X,
+		(true, _) => COOP_SPAWN_OFFSET_X,
+	}
+}
+
+fn spawn_player_ship(
+	commands: &mut Commands,
+	asset_server: &AssetServer,
+	ship_type: ShipType,
+	weapon: Weapon,
+	sub_weapon: Option<SubWeapon>,
+	slot: u8,
+	x: f32,
+) {
 	let stats = ship_type.get_stats();
 	let mut rng = rand::thread_rng();
 
-	let weapon_type = selected_weapon.weapon_type;
-	let weapon_config = weapon_type.config();
-	let weapon_level = if weapon_type == WeaponType::BasicBlaster { 0 } else { 1 };
+	let contact_radius = stats.size / 2.0 * stats.hitbox_scale;
+	let core_radius = contact_radius * CORE_HITBOX_FRACTION;
+	let tint = if slot == 0 { Color::WHITE } else { PLAYER_TWO_TINT };
 
 	let mut player_ec = commands.spawn((
 		Sprite {
 			image: asset_server.load(ship_type.sprite_path()),
 			custom_size: Some(Vec2::new(stats.size, stats.size)),
+			color: tint,
 			..default()
 		},
-		Transform::from_xyz(0.0, player_bounds::SPAWN_Y, 1.0),
-		Player {
-			fire_cooldown: Timer::from_seconds(stats.fire_cooldown, TimerMode::Repeating),
-			ship_type,
-		},
-		Weapon {
-			weapon_type,
-			level: weapon_level,
-			fire_cooldown: Timer::from_seconds(weapon_config.base_cooldown, TimerMode::Repeating),
-		},
+		Transform::from_xyz(x, player_bounds::SPAWN_Y, 1.0),
+		Player { ship_type, slot },
+		weapon,
 		PlayerTilt { target: 0.0, current: 0.0 },
 		PlayerVelocity::default(),
 		ParticleEmitter {
 			spawn_timer: Timer::from_seconds(0.05, TimerMode::Repeating),
 			offset: Vec2::new(0.0, -stats.size / 2.0 + 10.0),
 		},
-		PlayerDefenses::default(),
-		Collider::new(stats.size / 2.0 * 0.7), // Slightly smaller than visual for fair gameplay
+		PlayerDefenses::from_profile(&stats.defenses),
+		Collider::new(contact_radius), // Smaller than visual for fair gameplay
+		PlayerCoreHitbox { radius: core_radius },
+		FocusState::default(),
+		ShipAbilityState::new(stats.ability),
+		ChargeMeter::default(),
+		PlayerInput::default(),
 	));
+	if slot == 0 {
+		player_ec.insert(PrimaryPlayer);
+	}
+	if let Some(sub_weapon) = sub_weapon {
+		player_ec.insert(sub_weapon);
+	}
 
 	// Add a simple "thruster sprite" behind the ship (local-space), animated via `ThrusterFx`.
 	// This gives a juicy engine plume without requiring global bloom.
@@ -71,11 +126,24 @@ pub fn spawn_player(
 				phase: rng.gen_range(0.0..std::f32::consts::TAU),
 			},
 		));
+
+		// True hitbox, only visible in focus mode
+		parent.spawn((
+			Sprite {
+				image: asset_server.load("particles/spark_white.png"),
+				custom_size: Some(Vec2::splat(core_radius * 3.0)),
+				color: Color::srgb(1.0, 0.95, 0.95),
+				..default()
+			},
+			Transform::from_xyz(0.0, 0.0, 0.2),
+			Visibility::Hidden,
+			FocusHitboxDot,
+		));
 	});
 
 	info!(
-		"Player ship spawned: {:?} (size: {:.0} gu, speed: {}, fire rate: {})",
-		ship_type, stats.size, stats.speed, stats.fire_cooldown
+		"Player {} ship spawned: {:?} (size: {:.0} gu, speed: {}, ability: {})",
+		slot + 1, ship_type, stats.size, stats.speed, stats.ability.name()
 	);
 }
 
@@ -85,30 +153,24 @@ pub struct PlayerTilt {
 	pub current: f32,  // Current rotation (smoothly interpolates)
 }
 
+#[allow(clippy::type_complexity)]
 pub fn player_movement(
-	keyboard_input: Res<ButtonInput<KeyCode>>,
-	mut query: Query<(&mut Transform, &mut PlayerTilt, &Player, &mut PlayerVelocity), With<Player>>,
+	mut query: Query<(&mut Transform, &mut PlayerTilt, &Player, &PlayerInput, &mut PlayerVelocity, Option<&ShipAbilityState>, Option<&FocusState>)>,
 	time: Res<Time>,
 ) {
-	for (mut transform, mut tilt, player, mut player_velocity) in query.iter_mut() {
+	for (mut transform, mut tilt, player, input, mut player_velocity, ability, focus) in query.iter_mut() {
 		let stats = player.ship_type.get_stats();
-		let speed = stats.speed;
-
-		let mut direction = Vec2::ZERO;
-
-		if keyboard_input.pressed(KeyCode::ArrowLeft) || keyboard_input.pressed(KeyCode::KeyA) {
-			direction.x -= 1.0;
-		}
-		if keyboard_input.pressed(KeyCode::ArrowRight) || keyboard_input.pressed(KeyCode::KeyD) {
-			direction.x += 1.0;
-		}
-		if keyboard_input.pressed(KeyCode::ArrowUp) || keyboard_input.pressed(KeyCode::KeyW) {
-			direction.y += 1.0;
-		}
-		if keyboard_input.pressed(KeyCode::ArrowDown) || keyboard_input.pressed(KeyCode::KeyS) {
-			direction.y -= 1.0;
+		let mut speed = if ability.is_some_and(|a| a.is_active(ShipAbility::Afterburner)) {
+			stats.speed * AFTERBURNER_SPEED_MULT
+		} else {
+			stats.speed
+		};
+		if focus.is_some_and(|f| f.active) {
+			speed *= FOCUS_SPEED_MULT;
 		}
 
+		let direction = input.movement;
+
 		// Set target tilt based on horizontal movement
 		tilt.target = if direction.x < 0.0 {
 			TILT_ANGLE  // Tilt right when moving left (like banking)
@@ -126,7 +188,8 @@ pub fn player_movement(
 		transform.rotation = Quat::from_rotation_z(tilt.current);
 
 		if direction.length() > 0.0 {
-			let velocity = direction.normalize() * speed;
+			// Analog sticks can move slower than full speed; digital input is normalized
+			let velocity = direction.clamp_length_max(1.0) * speed;
 			transform.translation += velocity.extend(0.0) * time.delta_secs();
 			player_velocity.0 = velocity;
 		} else {
@@ -137,3 +200,115 @@ pub fn player_movement(
 		transform.translation.y = transform.translation.y.clamp(player_bounds::MIN_Y, player_bounds::MAX_Y);
 	}
 }
+
+/// Fill each ship's `PlayerInput` from its device: player one on the keyboard,
+/// player two on the first gamepad, falling back to the numpad
+pub fn read_player_input(
+	keyboard: Res<ButtonInput<KeyCode>>,
+	gamepads: Query<&Gamepad>,
+	mut player_query: Query<(&Player, &mut PlayerInput, Option<&PlayerDown>)>,
+) {
+	for (player, mut input, down) in player_query.iter_mut() {
+		*input = if down.is_some() {
+			PlayerInput::default()
+		} else if player.slot == 0 {
+			keyboard_input(&keyboard)
+		} else if let Some(gamepad) = gamepads.iter().next() {
+			gamepad_input(gamepad)
+		} else {
+			numpad_input(&keyboard)
+		};
+	}
+}
+
+fn keyboard_input(keyboard: &ButtonInput<KeyCode>) -> PlayerInput {
+	let any = |keys: [KeyCode; 2]| keyboard.any_pressed(keys);
+	let mut movement = Vec2::ZERO;
+	if any([KeyCode::ArrowLeft, KeyCode::KeyA]) {
+		movement.x -= 1.0;
+	}
+	if any([KeyCode::ArrowRight, KeyCode::KeyD]) {
+		movement.x += 1.0;
+	}
+	if any([KeyCode::ArrowUp, KeyCode::KeyW]) {
+		movement.y += 1.0;
+	}
+	if any([KeyCode::ArrowDown, KeyCode::KeyS]) {
+		movement.y -= 1.0;
+	}
+
+	PlayerInput {
+		movement,
+		fire: keyboard.pressed(KeyCode::Space),
+		fire_just_pressed: keyboard.just_pressed(KeyCode::Space),
+		fire_just_released: keyboard.just_released(KeyCode::Space),
+		focus: any([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+		bomb: keyboard.just_pressed(KeyCode::KeyX),
+		ability: keyboard.just_pressed(KeyCode::KeyC),
+		swap: keyboard.just_pressed(KeyCode::KeyV),
+	}
+}
+
+fn numpad_input(keyboard: &ButtonInput<KeyCode>) -> PlayerInput {
+	let mut movement = Vec2::ZERO;
+	if keyboard.pressed(KeyCode::Numpad4) {
+		movement.x -= 1.0;
+	}
+	if keyboard.pressed(KeyCode::Numpad6) {
+		movement.x += 1.0;
+	}
+	if keyboard.pressed(KeyCode::Numpad8) {
+		movement.y += 1.0;
+	}
+	if keyboard.pressed(KeyCode::Numpad5) {
+		movement.y -= 1.0;
+	}
+
+	PlayerInput {
+		movement,
+		fire: keyboard.pressed(KeyCode::Numpad0),
+		fire_just_pressed: keyboard.just_pressed(KeyCode::Numpad0),
+		fire_just_released: keyboard.just_released(KeyCode::Numpad0),
+		focus: keyboard.pressed(KeyCode::Numpad7),
+		bomb: keyboard.just_pressed(KeyCode::NumpadDecimal),
+		ability: keyboard.just_pressed(KeyCode::NumpadEnter),
+		swap: keyboard.just_pressed(KeyCode::Numpad9),
+	}
+}
+
+fn gamepad_input(gamepad: &Gamepad) -> PlayerInput {
+	let stick = gamepad.left_stick();
+	let movement = if stick.length() > STICK_DEADZONE { stick } else { gamepad.dpad() };
+
+	PlayerInput {
+		movement,
+		fire: gamepad.pressed(GamepadButton::South),
+		fire_just_pressed: gamepad.just_pressed(GamepadButton::South),
+		fire_just_released: gamepad.just_released(GamepadButton::South),
+		focus: gamepad.pressed(GamepadButton::RightTrigger2) || gamepad.pressed(GamepadButton::RightTrigger),
+		bomb: gamepad.just_pressed(GamepadButton::East),
+		ability: gamepad.just_pressed(GamepadButton::West),
+		swap: gamepad.just_pressed(GamepadButton::North),
+	}
+}
+
+/// Hold focus (Shift / trigger): slower movement, visible core hitbox, tighter spread weapons
+pub fn update_focus_mode(
+	mut player_query: Query<(&PlayerInput, &mut FocusState, &Children)>,
+	mut dot_query: Query<&mut Visibility, With<FocusHitboxDot>>,
+) {
+	for (input, mut focus, children) in player_query.iter_mut() {
+		if focus.active != input.focus {
+			focus.active = input.focus;
+		}
+
+		let target = if input.focus { Visibility::Inherited } else { Visibility::Hidden };
+		for &child in children.iter() {
+			if let Ok(mut visibility) = dot_query.get_mut(child) {
+				if *visibility != target {
+					*visibility = target;
+				}
+			}
+		}
+	}
+}
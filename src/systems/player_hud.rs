@@ -1,6 +1,12 @@
 use bevy::prelude::*;
-use crate::components::{Player, PlayerDefenses, DefenseHexagon, DefenseLayer, ArmorDamageState, ArmorState, ChargeMeter, WeaponType, Weapon};
-use crate::resources::SelectedWeapon;
+use crate::components::{Player, PrimaryPlayer, PlayerDown, PlayerDefenses, DefenseHexagon, DefenseLayer, ArmorDamageState, ArmorState, ChargeMeter, WeaponType, Weapon, SubWeapon};
+use crate::resources::{SelectedWeapon, Score, CoopEnabled};
+use crate::systems::bomb::BombStock;
+use crate::systems::lives::Lives;
+use crate::systems::lightning::ENHANCED_MODE_LEVEL;
+
+/// Player two's ship tint, reused for their HUD panel
+const PLAYER_TWO_TINT: Color = Color::srgb(1.0, 0.75, 0.55);
 
 #[derive(Component)]
 pub struct PlayerHudContainer;
@@ -21,6 +27,30 @@ pub struct Shield1Text;
 #[derive(Component)]
 pub struct ArmorText;
 
+/// Bomb stock readout under the defence display
+#[derive(Component)]
+pub struct BombStockText;
+
+/// Graze counter under the bomb stock
+#[derive(Component)]
+pub struct GrazeCountText;
+
+/// Lives stock readout above the defence display title
+#[derive(Component)]
+pub struct LivesText;
+
+/// Primary / sub-weapon slots with their levels, below the lives readout
+#[derive(Component)]
+pub struct WeaponSlotText;
+
+/// Co-op: player two's defence/charge readout in the bottom-right corner
+#[derive(Component)]
+pub struct PlayerTwoHudText;
+
+/// Which ship a per-player HUD element tracks (0 = player one)
+#[derive(Component)]
+pub struct HudSlot(pub u8);
+
 /// Marker for the charge meter rail sprite
 #[derive(Component)]
 pub struct ChargeMeterRail;
@@ -92,6 +122,7 @@ pub fn spawn_player_hud(
 	windows: Query<&Window>,
 	mut hud_state: ResMut<HudSpawnState>,
 	hud_entities: Query<Entity, With<HudElement>>,
+	coop: Res<CoopEnabled>,
 ) {
 	// Get camera first to check viewport
 	let Ok((camera, projection)) = camera_query.get_single() else {
@@ -151,148 +182,68 @@ pub fn spawn_player_hud(
 	hud_state.spawned = true;
 	hud_state.viewport_width_at_spawn = ortho.area.width();
 
-	// Spawn mesh panel background (bottom layer)
-	commands.spawn((
-		Sprite {
-			image: asset_server.load("ui/hud_mesh_panel.png"),
-			color: Color::srgba(1.0, 1.0, 1.0, 0.6), // 60% opacity
-			..default()
-		},
-		Transform::from_xyz(center.x + 50.0, center.y + 50.0, 10.0) // Offset 50px right, 50px up
-			.with_scale(Vec3::splat(0.4)), // Mesh scale (already vertical in file)
-		PlayerHudContainer,
-		HudElement,
-	));
-
-	// Spawn digital display panel (mounted on mesh, encompasses hexagons + text)
-	commands.spawn((
-		Sprite {
-			image: asset_server.load("ui/display_panel_vertical_green.png"),
-			color: Color::srgba(1.0, 1.0, 1.0, 0.85),
-			..default()
-		},
-		Transform::from_xyz(center.x + 9.0, center.y, 10.05) // Position at -815
-			.with_scale(Vec3::splat(0.25)), // Same size as previous panel
-		HudElement,
-	));
-
 	// Load Orbitron font for HUD
 	let orbitron_font = asset_server.load("fonts/Orbitron-Variable.ttf");
 
-	// Spawn "DEFENCE" title at top of display
-	commands.spawn((
-		Text2d::new("DEFENCE"),
-		TextFont {
-			font: orbitron_font.clone(),
-			font_size: 12.0,
-			..default()
-		},
-		TextColor(Color::srgb(0.4, 1.0, 0.5)), // Light green to match display
-		Transform::from_xyz(center.x + 9.0, center.y + 80.0, 10.2),
-		HudElement,
-	));
+	spawn_defense_panel(&mut commands, &asset_server, &orbitron_font, center, 0);
 
-	// Spawn Shield2 text (cyan) - top of display
+	// Co-op: player two gets the same panel mirrored in the bottom-right corner
+	if coop.0 {
+		let center_two = Vec2::new(ortho.area.max.x - (center_x - left_edge) - 100.0, center_y);
+		spawn_defense_panel(&mut commands, &asset_server, &orbitron_font, center_two, 1);
+		commands.spawn((
+			Text2d::new("CHARGE"),
+			TextFont {
+				font: orbitron_font.clone(),
+				font_size: 10.0,
+				..default()
+			},
+			TextColor(PLAYER_TWO_TINT),
+			Transform::from_xyz(center_two.x + 9.0, center_two.y - 62.0, 10.2),
+			PlayerTwoHudText,
+			HudElement,
+		));
+	}
+
+	// Spawn bomb stock (magenta) - below the hexagons
 	commands.spawn((
-		Text2d::new("75"),
+		Text2d::new("BOMBS"),
 		TextFont {
 			font: orbitron_font.clone(),
-			font_size: 10.0, // Made smaller
+			font_size: 10.0,
 			..default()
 		},
-		TextColor(Color::srgb(0.0, 1.0, 1.0)), // Cyan
-		Transform::from_xyz(center.x + 9.0 - 28.0, center.y + 50.0, 10.2), // Spread further apart
-		Shield2Text,
+		TextColor(Color::srgb(1.0, 0.3, 0.6)), // Matches bomb pickups
+		Transform::from_xyz(center.x + 9.0, center.y - 62.0, 10.2),
+		BombStockText,
 		HudElement,
 	));
 
-	// Spawn Shield1 text (blue) - top center
+	// Spawn graze counter (pale cyan) - below bomb stock
 	commands.spawn((
-		Text2d::new("200"),
+		Text2d::new("GRAZE 0"),
 		TextFont {
 			font: orbitron_font.clone(),
-			font_size: 10.0, // Made smaller
+			font_size: 10.0,
 			..default()
 		},
-		TextColor(Color::srgb(0.1, 0.4, 1.0)), // Deep blue
-		Transform::from_xyz(center.x + 9.0, center.y + 50.0, 10.2),
-		Shield1Text,
+		TextColor(Color::srgb(0.8, 0.95, 1.0)), // Matches graze sparks
+		Transform::from_xyz(center.x + 9.0, center.y - 76.0, 10.2),
+		GrazeCountText,
 		HudElement,
 	));
 
-	// Spawn Armor text (bronze) - top right
+	// Spawn lives stock (red) - below graze counter
 	commands.spawn((
-		Text2d::new("100"),
+		Text2d::new("LIVES"),
 		TextFont {
-			font: orbitron_font,
-			font_size: 10.0, // Made smaller
+			font: orbitron_font.clone(),
+			font_size: 10.0,
 			..default()
 		},
-		TextColor(Color::srgb(0.7, 0.6, 0.4)), // Bronze
-		Transform::from_xyz(center.x + 9.0 + 28.0, center.y + 50.0, 10.2), // Spread further apart
-		ArmorText,
-		HudElement,
-	));
-
-	// Spawn Shield2 hexagon (outermost, cyan) - layered base + glow
-	commands.spawn((
-		Sprite::from_image(asset_server.load("ui/shield2_cyan.png")),
-		Transform::from_xyz(center.x + 9.0, center.y, 10.1)
-			.with_scale(Vec3::splat(0.2295)), // Reduced by 23.5% total
-		DefenseHexagon {
-			layer: DefenseLayer::Shield2,
-			base_size: 100.0,
-			pulse_phase: 0.0,
-			pulse_speed: 1.0,
-			particle_spawn_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
-		},
-		HudElement,
-	)).with_children(|parent| {
-		// Glow overlay (child sprite, alpha varies with health)
-		parent.spawn((
-			Sprite::from_image(asset_server.load("ui/shield2_cyan_glow_bright.png")),
-			Transform::from_xyz(0.0, 0.0, 0.01), // Slightly in front of parent
-			DefenseGlow,
-		));
-	});
-
-	// Spawn Shield1 hexagon (middle, deep blue) - layered base + glow
-	commands.spawn((
-		Sprite::from_image(asset_server.load("ui/shield1_blue.png")),
-		Transform::from_xyz(center.x + 9.0, center.y, 10.2)
-			.with_scale(Vec3::splat(0.1683)), // Reduced by 23.5% total
-		DefenseHexagon {
-			layer: DefenseLayer::Shield1,
-			base_size: 70.0,
-			pulse_phase: 0.0,
-			pulse_speed: 1.0,
-			particle_spawn_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
-		},
-		HudElement,
-	)).with_children(|parent| {
-		// Glow overlay (child sprite, alpha varies with health)
-		parent.spawn((
-			Sprite::from_image(asset_server.load("ui/shield1_blue_glow_subtle.png")),
-			Transform::from_xyz(0.0, 0.0, 0.01), // Slightly in front of parent
-			DefenseGlow,
-		));
-	});
-
-	// Spawn Armor hexagon (innermost, bronze)
-	commands.spawn((
-		Sprite::from_image(asset_server.load("ui/armor_bronze.png")),
-		Transform::from_xyz(center.x + 9.0, center.y, 10.3)
-			.with_scale(Vec3::splat(0.09945)), // Reduced by 23.5% total
-		DefenseHexagon {
-			layer: DefenseLayer::Armor,
-			base_size: 40.0,
-			pulse_phase: 0.0,
-			pulse_speed: 1.0,
-			particle_spawn_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
-		},
-		ArmorDamageState {
-			current_state: ArmorState::Intact,
-		},
+		TextColor(Color::srgb(1.0, 0.4, 0.4)),
+		Transform::from_xyz(center.x + 9.0, center.y - 90.0, 10.2),
+		LivesText,
 		HudElement,
 	));
 
@@ -418,6 +369,182 @@ pub fn spawn_player_hud(
 	));
 }
 
+/// One ship's defense hexagons, readouts and weapon slots, centered on `center`
+fn spawn_defense_panel(
+	commands: &mut Commands,
+	asset_server: &AssetServer,
+	font: &Handle<Font>,
+	center: Vec2,
+	slot: u8,
+) {
+	// Spawn mesh panel background (bottom layer)
+	commands.spawn((
+		Sprite {
+			image: asset_server.load("ui/hud_mesh_panel.png"),
+			color: Color::srgba(1.0, 1.0, 1.0, 0.6), // 60% opacity
+			..default()
+		},
+		Transform::from_xyz(center.x + 50.0, center.y + 50.0, 10.0) // Offset 50px right, 50px up
+			.with_scale(Vec3::splat(0.4)), // Mesh scale (already vertical in file)
+		PlayerHudContainer,
+		HudSlot(slot),
+		HudElement,
+	));
+
+	// Spawn digital display panel (mounted on mesh, encompasses hexagons + text)
+	commands.spawn((
+		Sprite {
+			image: asset_server.load("ui/display_panel_vertical_green.png"),
+			color: Color::srgba(1.0, 1.0, 1.0, 0.85),
+			..default()
+		},
+		Transform::from_xyz(center.x + 9.0, center.y, 10.05) // Position at -815
+			.with_scale(Vec3::splat(0.25)), // Same size as previous panel
+		HudSlot(slot),
+		HudElement,
+	));
+
+	// Spawn "DEFENCE" title at top of display
+	commands.spawn((
+		Text2d::new(if slot == 0 { "DEFENCE" } else { "PLAYER 2" }),
+		TextFont {
+			font: font.clone(),
+			font_size: 12.0,
+			..default()
+		},
+		TextColor(if slot == 0 { Color::srgb(0.4, 1.0, 0.5) } else { PLAYER_TWO_TINT }), // Light green to match display
+		Transform::from_xyz(center.x + 9.0, center.y + 80.0, 10.2),
+		HudSlot(slot),
+		HudElement,
+	));
+
+	// Spawn Shield2 text (cyan) - top of display
+	commands.spawn((
+		Text2d::new("75"),
+		TextFont {
+			font: font.clone(),
+			font_size: 10.0, // Made smaller
+			..default()
+		},
+		TextColor(Color::srgb(0.0, 1.0, 1.0)), // Cyan
+		Transform::from_xyz(center.x + 9.0 - 28.0, center.y + 50.0, 10.2), // Spread further apart
+		Shield2Text,
+		HudSlot(slot),
+		HudElement,
+	));
+
+	// Spawn Shield1 text (blue) - top center
+	commands.spawn((
+		Text2d::new("200"),
+		TextFont {
+			font: font.clone(),
+			font_size: 10.0, // Made smaller
+			..default()
+		},
+		TextColor(Color::srgb(0.1, 0.4, 1.0)), // Deep blue
+		Transform::from_xyz(center.x + 9.0, center.y + 50.0, 10.2),
+		Shield1Text,
+		HudSlot(slot),
+		HudElement,
+	));
+
+	// Spawn Armor text (bronze) - top right
+	commands.spawn((
+		Text2d::new("100"),
+		TextFont {
+			font: font.clone(),
+			font_size: 10.0, // Made smaller
+			..default()
+		},
+		TextColor(Color::srgb(0.7, 0.6, 0.4)), // Bronze
+		Transform::from_xyz(center.x + 9.0 + 28.0, center.y + 50.0, 10.2), // Spread further apart
+		ArmorText,
+		HudSlot(slot),
+		HudElement,
+	));
+
+	// Spawn weapon slots (white) - below lives
+	commands.spawn((
+		Text2d::new("MAIN"),
+		TextFont {
+			font: font.clone(),
+			font_size: 10.0,
+			..default()
+		},
+		TextColor(Color::srgb(0.85, 0.9, 1.0)),
+		TextLayout::new_with_justify(JustifyText::Left),
+		Transform::from_xyz(center.x + 9.0, center.y - 112.0, 10.2),
+		WeaponSlotText,
+		HudSlot(slot),
+		HudElement,
+	));
+
+	// Spawn Shield2 hexagon (outermost, cyan) - layered base + glow
+	commands.spawn((
+		Sprite::from_image(asset_server.load("ui/shield2_cyan.png")),
+		Transform::from_xyz(center.x + 9.0, center.y, 10.1)
+			.with_scale(Vec3::splat(0.2295)), // Reduced by 23.5% total
+		DefenseHexagon {
+			layer: DefenseLayer::Shield2,
+			base_size: 100.0,
+			pulse_phase: 0.0,
+			pulse_speed: 1.0,
+			particle_spawn_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+		},
+		HudSlot(slot),
+		HudElement,
+	)).with_children(|parent| {
+		// Glow overlay (child sprite, alpha varies with health)
+		parent.spawn((
+			Sprite::from_image(asset_server.load("ui/shield2_cyan_glow_bright.png")),
+			Transform::from_xyz(0.0, 0.0, 0.01), // Slightly in front of parent
+			DefenseGlow,
+		));
+	});
+
+	// Spawn Shield1 hexagon (middle, deep blue) - layered base + glow
+	commands.spawn((
+		Sprite::from_image(asset_server.load("ui/shield1_blue.png")),
+		Transform::from_xyz(center.x + 9.0, center.y, 10.2)
+			.with_scale(Vec3::splat(0.1683)), // Reduced by 23.5% total
+		DefenseHexagon {
+			layer: DefenseLayer::Shield1,
+			base_size: 70.0,
+			pulse_phase: 0.0,
+			pulse_speed: 1.0,
+			particle_spawn_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+		},
+		HudSlot(slot),
+		HudElement,
+	)).with_children(|parent| {
+		// Glow overlay (child sprite, alpha varies with health)
+		parent.spawn((
+			Sprite::from_image(asset_server.load("ui/shield1_blue_glow_subtle.png")),
+			Transform::from_xyz(0.0, 0.0, 0.01), // Slightly in front of parent
+			DefenseGlow,
+		));
+	});
+
+	// Spawn Armor hexagon (innermost, bronze)
+	commands.spawn((
+		Sprite::from_image(asset_server.load("ui/armor_bronze.png")),
+		Transform::from_xyz(center.x + 9.0, center.y, 10.3)
+			.with_scale(Vec3::splat(0.09945)), // Reduced by 23.5% total
+		DefenseHexagon {
+			layer: DefenseLayer::Armor,
+			base_size: 40.0,
+			pulse_phase: 0.0,
+			pulse_speed: 1.0,
+			particle_spawn_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+		},
+		ArmorDamageState {
+			current_state: ArmorState::Intact,
+		},
+		HudSlot(slot),
+		HudElement,
+	));
+}
+
 /// Calculate defense layer alpha based on current/max ratio (non-linear fade)
 fn calculate_defense_alpha(current: f32, max: f32, base_alpha: f32) -> f32 {
 	let ratio = (current / max).clamp(0.0, 1.0);
@@ -435,15 +562,15 @@ pub fn animate_defense_hexagons(
 		&mut DefenseHexagon,
 		Option<&mut ArmorDamageState>,
 		Option<&Children>,
+		&HudSlot,
 	), Without<DefenseGlow>>,
 	mut glow_query: Query<&mut Sprite, With<DefenseGlow>>,
-	player_query: Query<&PlayerDefenses, With<Player>>,
+	player_query: Query<(&Player, &PlayerDefenses)>,
 	asset_server: Res<AssetServer>,
 	time: Res<Time>,
 ) {
-	let Ok(defenses) = player_query.get_single() else { return };
-
-	for (entity, mut transform, mut sprite, mut hexagon, armor_state, children_opt) in hexagon_query.iter_mut() {
+	for (entity, mut transform, mut sprite, mut hexagon, armor_state, children_opt, hud_slot) in hexagon_query.iter_mut() {
+		let Some((_, defenses)) = player_query.iter().find(|(player, _)| player.slot == hud_slot.0) else { continue };
 		let (current, max) = match hexagon.layer {
 			DefenseLayer::Shield2 => (defenses.shield2, defenses.shield2_max),
 			DefenseLayer::Shield1 => (defenses.shield1, defenses.shield1_max),
@@ -652,33 +779,136 @@ pub fn animate_defense_hexagons(
 	}
 }
 
-/// Update digital display text with current defense values
+/// Update each panel's digital display text with its ship's defense values
+#[allow(clippy::type_complexity)]
 pub fn update_digital_display_text(
-	player_query: Query<&PlayerDefenses, With<Player>>,
-	mut shield2_query: Query<&mut Text2d, (With<Shield2Text>, Without<Shield1Text>, Without<ArmorText>)>,
-	mut shield1_query: Query<&mut Text2d, (With<Shield1Text>, Without<Shield2Text>, Without<ArmorText>)>,
-	mut armor_query: Query<&mut Text2d, (With<ArmorText>, Without<Shield2Text>, Without<Shield1Text>)>,
+	player_query: Query<(&Player, &PlayerDefenses)>,
+	mut text_query: Query<(&mut Text2d, &HudSlot, Has<Shield2Text>, Has<Shield1Text>, Has<ArmorText>)>,
 ) {
-	let Ok(defenses) = player_query.get_single() else { return };
+	for (mut text, hud_slot, is_shield2, is_shield1, is_armor) in text_query.iter_mut() {
+		let Some((_, defenses)) = player_query.iter().find(|(player, _)| player.slot == hud_slot.0) else { continue };
+		let value = if is_shield2 {
+			defenses.shield2
+		} else if is_shield1 {
+			defenses.shield1
+		} else if is_armor {
+			defenses.armor
+		} else {
+			continue;
+		};
+		let label = format!("{:.0}", value);
+		if **text != label {
+			**text = label;
+		}
+	}
+}
 
-	if let Ok(mut text) = shield2_query.get_single_mut() {
-		**text = format!("{:.0}", defenses.shield2);
+/// "MAIN x Ln" / "SUB y Ln" lines for a ship's loadout, with "EVO name" once evolved
+fn weapon_slot_label(weapon: &Weapon, sub_weapon: Option<&SubWeapon>) -> String {
+	let mut label = format!("MAIN {}", weapon_slot_text(weapon));
+	if let Some(sub) = sub_weapon {
+		label.push_str(&format!("\nSUB  {}", weapon_slot_text(&sub.0)));
 	}
+	label
+}
+
+fn weapon_slot_text(weapon: &Weapon) -> String {
+	let text = format!("{} L{}", weapon_short_name(weapon.weapon_type), weapon.level);
+	match weapon.evolution {
+		Some(evolution) => format!("{} EVO {}", text, evolution.name().to_uppercase()),
+		None => text,
+	}
+}
 
-	if let Ok(mut text) = shield1_query.get_single_mut() {
-		**text = format!("{:.0}", defenses.shield1);
+fn weapon_short_name(weapon_type: WeaponType) -> &'static str {
+	match weapon_type {
+		WeaponType::BasicBlaster => "BLASTER",
+		WeaponType::PlasmaCannon => "PLASMA",
+		WeaponType::WaveGun => "WAVE",
+		WeaponType::SpreadShot => "SPREAD",
+		WeaponType::MissilePods => "MISSILES",
+		WeaponType::LaserArray => "LASER",
+		WeaponType::OrbitalDefense => "ORBITALS",
+		WeaponType::LightningChain => "LIGHTNING",
 	}
+}
 
-	if let Ok(mut text) = armor_query.get_single_mut() {
-		**text = format!("{:.0}", defenses.armor);
+/// Show each ship's primary and sub-weapon with levels on its own panel
+pub fn update_weapon_slot_text(
+	player_query: Query<(&Player, &Weapon, Option<&SubWeapon>)>,
+	mut query: Query<(&mut Text2d, &HudSlot), With<WeaponSlotText>>,
+) {
+	for (mut text, hud_slot) in query.iter_mut() {
+		let Some((_, weapon, sub_weapon)) = player_query.iter().find(|(player, ..)| player.slot == hud_slot.0) else { continue };
+		let label = weapon_slot_label(weapon, sub_weapon);
+		if **text != label {
+			**text = label;
+		}
+	}
+}
+
+/// Co-op: player two's charge, or DOWN while they're out
+pub fn update_player_two_hud_text(
+	player_query: Query<(&Player, &ChargeMeter, Option<&PlayerDown>)>,
+	mut query: Query<&mut Text2d, With<PlayerTwoHudText>>,
+) {
+	let Some((_, charge_meter, down)) = player_query.iter().find(|(player, ..)| player.slot == 1) else { return };
+	let label = if down.is_some() {
+		"DOWN".to_string()
+	} else {
+		format!("CHARGE {:.1}", charge_meter.current)
+	};
+	for mut text in query.iter_mut() {
+		if **text != label {
+			**text = label.clone();
+		}
+	}
+}
+
+/// Show remaining bomb stock
+pub fn update_bomb_stock_text(
+	stock: Res<BombStock>,
+	mut query: Query<&mut Text2d, With<BombStockText>>,
+) {
+	let label = format!("BOMBS {}", stock.count);
+	for mut text in query.iter_mut() {
+		// Only touch the text when it differs to avoid re-layout every frame
+		if **text != label {
+			**text = label.clone();
+		}
+	}
+}
+
+/// Show remaining lives
+pub fn update_lives_text(
+	lives: Res<Lives>,
+	mut query: Query<&mut Text2d, With<LivesText>>,
+) {
+	let label = format!("LIVES {}", lives.stock);
+	for mut text in query.iter_mut() {
+		if **text != label {
+			**text = label.clone();
+		}
+	}
+}
+
+/// Show the run's graze count
+pub fn update_graze_count_text(
+	score: Res<Score>,
+	mut query: Query<&mut Text2d, With<GrazeCountText>>,
+) {
+	let label = format!("GRAZE {}", score.grazes);
+	for mut text in query.iter_mut() {
+		if **text != label {
+			**text = label.clone();
+		}
 	}
 }
 
 /// Update charge meter capacitors and lightbulbs based on current charge state
 pub fn update_charge_meter_ui(
-	charge_meter: Res<ChargeMeter>,
 	selected_weapon: Res<SelectedWeapon>,
-	weapon_query: Query<&Weapon, With<Player>>,
+	primary_query: Query<(&Weapon, &ChargeMeter), With<PrimaryPlayer>>,
 	mut capacitor_query: Query<(&mut Sprite, &ChargeMeterCapacitor), Without<EnhancedModeLightbulb>>,
 	mut lightbulb_query: Query<(&mut Sprite, &EnhancedModeLightbulb), Without<ChargeMeterCapacitor>>,
 	mut rail_query: Query<&mut Visibility, (With<ChargeMeterRail>, Without<EnhancedModeOffline>, Without<EnhancedModeOnline>)>,
@@ -687,14 +917,15 @@ pub fn update_charge_meter_ui(
 	asset_server: Res<AssetServer>,
 ) {
 	// Get weapon level
-	let weapon_level = weapon_query.get_single().map(|w| w.level).unwrap_or(1);
+	let Ok((weapon, charge_meter)) = primary_query.get_single() else { return };
+	let weapon_level = weapon.level;
 
 	// Show panel for all weapons (can be repurposed for weapon-specific info later)
 	let is_lightning = selected_weapon.weapon_type == WeaponType::LightningChain;
 	let show_panel = true; // Panel always visible
-	let show_capacitors = is_lightning && weapon_level >= 8;
-	let show_offline = is_lightning && weapon_level < 8;
-	let show_online = is_lightning && weapon_level >= 8;
+	let show_capacitors = is_lightning && weapon_level >= ENHANCED_MODE_LEVEL;
+	let show_offline = is_lightning && weapon_level < ENHANCED_MODE_LEVEL;
+	let show_online = is_lightning && weapon_level >= ENHANCED_MODE_LEVEL;
 
 	// Panel + rail always visible for all weapons
 	for mut visibility in rail_query.iter_mut() {
@@ -789,17 +1020,17 @@ pub fn update_charge_meter_ui(
 /// Render glowing center on capacitors when building charge is maxed
 pub fn render_capacitor_glow(
 	mut gizmos: Gizmos,
-	charge_meter: Res<ChargeMeter>,
 	selected_weapon: Res<SelectedWeapon>,
-	weapon_query: Query<&Weapon, With<Player>>,
+	primary_query: Query<(&Weapon, &ChargeMeter), With<PrimaryPlayer>>,
 	capacitor_query: Query<(&Transform, &ChargeMeterCapacitor)>,
 ) {
 	// Only for lightning weapon level 8+
 	if selected_weapon.weapon_type != WeaponType::LightningChain {
 		return;
 	}
-	let weapon_level = weapon_query.get_single().map(|w| w.level).unwrap_or(1);
-	if weapon_level < 8 {
+	let Ok((weapon, charge_meter)) = primary_query.get_single() else { return };
+	let weapon_level = weapon.level;
+	if weapon_level < ENHANCED_MODE_LEVEL {
 		return;
 	}
 
@@ -838,8 +1069,7 @@ pub fn render_capacitor_glow(
 pub fn render_charge_meter_ticks(
 	mut gizmos: Gizmos,
 	selected_weapon: Res<SelectedWeapon>,
-	weapon_query: Query<&Weapon, With<Player>>,
-	charge_meter: Res<ChargeMeter>,
+	primary_query: Query<(&Weapon, &ChargeMeter), With<PrimaryPlayer>>,
 	time: Res<Time>,
 	camera_query: Query<(&Camera, &Projection), With<Camera2d>>,
 	windows: Query<&Window>,
@@ -849,8 +1079,9 @@ pub fn render_charge_meter_ticks(
 		return;
 	}
 
-	let weapon_level = weapon_query.get_single().map(|w| w.level).unwrap_or(1);
-	if weapon_level < 8 {
+	let Ok((weapon, charge_meter)) = primary_query.get_single() else { return };
+	let weapon_level = weapon.level;
+	if weapon_level < ENHANCED_MODE_LEVEL {
 		return;
 	}
 
@@ -946,8 +1177,7 @@ pub fn render_charge_meter_ticks(
 pub fn render_enhanced_mode_sparks(
 	mut gizmos: Gizmos,
 	selected_weapon: Res<SelectedWeapon>,
-	weapon_query: Query<&Weapon, With<Player>>,
-	charge_meter: Res<ChargeMeter>,
+	primary_query: Query<&Weapon, With<PrimaryPlayer>>,
 	time: Res<Time>,
 	camera_query: Query<(&Camera, &Projection), With<Camera2d>>,
 	windows: Query<&Window>,
@@ -957,8 +1187,9 @@ pub fn render_enhanced_mode_sparks(
 		return;
 	}
 
-	let weapon_level = weapon_query.get_single().map(|w| w.level).unwrap_or(1);
-	if weapon_level < 8 {
+	let Ok(weapon) = primary_query.get_single() else { return };
+	let weapon_level = weapon.level;
+	if weapon_level < ENHANCED_MODE_LEVEL {
 		return;
 	}
 
@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+use crate::components::{Player, Weapon, PlayerHitEvent, DamageSink, EnemyDeathEvent};
+use crate::resources::Score;
+use crate::systems::level::GamePaused;
+use crate::systems::weapon_tuning::WeaponTuning;
+
+/// Earned rank per enemy killed
+const KILL_RANK: f32 = 0.004;
+/// Seconds without a hit before clean play starts raising rank
+const NO_HIT_GRACE_SECS: f32 = 10.0;
+const NO_HIT_RANK_PER_SEC: f32 = 0.004;
+const DEATH_RANK: f32 = 0.25;
+const BOMB_RANK: f32 = 0.08;
+/// Earned rank can go below zero so deaths can offset weapon level and score
+const MIN_EARNED_RANK: f32 = -0.4;
+/// Share of the rank that comes from weapon level (averaged over ships, relative to max level)
+const WEAPON_RANK_WEIGHT: f32 = 0.2;
+/// Share of the rank that comes from score, full at `SCORE_FOR_FULL_RANK`
+const SCORE_RANK_WEIGHT: f32 = 0.2;
+const SCORE_FOR_FULL_RANK: f32 = 50_000.0;
+
+/// Hidden rank (0..1) that tracks how well the run is going and pushes enemies harder.
+/// Stacks on top of the selected difficulty.
+#[derive(Resource, Default)]
+pub struct Rank {
+	/// Current rank, recomputed every frame
+	pub value: f32,
+	/// Part earned from kills and clean play and lost on deaths and bombs
+	pub earned: f32,
+	pub no_hit_secs: f32,
+}
+
+impl Rank {
+	pub fn bullet_speed_multiplier(&self) -> f32 {
+		1.0 + 0.3 * self.value
+	}
+
+	/// How much faster enemy fire timers run (1.5 = cooldowns a third shorter)
+	pub fn fire_rate_multiplier(&self) -> f32 {
+		1.0 + 0.5 * self.value
+	}
+
+	/// Chance that a spawn tagged for the next difficulty up appears anyway
+	pub fn extra_spawn_chance(&self) -> f32 {
+		0.5 * self.value
+	}
+
+	pub fn on_bomb(&mut self) {
+		self.earned = (self.earned - BOMB_RANK).max(MIN_EARNED_RANK);
+	}
+
+	/// Clamp the earned part, then fold in weapon level and score (both 0..1) to get the rank
+	fn recompute(&mut self, weapon_term: f32, score_term: f32) {
+		self.earned = self.earned.clamp(MIN_EARNED_RANK, 1.0);
+		self.value = (self.earned + WEAPON_RANK_WEIGHT * weapon_term + SCORE_RANK_WEIGHT * score_term).clamp(0.0, 1.0);
+	}
+}
+
+/// Reset rank when a run starts - call this on OnEnter(Playing)
+pub fn reset_rank(mut commands: Commands) {
+	commands.insert_resource(Rank::default());
+}
+
+/// Raise rank on kills and clean play, drop it on deaths, then fold in weapon level and score
+#[allow(clippy::too_many_arguments)]
+pub fn update_rank(
+	mut rank: ResMut<Rank>,
+	mut death_events: EventReader<EnemyDeathEvent>,
+	mut hit_events: EventReader<PlayerHitEvent>,
+	weapon_query: Query<&Weapon, With<Player>>,
+	tuning: Res<WeaponTuning>,
+	score: Res<Score>,
+	paused: Res<GamePaused>,
+	time: Res<Time>,
+) {
+	let kills = death_events.read().filter(|event| event.killed_by_player).count();
+	rank.earned += kills as f32 * KILL_RANK;
+
+	// Several hit events can report the same death; one penalty per ship
+	let mut dead: Vec<Entity> = Vec::new();
+	for event in hit_events.read() {
+		rank.no_hit_secs = 0.0;
+		if event.sink == DamageSink::Dead && !dead.contains(&event.player) {
+			dead.push(event.player);
+		}
+	}
+	rank.earned -= dead.len() as f32 * DEATH_RANK;
+
+	if !paused.0 {
+		rank.no_hit_secs += time.delta_secs();
+		if rank.no_hit_secs > NO_HIT_GRACE_SECS {
+			rank.earned += NO_HIT_RANK_PER_SEC * time.delta_secs();
+		}
+	}
+	let weapon_levels: Vec<f32> = weapon_query.iter()
+		.map(|weapon| {
+			let max_level = tuning.weapon(weapon.weapon_type).max_level();
+			if max_level == 0 { 0.0 } else { weapon.level as f32 / max_level as f32 }
+		})
+		.collect();
+	let weapon_term = if weapon_levels.is_empty() {
+		0.0
+	} else {
+		weapon_levels.iter().sum::<f32>() / weapon_levels.len() as f32
+	};
+	let score_term = (score.points as f32 / SCORE_FOR_FULL_RANK).min(1.0);
+
+	rank.recompute(weapon_term, score_term);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rank_stays_in_range() {
+		let mut rank = Rank { earned: 5.0, ..default() };
+		rank.recompute(1.0, 1.0);
+		assert_eq!(rank.earned, 1.0);
+		assert_eq!(rank.value, 1.0);
+
+		rank.earned = -5.0;
+		rank.recompute(0.0, 0.0);
+		assert_eq!(rank.earned, MIN_EARNED_RANK);
+		assert_eq!(rank.value, 0.0);
+	}
+
+	#[test]
+	fn negative_earned_rank_offsets_weapon_and_score() {
+		let mut rank = Rank { earned: -0.1, ..default() };
+		rank.recompute(1.0, 0.5);
+		let expected = -0.1 + WEAPON_RANK_WEIGHT + SCORE_RANK_WEIGHT * 0.5;
+		assert!((rank.value - expected).abs() < 1e-6);
+	}
+
+	#[test]
+	fn bombs_never_push_earned_rank_below_the_floor() {
+		let mut rank = Rank::default();
+		for _ in 0..20 {
+			rank.on_bomb();
+		}
+		assert_eq!(rank.earned, MIN_EARNED_RANK);
+	}
+}
@@ -2,9 +2,12 @@ use bevy::prelude::*;
 use bevy::render::mesh::Mesh2d;
 use bevy::sprite::MeshMaterial2d;
 
-use crate::components::{CapsuleAxis, CollisionShape, DeathFx, Enemy, EnemyBehavior, EnemyMovement, EnemyType, FxPolicy, Health, HitFx, IdleFx, Collider, ProjectileHitbox, ShaderEffects, SpriteFrameAnimation, EnemyWeaponSockets, WeaponSocket};
+use crate::components::{CapsuleAxis, CollisionShape, DeathFx, Enemy, EnemyBehavior, EnemyMovement, EnemyType, FxPolicy, Health, HitFx, IdleFx, DamageResistances, FrontShield, Collider, ProjectileHitbox, ShaderEffects, SpriteFrameAnimation, EnemyWeaponSockets, WeaponSocket, Difficulty, EnemyShield, ArmorLayout, ArmorPlate, ArmorPlates};
+use bevy::ecs::system::SystemParam;
 use crate::materials::EffectsMaterial;
-use crate::resources::EnemyAssetRegistry;
+use crate::materials::noise::EffectsNoiseTexture;
+use crate::resources::{EnemyAssetRegistry, SelectedDifficulty};
+use super::world::sizes;
 
 fn stable_z_jitter(transform: &Transform) -> f32 {
 	// Tiny deterministic Z offset to stabilize render ordering between overlapping transparent quads.
@@ -240,6 +243,160 @@ fn default_weapon_sockets(metrics: &EnemySpriteMetrics) -> EnemyWeaponSockets {
 	}
 }
 
+/// Manifest-derived hitbox and sockets for an already-spawned enemy (used by manifest hot-reload)
+pub fn enemy_manifest_components(
+	enemy_type: EnemyType,
+	default_size: f32,
+	enemy_assets: &EnemyAssetRegistry,
+) -> (ProjectileHitbox, EnemyWeaponSockets) {
+	let sprite_path = enemy_assets
+		.get(enemy_type)
+		.map(|meta| meta.sprite_path.clone())
+		.unwrap_or_default();
+	let metrics = enemy_sprite_metrics(enemy_type, &sprite_path, default_size, enemy_assets);
+	let weapon_sockets = enemy_weapon_sockets(enemy_type, &sprite_path, enemy_assets)
+		.unwrap_or_else(|| default_weapon_sockets(&metrics));
+	(metrics.projectile_hitbox, weapon_sockets)
+}
+
+/// Resistances for every enemy, plus a front shield for the types that have one
+fn insert_damage_profile(ec: &mut EntityCommands, enemy_type: EnemyType) {
+	ec.insert(DamageResistances::for_enemy_type(enemy_type));
+	if let Some(shield) = FrontShield::for_enemy_type(enemy_type) {
+		ec.insert(shield);
+	}
+}
+
+/// Energy shield (with its bubble sprite) and armor plates for the types that have them
+fn insert_defense_layers(ec: &mut EntityCommands, enemy_type: EnemyType, difficulty: Difficulty, asset_server: &AssetServer) {
+	let core = ec.id();
+
+	if let Some(mut shield) = EnemyShield::for_enemy_type(enemy_type, difficulty) {
+		let bubble_size = Collider::for_enemy_type(enemy_type).radius * 2.6;
+		ec.with_children(|parent| {
+			shield.bubble = Some(parent.spawn((
+				Sprite {
+					image: asset_server.load("particles/shield_shimmer.png"),
+					custom_size: Some(Vec2::splat(bubble_size)),
+					color: Color::srgba(0.4, 0.85, 1.0, 0.0),
+					..default()
+				},
+				Transform::from_xyz(0.0, 0.0, 0.02),
+			)).id());
+		});
+		ec.insert(shield);
+	}
+
+	if let Some(layout) = ArmorLayout::for_enemy_type(enemy_type) {
+		let mut plates = Vec::with_capacity(layout.plates.len());
+		ec.with_children(|parent| {
+			for spec in &layout.plates {
+				plates.push(parent.spawn((
+					Sprite {
+						image: asset_server.load(layout.sprite),
+						custom_size: Some(Vec2::splat(spec.radius * 2.0)),
+						color: layout.tint,
+						..default()
+					},
+					Transform::from_translation(spec.offset.extend(0.01)),
+					ArmorPlate { core },
+					Health::new(spec.health * difficulty.enemy_health_multiplier()),
+					ProjectileHitbox::circle(spec.radius, Vec2::ZERO),
+				)).id());
+			}
+		});
+		ec.insert(ArmorPlates {
+			plates,
+			core_multiplier: layout.core_multiplier,
+		});
+	}
+}
+
+/// Sprite, default size and type for an enemy name as written in level YAML (unknown names become Scouts)
+pub fn enemy_sprite_for(name: &str) -> (&'static str, f32, EnemyType) {
+	match name {
+		"Scout" => ("enemies/scout.png", sizes::SCOUT, EnemyType::Scout),
+		"ScoutSting" => ("enemies/scout_sting.png", sizes::SCOUT_STING, EnemyType::ScoutSting),
+		"Fighter" => ("enemies/fighter.png", sizes::FIGHTER, EnemyType::Fighter),
+		"HeavyGunship" => ("enemies/heavy_gunship.png", sizes::HEAVY_GUNSHIP, EnemyType::HeavyGunship),
+		"Boss" => ("enemies/boss.png", sizes::BOSS, EnemyType::Boss),
+		"Interceptor" => ("enemies/interceptor.png", sizes::INTERCEPTOR, EnemyType::Interceptor),
+		"Drone" => ("enemies/drone.png", sizes::DRONE, EnemyType::Drone),
+		"Bomber" => ("enemies/bomber.png", sizes::BOMBER, EnemyType::Bomber),
+		"Corvette" => ("enemies/corvette.png", sizes::CORVETTE, EnemyType::Corvette),
+		"Drill" => ("enemies/drill/drill_0.png", sizes::DRILL, EnemyType::Drill),
+		"SmallAsteroid" => ("enemies/small_asteroid.png", sizes::SMALL_ASTEROID, EnemyType::SmallAsteroid),
+		"MediumAsteroid" => ("enemies/medium_asteroid.png", sizes::MEDIUM_ASTEROID, EnemyType::MediumAsteroid),
+		"LargeAsteroid" => ("enemies/large_asteroid.png", sizes::LARGE_ASTEROID, EnemyType::LargeAsteroid),
+		"StationDebris" => ("enemies/station_debris.png", sizes::STATION_DEBRIS, EnemyType::StationDebris),
+		"AsteroidTurret" => ("enemies/asteroid_turret.png", sizes::ASTEROID_TURRET, EnemyType::AsteroidTurret),
+		_ => ("enemies/scout.png", sizes::SCOUT, EnemyType::Scout),
+	}
+}
+
+/// The assets enemy spawning needs, so any system (level waves, carriers...) can spawn enemies
+#[derive(SystemParam)]
+pub struct EnemySpawnAssets<'w> {
+	pub asset_server: Res<'w, AssetServer>,
+	pub meshes: ResMut<'w, Assets<Mesh>>,
+	pub materials: ResMut<'w, Assets<EffectsMaterial>>,
+	pub noise_texture: Res<'w, EffectsNoiseTexture>,
+	pub enemy_assets: Res<'w, EnemyAssetRegistry>,
+	pub difficulty: Res<'w, SelectedDifficulty>,
+}
+
+impl EnemySpawnAssets<'_> {
+	/// Spawn the named enemy type driven by `behavior`
+	pub fn spawn_with_behavior(
+		&mut self,
+		commands: &mut Commands,
+		enemy_name: &str,
+		transform: Transform,
+		behavior: EnemyBehavior,
+	) -> Entity {
+		let (sprite_path, size, enemy_type) = enemy_sprite_for(enemy_name);
+		spawn_enemy_with_behavior(
+			commands,
+			&self.asset_server,
+			&mut self.meshes,
+			&mut self.materials,
+			&self.noise_texture.0,
+			&self.enemy_assets,
+			self.difficulty.difficulty,
+			enemy_type,
+			sprite_path,
+			size,
+			transform,
+			behavior,
+		)
+	}
+
+	/// Spawn the named enemy type on a legacy movement pattern
+	pub fn spawn_with_movement(
+		&mut self,
+		commands: &mut Commands,
+		enemy_name: &str,
+		transform: Transform,
+		movement: EnemyMovement,
+	) -> Entity {
+		let (sprite_path, size, enemy_type) = enemy_sprite_for(enemy_name);
+		spawn_enemy_with_movement(
+			commands,
+			&self.asset_server,
+			&mut self.meshes,
+			&mut self.materials,
+			&self.noise_texture.0,
+			&self.enemy_assets,
+			self.difficulty.difficulty,
+			enemy_type,
+			sprite_path,
+			size,
+			transform,
+			movement,
+		)
+	}
+}
+
 pub fn spawn_enemy_with_behavior(
 	commands: &mut Commands,
 	asset_server: &AssetServer,
@@ -247,6 +404,7 @@ pub fn spawn_enemy_with_behavior(
 	materials: &mut Assets<EffectsMaterial>,
 	noise_texture: &Handle<Image>,
 	enemy_assets: &EnemyAssetRegistry,
+	difficulty: Difficulty,
 	enemy_type: EnemyType,
 	sprite_path: &str,
 	size: f32,
@@ -281,13 +439,15 @@ pub fn spawn_enemy_with_behavior(
 				transform,
 				Enemy { enemy_type },
 				behavior,
-				Health::for_enemy_type(enemy_type),
+				Health::for_enemy_type(enemy_type, difficulty),
 				Collider::for_enemy_type(enemy_type),
 				metrics.projectile_hitbox,
 				fx_policy,
 			));
 
 			ec.insert(weapon_sockets.clone());
+			insert_damage_profile(&mut ec, enemy_type);
+			insert_defense_layers(&mut ec, enemy_type, difficulty, asset_server);
 
 			if let Some(anim) = anim {
 				ec.insert(anim);
@@ -325,7 +485,7 @@ pub fn spawn_enemy_with_behavior(
 				transform,
 				Enemy { enemy_type },
 				behavior,
-				Health::for_enemy_type(enemy_type),
+				Health::for_enemy_type(enemy_type, difficulty),
 				Collider::for_enemy_type(enemy_type),
 				metrics.projectile_hitbox,
 				ShaderEffects::default(),
@@ -333,6 +493,8 @@ pub fn spawn_enemy_with_behavior(
 			));
 
 			ec.insert(weapon_sockets.clone());
+			insert_damage_profile(&mut ec, enemy_type);
+			insert_defense_layers(&mut ec, enemy_type, difficulty, asset_server);
 
 			ec.id()
 		}
@@ -346,6 +508,7 @@ pub fn spawn_enemy_with_movement(
 	materials: &mut Assets<EffectsMaterial>,
 	noise_texture: &Handle<Image>,
 	enemy_assets: &EnemyAssetRegistry,
+	difficulty: Difficulty,
 	enemy_type: EnemyType,
 	sprite_path: &str,
 	size: f32,
@@ -380,13 +543,15 @@ pub fn spawn_enemy_with_movement(
 				transform,
 				Enemy { enemy_type },
 				movement,
-				Health::for_enemy_type(enemy_type),
+				Health::for_enemy_type(enemy_type, difficulty),
 				Collider::for_enemy_type(enemy_type),
 				metrics.projectile_hitbox,
 				fx_policy,
 			));
 
 			ec.insert(weapon_sockets.clone());
+			insert_damage_profile(&mut ec, enemy_type);
+			insert_defense_layers(&mut ec, enemy_type, difficulty, asset_server);
 
 			if let Some(anim) = anim {
 				ec.insert(anim);
@@ -422,7 +587,7 @@ pub fn spawn_enemy_with_movement(
 				transform,
 				Enemy { enemy_type },
 				movement,
-				Health::for_enemy_type(enemy_type),
+				Health::for_enemy_type(enemy_type, difficulty),
 				Collider::for_enemy_type(enemy_type),
 				metrics.projectile_hitbox,
 				ShaderEffects::default(),
@@ -430,6 +595,8 @@ pub fn spawn_enemy_with_movement(
 			));
 
 			ec.insert(weapon_sockets.clone());
+			insert_damage_profile(&mut ec, enemy_type);
+			insert_defense_layers(&mut ec, enemy_type, difficulty, asset_server);
 
 			ec.id()
 		}
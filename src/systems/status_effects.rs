@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::{
+	Enemy, Dying, DamageOverTime, DotKind, DotStack, DotStacks, KnockbackVelocity, EnemyHitEvent, Particle,
+};
+
+/// Stacks of one kind an enemy can carry; a new stack past this refreshes the oldest
+const MAX_DOT_STACKS: usize = 5;
+const DOT_TICK_SECS: f32 = 0.5;
+/// Share of an EMP'd enemy's normal fire rate
+pub const EMP_FIRE_RATE: f32 = 0.5;
+/// Knockback push lost per second (exponential)
+const KNOCKBACK_DAMPING: f32 = 6.0;
+
+/// Fresh stack set for an enemy's first burn/EMP hit
+pub fn new_dot_stacks() -> DotStacks {
+	DotStacks {
+		stacks: Vec::new(),
+		tick: Timer::from_seconds(DOT_TICK_SECS, TimerMode::Repeating),
+	}
+}
+
+/// Add one stack, refreshing the shortest-lived stack of that kind once at the cap
+pub fn add_dot_stack(stacks: &mut DotStacks, dot: DamageOverTime) {
+	let same_kind = stacks.stacks.iter().filter(|stack| stack.kind == dot.kind).count();
+	if same_kind >= MAX_DOT_STACKS {
+		if let Some(oldest) = stacks.stacks.iter_mut()
+			.filter(|stack| stack.kind == dot.kind)
+			.min_by(|a, b| a.remaining.total_cmp(&b.remaining))
+		{
+			oldest.damage_per_sec = oldest.damage_per_sec.max(dot.damage_per_sec);
+			oldest.remaining = dot.duration;
+		}
+		return;
+	}
+	stacks.stacks.push(DotStack {
+		kind: dot.kind,
+		damage_per_sec: dot.damage_per_sec,
+		remaining: dot.duration,
+	});
+}
+
+/// Deal burn/EMP damage in periodic silent hits (one per kind), with a few sparks so the stacks read
+#[allow(clippy::type_complexity)]
+pub fn tick_damage_over_time(
+	mut commands: Commands,
+	asset_server: Res<AssetServer>,
+	mut query: Query<(Entity, &Transform, &mut DotStacks), (With<Enemy>, Without<Dying>)>,
+	mut hit_events: EventWriter<EnemyHitEvent>,
+	time: Res<Time>,
+) {
+	let mut rng = rand::thread_rng();
+
+	for (entity, transform, mut dots) in query.iter_mut() {
+		dots.tick.tick(time.delta());
+		if !dots.tick.just_finished() {
+			continue;
+		}
+
+		for kind in [DotKind::Burn, DotKind::Emp] {
+			if !dots.has(kind) {
+				continue;
+			}
+			let damage: f32 = dots.stacks.iter()
+				.filter(|stack| stack.kind == kind)
+				.map(|stack| stack.damage_per_sec * DOT_TICK_SECS.min(stack.remaining))
+				.sum();
+			if damage > 0.0 {
+				hit_events.send(EnemyHitEvent {
+					enemy: entity,
+					damage,
+					hit_sound: None,
+					dot: None,
+					knockback: Vec2::ZERO,
+					damage_type: kind.damage_type(),
+					source: None,
+				});
+			}
+
+			let sprite = match kind {
+				DotKind::Burn => "particles/flame_orange.png",
+				DotKind::Emp => "particles/electric_arc.png",
+			};
+			for _ in 0..2 {
+				commands.spawn((
+					Sprite {
+						image: asset_server.load(sprite),
+						custom_size: Some(Vec2::splat(12.0)),
+						..default()
+					},
+					Transform::from_translation(transform.translation + Vec3::new(
+						rng.gen_range(-15.0..15.0),
+						rng.gen_range(-15.0..15.0),
+						0.1,
+					)),
+					Particle {
+						lifetime: Timer::from_seconds(0.3, TimerMode::Once),
+						velocity: Vec2::new(rng.gen_range(-20.0..20.0), rng.gen_range(20.0..60.0)),
+					},
+				));
+			}
+		}
+
+		for stack in dots.stacks.iter_mut() {
+			stack.remaining -= DOT_TICK_SECS;
+		}
+		dots.stacks.retain(|stack| stack.remaining > 0.0);
+		if dots.stacks.is_empty() {
+			commands.entity(entity).remove::<DotStacks>();
+		}
+	}
+}
+
+/// Drift knocked-back enemies on top of their normal movement until the push dies out
+pub fn apply_enemy_knockback(
+	mut commands: Commands,
+	mut query: Query<(Entity, &mut Transform, &mut KnockbackVelocity), Without<Dying>>,
+	time: Res<Time>,
+) {
+	for (entity, mut transform, mut knockback) in query.iter_mut() {
+		transform.translation += (knockback.0 * time.delta_secs()).extend(0.0);
+		knockback.0 *= (-KNOCKBACK_DAMPING * time.delta_secs()).exp();
+		if knockback.0.length_squared() < 1.0 {
+			commands.entity(entity).remove::<KnockbackVelocity>();
+		}
+	}
+}
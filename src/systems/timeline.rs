@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+use crate::components::{
+	Enemy, EnemyProjectile, Projectile, WeaponPickup, PowerUp, DistanceLocked, ParallaxEntity,
+	OrbitalEntity, FormationRegistry, BombPickup, EvolutionPickup, ScoreItem,
+};
+use crate::systems::level::{CurrentLevel, DoodadEntity, MusicState};
+use crate::systems::level_tally::{LevelTallyPanel, LevelTallyState};
+
+/// Debug step for F6/F7 jumps (GU)
+const JUMP_STEP: f32 = 1000.0;
+
+/// Request to move the level to an arbitrary distance at runtime (forward or back)
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TimelineJumpEvent {
+	pub distance: f32,
+}
+
+/// F6/F7: jump back/forward 1000 GU. Shift+F6/F7: previous/next phase start.
+pub fn timeline_debug_controls(
+	keyboard: Res<ButtonInput<KeyCode>>,
+	level: Option<Res<CurrentLevel>>,
+	mut jump_events: EventWriter<TimelineJumpEvent>,
+) {
+	let back = keyboard.just_pressed(KeyCode::F6);
+	let forward = keyboard.just_pressed(KeyCode::F7);
+	if !back && !forward {
+		return;
+	}
+	let Some(level) = level else { return };
+
+	let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+	let current = level.distance;
+
+	let target = if shift {
+		let mut starts: Vec<f32> = level.data.phases.iter().map(|p| p.start_distance).collect();
+		starts.sort_by(|a, b| a.total_cmp(b));
+		if forward {
+			starts.into_iter().find(|&start| start > current + 1.0)
+		} else {
+			// Small grace so a press just after a phase starts goes to the one before
+			starts.into_iter().rev().find(|&start| start < current - 50.0).or(Some(0.0))
+		}
+	} else if forward {
+		Some(current + JUMP_STEP)
+	} else {
+		Some(current - JUMP_STEP)
+	};
+
+	if let Some(distance) = target {
+		jump_events.send(TimelineJumpEvent { distance });
+	}
+}
+
+/// Clear everything on screen and rebuild level state at the requested distance
+#[allow(clippy::type_complexity)]
+pub fn handle_timeline_jump(
+	mut commands: Commands,
+	mut jump_events: EventReader<TimelineJumpEvent>,
+	level: Option<ResMut<CurrentLevel>>,
+	mut music_state: ResMut<MusicState>,
+	mut formation_registry: ResMut<FormationRegistry>,
+	mut tally_state: ResMut<LevelTallyState>,
+	clear_query: Query<Entity, (
+		Or<(
+			With<Enemy>,
+			With<EnemyProjectile>,
+			With<Projectile>,
+			With<WeaponPickup>,
+			With<PowerUp>,
+			With<BombPickup>,
+			With<EvolutionPickup>,
+			With<ScoreItem>,
+			With<DoodadEntity>,
+			With<DistanceLocked>,
+			With<ParallaxEntity>,
+			With<LevelTallyPanel>,
+		)>,
+		Without<OrbitalEntity>,
+	)>,
+) {
+	// Only the last request in a frame matters
+	let Some(event) = jump_events.read().last().copied() else { return };
+	let Some(mut level) = level else { return };
+
+	let target = event.distance.clamp(0.0, level.data.total_distance);
+
+	let mut cleared = 0;
+	for entity in clear_query.iter() {
+		commands.entity(entity).despawn_recursive();
+		cleared += 1;
+	}
+	formation_registry.formations.clear();
+	formation_registry.plans.clear();
+	// Let the end-of-level tally show again when the end is reached
+	tally_state.shown = false;
+
+	// Rebuilds processed lists + spawned_enemies for the new distance;
+	// DistanceLocked structures get respawned by process_doodads at the right Y
+	*level = level.jumped_to(target);
+
+	// Force process_phases to (re)start the music for whatever phase we landed in
+	music_state.current_track = None;
+
+	let phase_name = level.get_current_phase()
+		.map(|p| p.name.clone())
+		.unwrap_or_else(|| "Unknown".to_string());
+	info!("⏩ Timeline jump to {:.0} GU ({}) - cleared {} entities", target, phase_name, cleared);
+}
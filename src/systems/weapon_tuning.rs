@@ -0,0 +1,249 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use crate::components::{Weapon, WeaponType, WeaponEvolution, DamageOverTime};
+use crate::systems::lightning::LightningTuning;
+
+const WEAPON_TUNING_PATH: &str = "assets/weapons/weapon_tuning.yaml";
+/// Shipped copy of the tuning file, used if the file on disk is missing or invalid at startup
+const BUILTIN_WEAPON_TUNING: &str = include_str!("../../assets/weapons/weapon_tuning.yaml");
+
+/// Projectile modifiers a weapon level or evolution grants (all optional in the file)
+#[derive(Deserialize, Clone, Copy, Default)]
+#[serde(default)]
+pub struct ProjectileModifiers {
+	/// Extra enemies each projectile passes through
+	pub pierce: u8,
+	/// Times each projectile bounces to a nearby enemy
+	pub ricochet: u8,
+	/// Burn or EMP stack left on each enemy hit
+	pub dot: Option<DamageOverTime>,
+	/// Push given to enemies hit
+	pub knockback: f32,
+}
+
+impl ProjectileModifiers {
+	fn validate(&self, name: &str, errors: &mut Vec<String>) {
+		if let Some(dot) = self.dot {
+			if dot.damage_per_sec < 0.0 || dot.duration <= 0.0 {
+				errors.push(format!("{}: dot needs damage_per_sec >= 0 and duration > 0", name));
+			}
+		}
+		if self.knockback < 0.0 {
+			errors.push(format!("{}: knockback must be >= 0", name));
+		}
+	}
+}
+
+/// Stats for one weapon level
+#[derive(Deserialize, Clone, Copy)]
+pub struct WeaponLevelStats {
+	pub level: u8,
+	pub damage: f32,
+	pub cooldown: f32,
+	#[serde(flatten)]
+	pub modifiers: ProjectileModifiers,
+}
+
+/// One weapon's entry in the tuning file
+#[derive(Deserialize, Clone)]
+pub struct WeaponConfig {
+	pub projectile_speed: f32,
+	/// Listed in order; validation guarantees there are no gaps
+	pub levels: Vec<WeaponLevelStats>,
+}
+
+impl WeaponConfig {
+	pub fn max_level(&self) -> u8 {
+		self.levels.last().map_or(0, |stats| stats.level)
+	}
+
+	/// Stats for `level`, clamped to the levels the weapon has
+	pub fn stats(&self, level: u8) -> WeaponLevelStats {
+		*self.levels.iter().rev()
+			.find(|stats| stats.level <= level)
+			.unwrap_or(&self.levels[0])
+	}
+}
+
+/// Stats for an evolved weapon; replace its top level's stats
+#[derive(Deserialize, Clone, Copy)]
+pub struct EvolutionStats {
+	pub damage: f32,
+	pub cooldown: f32,
+	#[serde(flatten)]
+	pub modifiers: ProjectileModifiers,
+}
+
+/// Per-level weapon stats from `assets/weapons/weapon_tuning.yaml` (hot-reloaded)
+#[derive(Resource, Deserialize, Clone)]
+pub struct WeaponTuning {
+	weapons: HashMap<WeaponType, WeaponConfig>,
+	evolutions: HashMap<WeaponEvolution, EvolutionStats>,
+	pub lightning: LightningTuning,
+}
+
+impl WeaponTuning {
+	/// Load and validate the tuning file, falling back to the shipped copy if it's unusable
+	pub fn load_from_disk() -> Self {
+		let loaded = fs::read_to_string(tuning_path())
+			.map_err(|err| vec![err.to_string()])
+			.and_then(|yaml| Self::parse(&yaml));
+		match loaded {
+			Ok(tuning) => {
+				info!("✓ Loaded weapon tuning: {} weapons", tuning.weapons.len());
+				tuning
+			}
+			Err(errors) => {
+				report_errors("Weapon tuning unusable, using built-in defaults", &errors);
+				Self::parse(BUILTIN_WEAPON_TUNING).expect("built-in weapon tuning must be valid")
+			}
+		}
+	}
+
+	/// Re-read the tuning file in place. Keeps the current values if the file is
+	/// missing, fails to parse or fails validation (e.g. saved mid-edit).
+	pub fn reload(&mut self) -> bool {
+		let loaded = fs::read_to_string(tuning_path())
+			.map_err(|err| vec![err.to_string()])
+			.and_then(|yaml| Self::parse(&yaml));
+		match loaded {
+			Ok(tuning) => {
+				*self = tuning;
+				info!("🔄 Reloaded weapon tuning");
+				true
+			}
+			Err(errors) => {
+				report_errors("Weapon tuning rejected, keeping previous", &errors);
+				false
+			}
+		}
+	}
+
+	/// Last modification time of the tuning file (for hot-reload polling)
+	pub fn file_modified() -> Option<SystemTime> {
+		fs::metadata(tuning_path()).and_then(|meta| meta.modified()).ok()
+	}
+
+	fn parse(yaml: &str) -> Result<Self, Vec<String>> {
+		let tuning: Self = serde_yaml::from_str(yaml).map_err(|err| vec![err.to_string()])?;
+		let errors = tuning.validate();
+		if errors.is_empty() { Ok(tuning) } else { Err(errors) }
+	}
+
+	fn validate(&self) -> Vec<String> {
+		let mut errors = Vec::new();
+
+		for weapon_type in WeaponType::ALL {
+			let name = format!("{:?}", weapon_type);
+			let Some(config) = self.weapons.get(&weapon_type) else {
+				errors.push(format!("{}: missing from weapons", name));
+				continue;
+			};
+			if config.levels.is_empty() {
+				errors.push(format!("{}: no levels", name));
+				continue;
+			}
+
+			// The blaster is the unupgradeable fallback and lives at level 0
+			let expected = if weapon_type == WeaponType::BasicBlaster {
+				0..=0
+			} else {
+				1..=config.max_level().max(1)
+			};
+			let levels: Vec<u8> = config.levels.iter().map(|stats| stats.level).collect();
+			check_levels(&name, &levels, expected, &mut errors);
+
+			if config.projectile_speed <= 0.0 {
+				errors.push(format!("{}: projectile_speed must be positive", name));
+			}
+			for stats in &config.levels {
+				if stats.damage < 0.0 || stats.cooldown <= 0.0 {
+					errors.push(format!("{} level {}: damage must be >= 0 and cooldown > 0", name, stats.level));
+				}
+				stats.modifiers.validate(&format!("{} level {}", name, stats.level), &mut errors);
+			}
+		}
+
+		for evolution in WeaponEvolution::ALL {
+			match self.evolutions.get(&evolution) {
+				None => errors.push(format!("{:?}: missing from evolutions", evolution)),
+				Some(stats) => {
+					if stats.damage < 0.0 || stats.cooldown <= 0.0 {
+						errors.push(format!("{:?}: damage must be >= 0 and cooldown > 0", evolution));
+					}
+					stats.modifiers.validate(&format!("{:?}", evolution), &mut errors);
+				}
+			}
+		}
+
+		let lightning_max = self.weapons.get(&WeaponType::LightningChain).map_or(0, |config| config.max_level());
+		self.lightning.validate(lightning_max, &mut errors);
+
+		errors
+	}
+
+	pub fn weapon(&self, weapon_type: WeaponType) -> &WeaponConfig {
+		&self.weapons[&weapon_type]
+	}
+
+	/// Stats the weapon fires with: its level's, or its evolution's once evolved
+	pub fn stats(&self, weapon: &Weapon) -> WeaponLevelStats {
+		match weapon.evolution {
+			Some(evolution) => {
+				let stats = self.evolutions[&evolution];
+				WeaponLevelStats {
+					level: weapon.level,
+					damage: stats.damage,
+					cooldown: stats.cooldown,
+					modifiers: stats.modifiers,
+				}
+			}
+			None => self.weapon(weapon.weapon_type).stats(weapon.level),
+		}
+	}
+
+	/// Fresh weapon at `level` with the cooldown for that level
+	pub fn new_weapon(&self, weapon_type: WeaponType, level: u8) -> Weapon {
+		Weapon::new(weapon_type, level, self.weapon(weapon_type).stats(level).cooldown)
+	}
+}
+
+/// Report missing, unexpected, duplicate or out-of-order levels in one table
+pub fn check_levels(name: &str, levels: &[u8], expected: RangeInclusive<u8>, errors: &mut Vec<String>) {
+	let missing: Vec<u8> = expected.clone().filter(|level| !levels.contains(level)).collect();
+	if !missing.is_empty() {
+		errors.push(format!("{}: missing level(s) {:?}", name, missing));
+	}
+	let unexpected: Vec<u8> = levels.iter().copied().filter(|level| !expected.contains(level)).collect();
+	if !unexpected.is_empty() {
+		errors.push(format!("{}: unexpected level(s) {:?} (expected {}-{})",
+			name, unexpected, expected.start(), expected.end()));
+	}
+	if missing.is_empty() && unexpected.is_empty() && !levels.iter().copied().eq(expected) {
+		errors.push(format!("{}: levels must be listed once each, in order", name));
+	}
+}
+
+fn report_errors(context: &str, errors: &[String]) {
+	error!("{} ({}):", context, WEAPON_TUNING_PATH);
+	for err in errors {
+		error!("  - {}", err);
+	}
+}
+
+fn tuning_path() -> PathBuf {
+	let path = PathBuf::from(WEAPON_TUNING_PATH);
+	if path.exists() {
+		return path;
+	}
+	let exe_dir = std::env::current_exe()
+		.ok()
+		.and_then(|path| path.parent().map(|p| p.to_path_buf()))
+		.unwrap_or_default();
+	exe_dir.join(WEAPON_TUNING_PATH)
+}
@@ -1,42 +1,65 @@
 use bevy::prelude::*;
-use crate::components::{Player, Weapon, WeaponSwitchEvent, WeaponUpgradeEvent, PlayerHitEvent, WeaponType, PlayerDefenses};
+use crate::systems::audio::PlaySfxEvent;
+use crate::components::{Player, PrimaryPlayer, PlayerInput, SubWeapon, Weapon, WeaponSwitchEvent, WeaponUpgradeEvent, PlayerHitEvent, WeaponType, PlayerDefenses, EvolutionOfferEvent};
+use crate::systems::evolution::can_evolve;
+use crate::systems::weapon_tuning::WeaponTuning;
 
+/// Weapon pickups replace the primary at level 1; the sub-weapon is left alone
 pub fn handle_weapon_switch(
 	mut weapon_switch_events: EventReader<WeaponSwitchEvent>,
 	mut query: Query<&mut Weapon, With<Player>>,
+	tuning: Res<WeaponTuning>,
 ) {
 	for event in weapon_switch_events.read() {
-		if let Ok(mut weapon) = query.get_single_mut() {
-			weapon.weapon_type = event.new_weapon;
-			weapon.level = 1;
-
-			let config = weapon.weapon_type.config();
-			weapon.fire_cooldown = Timer::from_seconds(
-				config.base_cooldown,
-				TimerMode::Repeating
-			);
+		let Ok(mut weapon) = query.get_mut(event.player) else { continue };
+		*weapon = tuning.new_weapon(event.new_weapon, 1);
+		info!("Switched to {:?} (Level 1)", weapon.weapon_type);
+	}
+}
 
-			info!("Switched to {:?} (Level 1)", weapon.weapon_type);
+/// Swap button (V / numpad 9 / gamepad North): exchange primary and sub-weapon, keeping both levels
+pub fn swap_weapons(
+	mut query: Query<(&PlayerInput, &mut Weapon, &mut SubWeapon), With<Player>>,
+	mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+	for (input, mut weapon, mut sub_weapon) in query.iter_mut() {
+		if !input.swap {
+			continue;
 		}
+		std::mem::swap(&mut *weapon, &mut sub_weapon.0);
+		sfx_events.send(PlaySfxEvent::simple("sounds/menu_select.ogg", 0.5, 60, 0.1));
+		info!("🔀 Swapped weapons: {:?} L{} / sub {:?} L{}",
+			weapon.weapon_type, weapon.level, sub_weapon.0.weapon_type, sub_weapon.0.level);
 	}
 }
 
+/// Power-ups level the primary; one collected at max level offers an evolution
 pub fn handle_weapon_upgrade(
 	mut weapon_upgrade_events: EventReader<WeaponUpgradeEvent>,
 	mut query: Query<&mut Weapon, With<Player>>,
+	tuning: Res<WeaponTuning>,
+	mut offer_events: EventWriter<EvolutionOfferEvent>,
 ) {
 	for event in weapon_upgrade_events.read() {
-		if let Ok(mut weapon) = query.get_single_mut() {
-			let config = weapon.weapon_type.config();
+		if let Ok(mut weapon) = query.get_mut(event.player) {
+			let max_level = tuning.weapon(weapon.weapon_type).max_level();
 
 			if weapon.weapon_type == WeaponType::BasicBlaster {
 				continue;
 			}
 
+			if event.level_change > 0 && can_evolve(&weapon, &tuning) {
+				offer_events.send(EvolutionOfferEvent { player: event.player });
+				continue;
+			}
+
 			let new_level = (weapon.level as i8 + event.level_change)
-				.clamp(1, config.max_level as i8) as u8;
+				.clamp(1, max_level as i8) as u8;
 
 			if new_level != weapon.level {
+				if new_level < weapon.level {
+					weapon.evolution = None;
+				}
 				weapon.level = new_level;
 				info!("{:?} upgraded to Level {}", weapon.weapon_type, weapon.level);
 			}
@@ -46,24 +69,22 @@ pub fn handle_weapon_upgrade(
 
 pub fn handle_player_hit(
 	mut player_hit_events: EventReader<PlayerHitEvent>,
-	mut weapon_query: Query<&mut Weapon, With<Player>>,
-	defenses_query: Query<&PlayerDefenses, With<Player>>,
+	mut player_query: Query<(&mut Weapon, &PlayerDefenses), With<Player>>,
 ) {
-	for _ in player_hit_events.read() {
-		if let Ok(mut weapon) = weapon_query.get_single_mut() {
+	for event in player_hit_events.read() {
+		if let Ok((mut weapon, defenses)) = player_query.get_mut(event.player) {
 			if weapon.weapon_type == WeaponType::BasicBlaster {
 				continue;
 			}
 
 			// Only lose weapon levels if all shields are down
-			let all_shields_down = if let Ok(defenses) = defenses_query.get_single() {
-				defenses.shield2 <= 0.0 && defenses.shield1 <= 0.0
-			} else {
-				true // Default to allowing weapon loss if we can't check shields
-			};
+			let all_shields_down = defenses.shield2 <= 0.0 && defenses.shield1 <= 0.0;
 
 			if all_shields_down {
 				weapon.level = weapon.level.saturating_sub(2);
+				if weapon.evolution.take().is_some() {
+					info!("🧬 {:?} lost its evolution", weapon.weapon_type);
+				}
 
 				// Keep weapon type, just reset to level 1 instead of reverting to BasicBlaster
 				if weapon.level == 0 {
@@ -79,44 +100,48 @@ pub fn handle_player_hit(
 	}
 }
 
+/// Debug weapon keys act on player one
 pub fn debug_weapon_controls(
 	keyboard_input: Res<ButtonInput<KeyCode>>,
+	primary_query: Query<Entity, With<PrimaryPlayer>>,
 	mut weapon_upgrade_events: EventWriter<WeaponUpgradeEvent>,
 	mut weapon_switch_events: EventWriter<WeaponSwitchEvent>,
 ) {
+	let Ok(player) = primary_query.get_single() else { return };
+
 	if keyboard_input.just_pressed(KeyCode::Equal) || keyboard_input.just_pressed(KeyCode::NumpadAdd) {
-		weapon_upgrade_events.send(WeaponUpgradeEvent { level_change: 1 });
+		weapon_upgrade_events.send(WeaponUpgradeEvent { player, level_change: 1 });
 	}
 
 	if keyboard_input.just_pressed(KeyCode::Minus) || keyboard_input.just_pressed(KeyCode::NumpadSubtract) {
-		weapon_upgrade_events.send(WeaponUpgradeEvent { level_change: -1 });
+		weapon_upgrade_events.send(WeaponUpgradeEvent { player, level_change: -1 });
 	}
 
 	if keyboard_input.just_pressed(KeyCode::Digit1) {
-		weapon_switch_events.send(WeaponSwitchEvent { new_weapon: WeaponType::BasicBlaster });
+		weapon_switch_events.send(WeaponSwitchEvent { player, new_weapon: WeaponType::BasicBlaster });
 	}
 
 	if keyboard_input.just_pressed(KeyCode::Digit2) {
-		weapon_switch_events.send(WeaponSwitchEvent { new_weapon: WeaponType::PlasmaCannon });
+		weapon_switch_events.send(WeaponSwitchEvent { player, new_weapon: WeaponType::PlasmaCannon });
 	}
 
 	if keyboard_input.just_pressed(KeyCode::Digit3) {
-		weapon_switch_events.send(WeaponSwitchEvent { new_weapon: WeaponType::WaveGun });
+		weapon_switch_events.send(WeaponSwitchEvent { player, new_weapon: WeaponType::WaveGun });
 	}
 
 	if keyboard_input.just_pressed(KeyCode::Digit4) {
-		weapon_switch_events.send(WeaponSwitchEvent { new_weapon: WeaponType::SpreadShot });
+		weapon_switch_events.send(WeaponSwitchEvent { player, new_weapon: WeaponType::SpreadShot });
 	}
 
 	if keyboard_input.just_pressed(KeyCode::Digit5) {
-		weapon_switch_events.send(WeaponSwitchEvent { new_weapon: WeaponType::MissilePods });
+		weapon_switch_events.send(WeaponSwitchEvent { player, new_weapon: WeaponType::MissilePods });
 	}
 
 	if keyboard_input.just_pressed(KeyCode::Digit6) {
-		weapon_switch_events.send(WeaponSwitchEvent { new_weapon: WeaponType::LaserArray });
+		weapon_switch_events.send(WeaponSwitchEvent { player, new_weapon: WeaponType::LaserArray });
 	}
 
 	if keyboard_input.just_pressed(KeyCode::Digit7) {
-		weapon_switch_events.send(WeaponSwitchEvent { new_weapon: WeaponType::OrbitalDefense });
+		weapon_switch_events.send(WeaponSwitchEvent { player, new_weapon: WeaponType::OrbitalDefense });
 	}
 }
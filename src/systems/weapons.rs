@@ -1,32 +1,66 @@
 use bevy::prelude::*;
 use rand::Rng;
-use crate::components::{Player, Weapon, Projectile, SineMotion, WeaponType, Particle, HomingProjectile, OrbitalEntity, Enemy, AngledShot, ChargeMeter, Health, Collider, EnemyHitEvent};
+use crate::components::{Player, Weapon, Projectile, SineMotion, WeaponType, Particle, HomingProjectile, OrbitalEntity, Enemy, AngledShot, ChargeMeter, Health, PlayerInput, SubWeapon, Collider, EnemyHitEvent, FocusState, WeaponEvolution, SplashDamage, EvolutionBeam,
+	Pierce, Ricochet, ProjectileHits, Knockback};
+use crate::materials::ProjectileMaterialHandles;
 use super::world::{HALF_WORLD_HEIGHT};
-use super::lightning;
+use super::lightning::{self, ENHANCED_MODE_LEVEL};
+use super::weapon_tuning::{WeaponTuning, WeaponConfig, WeaponLevelStats, ProjectileModifiers};
 use std::f32::consts::{PI, FRAC_PI_2};
 use crate::systems::audio::PlaySfxEvent;
 
 const PROJECTILE_Z: f32 = 0.5;
 const PROJECTILE_LIFETIME: f32 = 3.0;
-
+/// Spread/amplitude multiplier for SpreadShot and WaveGun while focused
+const FOCUS_SPREAD_SCALE: f32 = 0.35;
+/// Sub-weapons hit softer than the same weapon in the primary slot
+const SUB_WEAPON_DAMAGE_MULT: f32 = 0.6;
+const SUB_WEAPON_VOLUME_MULT: f32 = 0.6;
+/// Sub-weapon volleys leave from slightly behind the nose so the two streams read apart
+const SUB_WEAPON_OFFSET: Vec3 = Vec3::new(0.0, -20.0, 0.0);
+/// How far a ricocheting projectile looks for its next enemy
+const RICOCHET_RANGE: f32 = 350.0;
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn fire_weapons(
-	keyboard_input: Res<ButtonInput<KeyCode>>,
 	asset_server: Res<AssetServer>,
 	mut commands: Commands,
-	mut query: Query<(&Transform, &mut Weapon), With<Player>>,
+	mut query: Query<(&Transform, &mut Weapon, Option<&mut SubWeapon>, &PlayerInput, &ChargeMeter, Option<&FocusState>), With<Player>>,
 	time: Res<Time>,
-	charge_meter: Res<ChargeMeter>,
 	enemies: Query<(Entity, &Transform, &Health, &Collider), With<Enemy>>,
 	mut hit_events: EventWriter<EnemyHitEvent>,
 	mut sfx_events: EventWriter<PlaySfxEvent>,
+	tuning: Res<WeaponTuning>,
+	projectile_materials: Res<ProjectileMaterialHandles>,
 ) {
-	for (transform, mut weapon) in query.iter_mut() {
-		// Lightning (level 8+) fires on release via pending_fire_tier
-		if weapon.weapon_type == WeaponType::LightningChain && weapon.level >= 8 {
+	for (transform, mut weapon, sub_weapon, input, charge_meter, focus) in query.iter_mut() {
+		let spread_scale = if focus.is_some_and(|f| f.active) { FOCUS_SPREAD_SCALE } else { 1.0 };
+		let spawn_pos = transform.translation + Vec3::new(0.0, 55.0, 0.0);
+
+		// Sub-weapon runs on its own cooldown whenever fire is held
+		if let Some(mut sub_weapon) = sub_weapon {
+			if input.fire {
+				let sub = &mut sub_weapon.0;
+				sub.fire_cooldown.tick(time.delta());
+				if sub.fire_cooldown.finished() {
+					let stats = apply_weapon_cooldown(sub, &tuning);
+					let shot = WeaponShot {
+						spawn_pos: spawn_pos + SUB_WEAPON_OFFSET,
+						damage: stats.damage * SUB_WEAPON_DAMAGE_MULT,
+						modifiers: stats.modifiers,
+						spread_scale,
+						volume_scale: SUB_WEAPON_VOLUME_MULT,
+					};
+					fire_weapon_shot(&mut commands, &asset_server, sub, shot, &enemies, &mut hit_events, &mut sfx_events, &tuning, &projectile_materials);
+					sub.fire_cooldown.reset();
+				}
+			}
+		}
+
+		// Lightning in enhanced mode fires on release via pending_fire_tier
+		if weapon.weapon_type == WeaponType::LightningChain && weapon.level >= ENHANCED_MODE_LEVEL {
 			if let Some(tier) = charge_meter.pending_fire_tier {
-				let config = weapon.weapon_type.config();
-				let damage = config.base_damage + (weapon.level as f32 * config.damage_per_level);
-				let spawn_pos = transform.translation + Vec3::new(0.0, 55.0, 0.0);
+				let damage = tuning.stats(&weapon).damage;
 
 				lightning::fire_lightning_weapon(
 					&mut commands,
@@ -36,109 +70,164 @@ pub fn fire_weapons(
 					tier,
 					&enemies,
 					&mut hit_events,
+					&tuning.lightning,
 				);
 			}
-			continue; // Don't process normal fire logic for level 8+ lightning
+			continue; // Don't process normal fire logic for enhanced lightning
 		}
 
-		// All other weapons fire while Space is held
-		if !keyboard_input.pressed(KeyCode::Space) {
+		// All other weapons fire while the fire button is held
+		if !input.fire {
 			continue;
 		}
 
 		weapon.fire_cooldown.tick(time.delta());
 
 		if weapon.fire_cooldown.finished() {
-			let config = weapon.weapon_type.config();
-
-			let cooldown = config.base_cooldown -
-				(weapon.level as f32 * config.cooldown_reduction_per_level);
-			let damage = config.base_damage +
-				(weapon.level as f32 * config.damage_per_level);
-
-			weapon.fire_cooldown.set_duration(
-				std::time::Duration::from_secs_f32(cooldown.max(0.05))
-			);
-
-			let spawn_pos = transform.translation + Vec3::new(0.0, 55.0, 0.0);
-
-			match weapon.weapon_type {
-				WeaponType::BasicBlaster => {
-					spawn_basic_projectile(&mut commands, &asset_server, spawn_pos, &weapon, &config, damage);
-				},
-				WeaponType::PlasmaCannon => {
-					spawn_plasma_projectile(&mut commands, &asset_server, spawn_pos, &weapon, &config, damage);
-				},
-				WeaponType::WaveGun => {
-					spawn_wave_projectile(&mut commands, &asset_server, spawn_pos, &weapon, &config, damage);
-				},
-				WeaponType::SpreadShot => {
-					spawn_spread_projectiles(&mut commands, &asset_server, spawn_pos, &weapon, &config, damage);
-				},
-				WeaponType::MissilePods => {
-					spawn_missile_projectiles(&mut commands, &asset_server, spawn_pos, &weapon, &config, damage);
-				},
-				WeaponType::LaserArray => {
-					spawn_laser_beams(&mut commands, &asset_server, spawn_pos, &weapon, &config, damage);
-				},
-				WeaponType::OrbitalDefense => {
-					// Orbital defense doesn't fire projectiles traditionally
-					// It spawns/maintains orbs that are handled separately
-				},
-				WeaponType::LightningChain => {
-					// Levels 1-7: Fire on press with default tier 0.4
-					lightning::fire_lightning_weapon(
-						&mut commands,
-						spawn_pos,
-						&weapon,
-						damage,
-						0.4, // Default tier for low levels
-						&enemies,
-						&mut hit_events,
-					);
-				},
-			}
-
-			spawn_muzzle_flash(&mut commands, &asset_server, spawn_pos, weapon.weapon_type);
-
-			// Lightning has its own audio handling in lightning.rs
-			if weapon.weapon_type != WeaponType::LightningChain {
-				let sound_path = match weapon.weapon_type {
-					WeaponType::BasicBlaster => "sounds/basic_blaster_fire.ogg",
-					WeaponType::PlasmaCannon => "sounds/plasma_cannon_fire.ogg",
-					WeaponType::WaveGun => "sounds/wave_gun_fire.ogg",
-					WeaponType::SpreadShot => "sounds/spread_shot_fire.ogg",
-					WeaponType::MissilePods => "sounds/missile_launch.ogg",
-					WeaponType::LaserArray => "sounds/laser_array_fire.ogg",
-					WeaponType::OrbitalDefense => "sounds/orbital_fire.ogg",
-					WeaponType::LightningChain => unreachable!(),
-				};
-				let (volume, priority, cooldown) = match weapon.weapon_type {
-					WeaponType::BasicBlaster => (0.55, 40, 0.02),
-					WeaponType::PlasmaCannon => (0.7, 55, 0.05),
-					WeaponType::WaveGun => (0.6, 50, 0.03),
-					WeaponType::SpreadShot => (0.65, 50, 0.06),
-					WeaponType::MissilePods => (0.75, 60, 0.08),
-					WeaponType::LaserArray => (0.5, 45, 0.015),
-					WeaponType::OrbitalDefense => (0.55, 45, 0.03),
-					WeaponType::LightningChain => unreachable!(),
-				};
-				sfx_events.send(PlaySfxEvent::simple(sound_path, volume, priority, cooldown));
-			}
+			let stats = apply_weapon_cooldown(&mut weapon, &tuning);
+			let shot = WeaponShot { spawn_pos, damage: stats.damage, modifiers: stats.modifiers, spread_scale, volume_scale: 1.0 };
+			fire_weapon_shot(&mut commands, &asset_server, &weapon, shot, &enemies, &mut hit_events, &mut sfx_events, &tuning, &projectile_materials);
 			weapon.fire_cooldown.reset();
 		}
 	}
 }
 
+/// Set the weapon's cooldown for its level and return the stats to fire with
+fn apply_weapon_cooldown(weapon: &mut Weapon, tuning: &WeaponTuning) -> WeaponLevelStats {
+	let stats = tuning.stats(weapon);
+
+	weapon.fire_cooldown.set_duration(
+		std::time::Duration::from_secs_f32(stats.cooldown.max(0.05))
+	);
+	stats
+}
+
+/// Where and how hard one volley lands
+struct WeaponShot {
+	spawn_pos: Vec3,
+	damage: f32,
+	modifiers: ProjectileModifiers,
+	spread_scale: f32,
+	volume_scale: f32,
+}
+
+/// Spawn a player projectile with its weapon's modifiers attached as components
+fn spawn_projectile(commands: &mut Commands, modifiers: &ProjectileModifiers, bundle: impl Bundle) {
+	let mut projectile = commands.spawn(bundle);
+	if modifiers.pierce > 0 {
+		projectile.insert(Pierce { remaining: modifiers.pierce });
+	}
+	if modifiers.ricochet > 0 {
+		projectile.insert(Ricochet { remaining: modifiers.ricochet, range: RICOCHET_RANGE });
+	}
+	if modifiers.pierce > 0 || modifiers.ricochet > 0 {
+		projectile.insert(ProjectileHits::default());
+	}
+	if let Some(dot) = modifiers.dot {
+		projectile.insert(dot);
+	}
+	if modifiers.knockback > 0.0 {
+		projectile.insert(Knockback { strength: modifiers.knockback });
+	}
+}
+
+/// Spawn one volley for `weapon` with muzzle flash and fire sound
+#[allow(clippy::too_many_arguments)]
+fn fire_weapon_shot(
+	commands: &mut Commands,
+	asset_server: &AssetServer,
+	weapon: &Weapon,
+	shot: WeaponShot,
+	enemies: &Query<(Entity, &Transform, &Health, &Collider), With<Enemy>>,
+	hit_events: &mut EventWriter<EnemyHitEvent>,
+	sfx_events: &mut EventWriter<PlaySfxEvent>,
+	tuning: &WeaponTuning,
+	projectile_materials: &ProjectileMaterialHandles,
+) {
+	let config = tuning.weapon(weapon.weapon_type);
+	let WeaponShot { spawn_pos, damage, modifiers, spread_scale, volume_scale } = shot;
+
+	if let Some(evolution) = weapon.evolution {
+		let evolved = EvolvedShot { evolution, weapon, config, spawn_pos, damage, modifiers, spread_scale, materials: projectile_materials };
+		fire_evolved_shot(commands, &evolved, enemies, hit_events);
+	} else {
+		match weapon.weapon_type {
+			WeaponType::BasicBlaster => {
+				spawn_basic_projectile(commands, asset_server, spawn_pos, weapon, config, damage, &modifiers);
+			},
+			WeaponType::PlasmaCannon => {
+				spawn_plasma_projectile(commands, asset_server, spawn_pos, weapon, config, damage, &modifiers);
+			},
+			WeaponType::WaveGun => {
+				spawn_wave_projectile(commands, asset_server, spawn_pos, weapon, config, damage, &modifiers, spread_scale);
+			},
+			WeaponType::SpreadShot => {
+				spawn_spread_projectiles(commands, asset_server, spawn_pos, weapon, config, damage, &modifiers, spread_scale);
+			},
+			WeaponType::MissilePods => {
+				spawn_missile_projectiles(commands, asset_server, spawn_pos, weapon, config, damage, &modifiers);
+			},
+			WeaponType::LaserArray => {
+				spawn_laser_beams(commands, asset_server, spawn_pos, weapon, config, damage, &modifiers);
+			},
+			WeaponType::OrbitalDefense => {
+				// Orbital defense doesn't fire projectiles traditionally
+				// It spawns/maintains orbs that are handled separately
+			},
+			WeaponType::LightningChain => {
+				// Levels 1-7: Fire on press with default tier 0.4
+				lightning::fire_lightning_weapon(
+					commands,
+					spawn_pos,
+					weapon,
+					damage,
+					0.4, // Default tier for low levels
+					enemies,
+					hit_events,
+					&tuning.lightning,
+				);
+			},
+		}
+	}
+
+	spawn_muzzle_flash(commands, asset_server, spawn_pos, weapon.weapon_type);
+
+	// Lightning has its own audio handling in lightning.rs
+	if weapon.weapon_type != WeaponType::LightningChain {
+		let sound_path = match weapon.weapon_type {
+			WeaponType::BasicBlaster => "sounds/basic_blaster_fire.ogg",
+			WeaponType::PlasmaCannon => "sounds/plasma_cannon_fire.ogg",
+			WeaponType::WaveGun => "sounds/wave_gun_fire.ogg",
+			WeaponType::SpreadShot => "sounds/spread_shot_fire.ogg",
+			WeaponType::MissilePods => "sounds/missile_launch.ogg",
+			WeaponType::LaserArray => "sounds/laser_array_fire.ogg",
+			WeaponType::OrbitalDefense => "sounds/orbital_fire.ogg",
+			WeaponType::LightningChain => unreachable!(),
+		};
+		let (volume, priority, cooldown) = match weapon.weapon_type {
+			WeaponType::BasicBlaster => (0.55, 40, 0.02),
+			WeaponType::PlasmaCannon => (0.7, 55, 0.05),
+			WeaponType::WaveGun => (0.6, 50, 0.03),
+			WeaponType::SpreadShot => (0.65, 50, 0.06),
+			WeaponType::MissilePods => (0.75, 60, 0.08),
+			WeaponType::LaserArray => (0.5, 45, 0.015),
+			WeaponType::OrbitalDefense => (0.55, 45, 0.03),
+			WeaponType::LightningChain => unreachable!(),
+		};
+		sfx_events.send(PlaySfxEvent::simple(sound_path, volume * volume_scale, priority, cooldown));
+	}
+}
+
 fn spawn_basic_projectile(
 	commands: &mut Commands,
 	asset_server: &AssetServer,
 	spawn_pos: Vec3,
 	weapon: &Weapon,
-	config: &crate::components::WeaponConfig,
+	config: &WeaponConfig,
 	damage: f32,
+	modifiers: &ProjectileModifiers,
 ) {
-	commands.spawn((
+	spawn_projectile(commands, modifiers, (
 		Sprite {
 			image: asset_server.load("sprites/projectiles/basic_blaster.png"),
 			custom_size: Some(Vec2::new(20.0, 60.0)),
@@ -147,7 +236,6 @@ fn spawn_basic_projectile(
 		Transform::from_translation(spawn_pos.with_z(PROJECTILE_Z)),
 		Projectile {
 			weapon_type: weapon.weapon_type,
-			level: weapon.level,
 			speed: config.projectile_speed,
 			damage,
 			lifetime: Timer::from_seconds(PROJECTILE_LIFETIME, TimerMode::Once),
@@ -160,10 +248,11 @@ fn spawn_plasma_projectile(
 	asset_server: &AssetServer,
 	spawn_pos: Vec3,
 	weapon: &Weapon,
-	config: &crate::components::WeaponConfig,
+	config: &WeaponConfig,
 	damage: f32,
+	modifiers: &ProjectileModifiers,
 ) {
-	commands.spawn((
+	spawn_projectile(commands, modifiers, (
 		Sprite {
 			image: asset_server.load("sprites/projectiles/plasma_cannon.png"),
 			custom_size: Some(Vec2::new(35.0, 80.0)),
@@ -172,7 +261,6 @@ fn spawn_plasma_projectile(
 		Transform::from_translation(spawn_pos.with_z(PROJECTILE_Z)),
 		Projectile {
 			weapon_type: weapon.weapon_type,
-			level: weapon.level,
 			speed: config.projectile_speed,
 			damage,
 			lifetime: Timer::from_seconds(PROJECTILE_LIFETIME, TimerMode::Once),
@@ -182,18 +270,21 @@ fn spawn_plasma_projectile(
 	spawn_plasma_trail(commands, asset_server, spawn_pos);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_wave_projectile(
 	commands: &mut Commands,
 	asset_server: &AssetServer,
 	spawn_pos: Vec3,
 	weapon: &Weapon,
-	config: &crate::components::WeaponConfig,
+	config: &WeaponConfig,
 	damage: f32,
+	modifiers: &ProjectileModifiers,
+	spread_scale: f32,
 ) {
-	let amplitude = 100.0 + (weapon.level as f32 * 30.0);
+	let amplitude = (100.0 + (weapon.level as f32 * 30.0)) * spread_scale;
 	let frequency = 2.0 + (weapon.level as f32 * 0.5);
 
-	commands.spawn((
+	spawn_projectile(commands, modifiers, (
 		Sprite {
 			image: asset_server.load("sprites/projectiles/wave_gun.png"),
 			custom_size: Some(Vec2::new(40.0, 90.0)),
@@ -202,7 +293,6 @@ fn spawn_wave_projectile(
 		Transform::from_translation(spawn_pos.with_z(PROJECTILE_Z)),
 		Projectile {
 			weapon_type: weapon.weapon_type,
-			level: weapon.level,
 			speed: config.projectile_speed,
 			damage,
 			lifetime: Timer::from_seconds(PROJECTILE_LIFETIME, TimerMode::Once),
@@ -320,16 +410,19 @@ pub fn move_projectiles_sine(
 	}
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_spread_projectiles(
 	commands: &mut Commands,
 	asset_server: &AssetServer,
 	spawn_pos: Vec3,
 	weapon: &Weapon,
-	config: &crate::components::WeaponConfig,
+	config: &WeaponConfig,
 	damage: f32,
+	modifiers: &ProjectileModifiers,
+	spread_scale: f32,
 ) {
 	let projectile_count = 3 + (weapon.level as usize * 2);
-	let max_angle = (30.0 + weapon.level as f32 * 15.0).to_radians();
+	let max_angle = (30.0 + weapon.level as f32 * 15.0).to_radians() * spread_scale;
 
 	for i in 0..projectile_count {
 		let t = if projectile_count > 1 {
@@ -341,7 +434,7 @@ fn spawn_spread_projectiles(
 
 		let velocity = Vec2::new(angle.sin(), angle.cos()) * config.projectile_speed;
 
-		commands.spawn((
+		spawn_projectile(commands, modifiers, (
 			Sprite {
 				image: asset_server.load("sprites/projectiles/spread_shot.png"),
 				custom_size: Some(Vec2::new(25.0, 50.0)),
@@ -351,7 +444,6 @@ fn spawn_spread_projectiles(
 				.with_rotation(Quat::from_rotation_z(angle)),
 			Projectile {
 				weapon_type: weapon.weapon_type,
-				level: weapon.level,
 				speed: velocity.length(),
 				damage,
 				lifetime: Timer::from_seconds(PROJECTILE_LIFETIME, TimerMode::Once),
@@ -368,15 +460,16 @@ fn spawn_missile_projectiles(
 	asset_server: &AssetServer,
 	spawn_pos: Vec3,
 	weapon: &Weapon,
-	config: &crate::components::WeaponConfig,
+	config: &WeaponConfig,
 	damage: f32,
+	modifiers: &ProjectileModifiers,
 ) {
 	let missile_count = 1 + (weapon.level / 2) as usize;
 
 	for i in 0..missile_count {
 		let offset_x = (i as f32 - (missile_count - 1) as f32 / 2.0) * 15.0;
 
-		commands.spawn((
+		spawn_projectile(commands, modifiers, (
 			Sprite {
 				image: asset_server.load("sprites/projectiles/missile.png"),
 				custom_size: Some(Vec2::new(30.0, 70.0)),
@@ -386,7 +479,6 @@ fn spawn_missile_projectiles(
 				.with_rotation(Quat::from_rotation_z(0.0)), // Points upward
 			Projectile {
 				weapon_type: weapon.weapon_type,
-				level: weapon.level,
 				speed: config.projectile_speed,
 				damage,
 				lifetime: Timer::from_seconds(PROJECTILE_LIFETIME, TimerMode::Once),
@@ -403,15 +495,16 @@ fn spawn_laser_beams(
 	asset_server: &AssetServer,
 	spawn_pos: Vec3,
 	weapon: &Weapon,
-	config: &crate::components::WeaponConfig,
+	config: &WeaponConfig,
 	damage: f32,
+	modifiers: &ProjectileModifiers,
 ) {
 	let beam_count = 1 + (weapon.level / 3) as usize;
 
 	for i in 0..beam_count {
 		let offset_x = (i as f32 - (beam_count - 1) as f32 / 2.0) * 20.0;
 
-		commands.spawn((
+		spawn_projectile(commands, modifiers, (
 			Sprite {
 				image: asset_server.load("sprites/projectiles/laser_beam.png"),
 				custom_size: Some(Vec2::new(15.0, 100.0)),
@@ -420,7 +513,6 @@ fn spawn_laser_beams(
 			Transform::from_translation((spawn_pos + Vec3::new(offset_x, 0.0, 0.0)).with_z(PROJECTILE_Z)),
 			Projectile {
 				weapon_type: weapon.weapon_type,
-				level: weapon.level,
 				speed: config.projectile_speed,
 				damage,
 				lifetime: Timer::from_seconds(0.15, TimerMode::Once),
@@ -429,6 +521,244 @@ fn spawn_laser_beams(
 	}
 }
 
+// === Evolutions ===
+
+const PIERCING_BEAM_WIDTH: f32 = 44.0;
+const PIERCING_BEAM_FADE: f32 = 0.12;
+const PLASMA_NOVA_SPLASH: SplashDamage = SplashDamage { radius: 130.0, fraction: 0.6 };
+const TORPEDO_SPLASH: SplashDamage = SplashDamage { radius: 160.0, fraction: 0.75 };
+/// Distance ahead of the ship where Crossfire's wing beams meet
+const CROSSFIRE_FOCUS: f32 = 420.0;
+
+/// Everything an evolved weapon's firing function needs for one volley
+struct EvolvedShot<'a> {
+	evolution: WeaponEvolution,
+	weapon: &'a Weapon,
+	config: &'a WeaponConfig,
+	spawn_pos: Vec3,
+	damage: f32,
+	modifiers: ProjectileModifiers,
+	spread_scale: f32,
+	materials: &'a ProjectileMaterialHandles,
+}
+
+impl EvolvedShot<'_> {
+	/// Glowing quad projectile of `size` at `offset` from the muzzle, heading `angle` from straight up
+	fn projectile(&self, offset: Vec2, size: Vec2, angle: f32, speed: f32, lifetime: f32) -> impl Bundle {
+		(
+			Mesh2d(self.materials.quad.clone()),
+			MeshMaterial2d(self.materials.evolution(self.evolution)),
+			Transform::from_translation((self.spawn_pos + offset.extend(0.0)).with_z(PROJECTILE_Z))
+				.with_rotation(Quat::from_rotation_z(-angle))
+				.with_scale(size.extend(1.0)),
+			Projectile {
+				weapon_type: self.weapon.weapon_type,
+				speed,
+				damage: self.damage,
+				lifetime: Timer::from_seconds(lifetime, TimerMode::Once),
+			},
+		)
+	}
+}
+
+/// Velocity for a shot heading `angle` radians clockwise from straight up
+fn heading_velocity(angle: f32, speed: f32) -> Vec2 {
+	Vec2::new(angle.sin(), angle.cos()) * speed
+}
+
+fn fire_evolved_shot(
+	commands: &mut Commands,
+	shot: &EvolvedShot,
+	enemies: &Query<(Entity, &Transform, &Health, &Collider), With<Enemy>>,
+	hit_events: &mut EventWriter<EnemyHitEvent>,
+) {
+	match shot.evolution {
+		WeaponEvolution::PiercingBeam => fire_piercing_beam(commands, shot, enemies, hit_events),
+		WeaponEvolution::PlasmaNova => fire_splash_shot(commands, shot, Vec2::splat(70.0), 0.6, PLASMA_NOVA_SPLASH),
+		WeaponEvolution::TwinHelix => fire_twin_helix(commands, shot),
+		WeaponEvolution::Tsunami => fire_tsunami(commands, shot),
+		WeaponEvolution::SevenWay => fire_fan(commands, shot, 7, 40.0_f32.to_radians() * shot.spread_scale),
+		WeaponEvolution::RearGuard => {
+			fire_fan(commands, shot, 9, 55.0_f32.to_radians() * shot.spread_scale);
+			fire_rear_guard(commands, shot);
+		}
+		WeaponEvolution::Swarm => fire_swarm(commands, shot),
+		WeaponEvolution::Torpedo => fire_splash_shot(commands, shot, Vec2::new(34.0, 90.0), 0.7, TORPEDO_SPLASH),
+		WeaponEvolution::PrismFan => fire_prism_fan(commands, shot),
+		WeaponEvolution::Crossfire => fire_crossfire(commands, shot),
+	}
+}
+
+/// PlasmaCannon branch: instant beam to the top of the screen that hits everything in its column.
+/// Pierce and ricochet don't apply; burn and knockback do.
+fn fire_piercing_beam(
+	commands: &mut Commands,
+	shot: &EvolvedShot,
+	enemies: &Query<(Entity, &Transform, &Health, &Collider), With<Enemy>>,
+	hit_events: &mut EventWriter<EnemyHitEvent>,
+) {
+	let origin = shot.spawn_pos.truncate();
+	let top = HALF_WORLD_HEIGHT;
+
+	for (entity, transform, health, collider) in enemies.iter() {
+		let pos = transform.translation.truncate();
+		let in_column = (pos.x - origin.x).abs() < PIERCING_BEAM_WIDTH / 2.0 + collider.radius;
+		if in_column && pos.y > origin.y && pos.y < top + collider.radius && health.current > 0.0 {
+			hit_events.send(EnemyHitEvent {
+				enemy: entity,
+				damage: shot.damage,
+				hit_sound: None,
+				dot: shot.modifiers.dot,
+				knockback: Vec2::Y * shot.modifiers.knockback,
+				damage_type: shot.weapon.weapon_type.damage_type(),
+				source: Some(origin),
+			});
+		}
+	}
+
+	let length = (top - origin.y).max(0.0);
+	commands.spawn((
+		Mesh2d(shot.materials.quad.clone()),
+		MeshMaterial2d(shot.materials.evolution(shot.evolution)),
+		Transform::from_xyz(origin.x, origin.y + length / 2.0, PROJECTILE_Z)
+			.with_scale(Vec3::new(PIERCING_BEAM_WIDTH, length, 1.0)),
+		EvolutionBeam {
+			lifetime: Timer::from_seconds(PIERCING_BEAM_FADE, TimerMode::Once),
+			width: PIERCING_BEAM_WIDTH,
+		},
+	));
+}
+
+/// PlasmaCannon and MissilePods branches: one slow heavy shot that splashes on impact
+fn fire_splash_shot(commands: &mut Commands, shot: &EvolvedShot, size: Vec2, speed_scale: f32, splash: SplashDamage) {
+	let speed = shot.config.projectile_speed * speed_scale;
+	spawn_projectile(commands, &shot.modifiers, (
+		shot.projectile(Vec2::ZERO, size, 0.0, speed, PROJECTILE_LIFETIME),
+		splash,
+	));
+}
+
+/// WaveGun branch: two shots weaving around each other in mirrored sine paths
+fn fire_twin_helix(commands: &mut Commands, shot: &EvolvedShot) {
+	let amplitude = 90.0 * shot.spread_scale;
+	let frequency = 6.0;
+
+	for phase in [0.0, PI] {
+		spawn_projectile(commands, &shot.modifiers, (
+			shot.projectile(Vec2::ZERO, Vec2::new(26.0, 60.0), 0.0, shot.config.projectile_speed, PROJECTILE_LIFETIME),
+			SineMotion {
+				amplitude,
+				frequency,
+				time_offset: phase / frequency,
+				base_x: shot.spawn_pos.x,
+			},
+		));
+	}
+}
+
+/// WaveGun branch: a wide wall of shots swaying together
+fn fire_tsunami(commands: &mut Commands, shot: &EvolvedShot) {
+	let amplitude = 70.0 * shot.spread_scale;
+	let spacing = 40.0;
+
+	for i in -2..=2 {
+		let offset_x = i as f32 * spacing;
+		spawn_projectile(commands, &shot.modifiers, (
+			shot.projectile(Vec2::new(offset_x, 0.0), Vec2::new(30.0, 50.0), 0.0, shot.config.projectile_speed, PROJECTILE_LIFETIME),
+			SineMotion {
+				amplitude,
+				frequency: 3.0,
+				time_offset: 0.0,
+				base_x: shot.spawn_pos.x + offset_x,
+			},
+		));
+	}
+}
+
+/// SpreadShot branches: `count` pellets evenly across +/- `max_angle`
+fn fire_fan(commands: &mut Commands, shot: &EvolvedShot, count: usize, max_angle: f32) {
+	let speed = shot.config.projectile_speed;
+
+	for i in 0..count {
+		let t = i as f32 / (count - 1) as f32;
+		let angle = (t - 0.5) * 2.0 * max_angle;
+		spawn_projectile(commands, &shot.modifiers, (
+			shot.projectile(Vec2::ZERO, Vec2::new(18.0, 40.0), angle, speed, PROJECTILE_LIFETIME),
+			AngledShot { velocity: heading_velocity(angle, speed) },
+		));
+	}
+}
+
+/// RearGuard's extra pellets, fired backwards from behind the ship
+fn fire_rear_guard(commands: &mut Commands, shot: &EvolvedShot) {
+	let speed = shot.config.projectile_speed * 0.8;
+
+	for angle in [PI - 0.35, PI, PI + 0.35] {
+		spawn_projectile(commands, &shot.modifiers, (
+			shot.projectile(Vec2::new(0.0, -110.0), Vec2::new(18.0, 40.0), angle, speed, 1.0),
+			AngledShot { velocity: heading_velocity(angle, speed) },
+		));
+	}
+}
+
+/// MissilePods branch: a staggered cloud of small, sharp-turning missiles
+fn fire_swarm(commands: &mut Commands, shot: &EvolvedShot) {
+	let count = 8;
+
+	for i in 0..count {
+		let offset = Vec2::new(
+			(i as f32 - (count - 1) as f32 / 2.0) * 14.0,
+			if i % 2 == 0 { 0.0 } else { -18.0 },
+		);
+		spawn_projectile(commands, &shot.modifiers, (
+			shot.projectile(offset, Vec2::new(14.0, 36.0), 0.0, shot.config.projectile_speed * 1.1, PROJECTILE_LIFETIME),
+			HomingProjectile { turn_speed: 6.0 },
+		));
+	}
+}
+
+/// LaserArray branch: five beams fanned like light through a prism
+fn fire_prism_fan(commands: &mut Commands, shot: &EvolvedShot) {
+	let speed = shot.config.projectile_speed;
+
+	for i in -2..=2 {
+		let angle = (i as f32 * 8.0).to_radians();
+		spawn_projectile(commands, &shot.modifiers, (
+			shot.projectile(Vec2::ZERO, Vec2::new(10.0, 90.0), angle, speed, 0.2),
+			AngledShot { velocity: heading_velocity(angle, speed) },
+		));
+	}
+}
+
+/// LaserArray branch: wing-tip beams angled inward to cross ahead of the ship
+fn fire_crossfire(commands: &mut Commands, shot: &EvolvedShot) {
+	let speed = shot.config.projectile_speed;
+
+	for offset_x in [-60.0_f32, -30.0, 30.0, 60.0] {
+		let angle = (-offset_x).atan2(CROSSFIRE_FOCUS);
+		spawn_projectile(commands, &shot.modifiers, (
+			shot.projectile(Vec2::new(offset_x, -20.0), Vec2::new(10.0, 90.0), angle, speed, 0.25),
+			AngledShot { velocity: heading_velocity(angle, speed) },
+		));
+	}
+}
+
+/// Narrow and remove PiercingBeam visuals
+pub fn update_evolution_beams(
+	mut commands: Commands,
+	mut query: Query<(Entity, &mut Transform, &mut EvolutionBeam)>,
+	time: Res<Time>,
+) {
+	for (entity, mut transform, mut beam) in query.iter_mut() {
+		beam.lifetime.tick(time.delta());
+		if beam.lifetime.finished() {
+			commands.entity(entity).despawn();
+		} else {
+			transform.scale.x = beam.width * (1.0 - beam.lifetime.fraction());
+		}
+	}
+}
+
 pub fn move_homing_projectiles(
 	mut query: Query<(&mut Transform, &Projectile, &HomingProjectile), Without<Enemy>>,
 	enemy_query: Query<&Transform, With<Enemy>>,
@@ -465,61 +795,77 @@ pub fn move_homing_projectiles(
 	}
 }
 
+/// Level of OrbitalDefense in whichever slot holds it
+fn orbital_level(weapon: &Weapon, sub: Option<&SubWeapon>) -> Option<u8> {
+	if weapon.weapon_type == WeaponType::OrbitalDefense {
+		return Some(weapon.level);
+	}
+	sub.filter(|sub| sub.0.weapon_type == WeaponType::OrbitalDefense)
+		.map(|sub| sub.0.level)
+}
+
 pub fn manage_orbital_entities(
 	mut commands: Commands,
 	asset_server: Res<AssetServer>,
-	player_query: Query<(&Transform, &Weapon), With<Player>>,
+	player_query: Query<(Entity, &Transform, &Weapon, Option<&SubWeapon>), With<Player>>,
 	mut orbital_query: Query<(Entity, &mut Transform, &mut OrbitalEntity), Without<Player>>,
 	time: Res<Time>,
 ) {
-	let Ok((player_transform, weapon)) = player_query.get_single() else { return };
-
-	if weapon.weapon_type != WeaponType::OrbitalDefense {
-		for (entity, _, _) in orbital_query.iter() {
+	// Orbs whose ship is gone or no longer has the weapon in either slot
+	for (entity, _, orbital) in orbital_query.iter() {
+		let owner_has_orbitals = player_query.get(orbital.owner)
+			.is_ok_and(|(_, _, weapon, sub)| orbital_level(weapon, sub).is_some());
+		if !owner_has_orbitals {
 			commands.entity(entity).despawn();
 		}
-		return;
-	}
-
-	let orb_count = 2 + weapon.level as usize;
-	let current_orb_count = orbital_query.iter().count();
-
-	if current_orb_count < orb_count {
-		for i in current_orb_count..orb_count {
-			let angle = (i as f32 / orb_count as f32) * 2.0 * PI;
-			let config = weapon.weapon_type.config();
-
-			commands.spawn((
-				Sprite {
-					image: asset_server.load("sprites/projectiles/orbital_orb.png"),
-					custom_size: Some(Vec2::splat(45.0)),
-					..default()
-				},
-				Transform::from_xyz(0.0, 0.0, 0.6),
-				OrbitalEntity {
-					angle,
-					radius: 60.0,
-					rotation_speed: 2.0,
-					fire_timer: Timer::from_seconds(1.0, TimerMode::Repeating),
-				},
-			));
-		}
-	} else if current_orb_count > orb_count {
-		let mut iter = orbital_query.iter();
-		for _ in orb_count..current_orb_count {
-			if let Some((entity, _, _)) = iter.next() {
-				commands.entity(entity).despawn();
+	}
+
+	for (player_entity, player_transform, weapon, sub) in player_query.iter() {
+		let Some(level) = orbital_level(weapon, sub) else { continue };
+
+		let orb_count = 2 + level as usize;
+		let current_orb_count = orbital_query.iter().filter(|(_, _, o)| o.owner == player_entity).count();
+
+		if current_orb_count < orb_count {
+			for i in current_orb_count..orb_count {
+				let angle = (i as f32 / orb_count as f32) * 2.0 * PI;
+
+				commands.spawn((
+					Sprite {
+						image: asset_server.load("sprites/projectiles/orbital_orb.png"),
+						custom_size: Some(Vec2::splat(45.0)),
+						..default()
+					},
+					Transform::from_xyz(0.0, 0.0, 0.6),
+					OrbitalEntity {
+						owner: player_entity,
+						angle,
+						radius: 60.0,
+						rotation_speed: 2.0,
+						fire_timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+					},
+				));
+			}
+		} else if current_orb_count > orb_count {
+			let mut iter = orbital_query.iter().filter(|(_, _, o)| o.owner == player_entity);
+			for _ in orb_count..current_orb_count {
+				if let Some((entity, _, _)) = iter.next() {
+					commands.entity(entity).despawn();
+				}
 			}
 		}
-	}
 
-	for (_, mut orb_transform, mut orbital) in orbital_query.iter_mut() {
-		orbital.angle += orbital.rotation_speed * time.delta_secs();
+		for (_, mut orb_transform, mut orbital) in orbital_query.iter_mut() {
+			if orbital.owner != player_entity {
+				continue;
+			}
+			orbital.angle += orbital.rotation_speed * time.delta_secs();
 
-		let offset_x = orbital.angle.cos() * orbital.radius;
-		let offset_y = orbital.angle.sin() * orbital.radius;
+			let offset_x = orbital.angle.cos() * orbital.radius;
+			let offset_y = orbital.angle.sin() * orbital.radius;
 
-		orb_transform.translation = player_transform.translation + Vec3::new(offset_x, offset_y, 0.0);
+			orb_transform.translation = player_transform.translation + Vec3::new(offset_x, offset_y, 0.0);
+		}
 	}
 }
 
@@ -568,7 +914,6 @@ pub fn orbital_auto_fire(
 						.with_rotation(Quat::from_rotation_z(angle)),
 					Projectile {
 						weapon_type: WeaponType::OrbitalDefense,
-						level: 1,
 						speed: 800.0,
 						damage: 15.0,
 						lifetime: Timer::from_seconds(2.0, TimerMode::Once),
@@ -23,7 +23,6 @@ pub mod sizes {
 	pub const LARGE_ASTEROID: f32 = 120.0;
 	pub const ASTEROID_TURRET: f32 = 130.0;
 	pub const STATION_DEBRIS: f32 = 80.0;
-	#[allow(dead_code)]
 	pub const POWER_UP: f32 = 40.0;
 }
 